@@ -1,28 +1,94 @@
 use super::{
+    change::{CacheUpdate, OnChange},
     config::{Config, ResourceType},
+    filter::UserFilter,
     InMemoryCache,
 };
+use twilight_model::user::User;
 
 /// Builder to configure and construct an [`InMemoryCache`].
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct InMemoryCacheBuilder(Config);
+pub struct InMemoryCacheBuilder {
+    config: Config,
+    on_change: Option<OnChange>,
+    user_filter: Option<UserFilter>,
+}
 
 impl InMemoryCacheBuilder {
     /// Creates a builder to configure and construct an [`InMemoryCache`].
     pub const fn new() -> Self {
-        Self(Config::new())
+        Self {
+            config: Config::new(),
+            on_change: None,
+            user_filter: None,
+        }
     }
 
     /// Consume the builder, returning a configured cache.
     pub fn build(self) -> InMemoryCache {
-        InMemoryCache::new_with_config(self.0)
+        InMemoryCache::new_with_config(self.config, self.on_change, self.user_filter)
+    }
+
+    /// Sets a callback to be notified when resources in the cache change.
+    ///
+    /// The callback is called after the cache has been updated and no
+    /// internal locks are held, so it's safe to access the cache from within
+    /// it. The callback should be quick to return, as it's called on the task
+    /// updating the cache.
+    ///
+    /// # Examples
+    ///
+    /// Print the ID of every role added to the cache:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::{CacheUpdate, InMemoryCache};
+    ///
+    /// let cache = InMemoryCache::builder()
+    ///     .on_change(|update| {
+    ///         if let CacheUpdate::RoleAdded { role_id, .. } = update {
+    ///             println!("role added: {}", role_id);
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn on_change(mut self, callback: impl Fn(CacheUpdate) + Send + Sync + 'static) -> Self {
+        self.on_change.replace(OnChange::new(callback));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether a user should be cached.
+    ///
+    /// Users for which the predicate returns `false` aren't cached, and
+    /// neither are their members. This can be used to save memory by not
+    /// caching users that are never needed. The current user is always
+    /// cached, regardless of the predicate.
+    ///
+    /// # Examples
+    ///
+    /// Don't cache bot users:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// let cache = InMemoryCache::builder()
+    ///     .user_filter(|user| !user.bot)
+    ///     .build();
+    /// ```
+    pub fn user_filter(
+        mut self,
+        predicate: impl Fn(&User) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.user_filter.replace(UserFilter::new(predicate));
+
+        self
     }
 
     /// Sets the list of resource types for the cache to handle.
     ///
     /// Defaults to all types.
     pub const fn resource_types(mut self, resource_types: ResourceType) -> Self {
-        self.0.resource_types = resource_types;
+        self.config.resource_types = resource_types;
 
         self
     }
@@ -31,7 +97,19 @@ impl InMemoryCacheBuilder {
     ///
     /// Defaults to 100.
     pub const fn message_cache_size(mut self, message_cache_size: usize) -> Self {
-        self.0.message_cache_size = message_cache_size;
+        self.config.message_cache_size = message_cache_size;
+
+        self
+    }
+
+    /// Sets the maximum number of members to cache per guild.
+    ///
+    /// When the limit is exceeded the least recently accessed member is
+    /// evicted. The current user and the guild owner are never evicted.
+    ///
+    /// Defaults to no limit.
+    pub const fn member_cache_size(mut self, member_cache_size: usize) -> Self {
+        self.config.member_cache_size = Some(member_cache_size);
 
         self
     }
@@ -43,5 +121,23 @@ mod tests {
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
-    assert_impl_all!(InMemoryCacheBuilder: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(
+        InMemoryCacheBuilder: Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(InMemoryCacheBuilder::new(), InMemoryCacheBuilder::new());
+
+        let builder = InMemoryCacheBuilder::new().on_change(|_| {});
+        assert_eq!(builder, builder.clone());
+        assert_ne!(builder, InMemoryCacheBuilder::new().on_change(|_| {}));
+        assert_ne!(builder, InMemoryCacheBuilder::new());
+    }
 }
@@ -0,0 +1,309 @@
+//! Retrieve resources from the cache, falling back to fetching them over HTTP
+//! and caching them.
+//!
+//! # Examples
+//!
+//! Retrieve a user, only making a request if the user isn't cached:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_cache_inmemory::{CachingClient, InMemoryCache};
+//! use twilight_http::Client;
+//! use twilight_model::id::UserId;
+//!
+//! let client = CachingClient::new(Client::new("my token"), InMemoryCache::new());
+//!
+//! if let Some(user) = client.user(UserId(1)).await? {
+//!     println!("user's name: {}", user.name);
+//! }
+//! # Ok(()) }
+//! ```
+
+use super::{
+    model::{CachedGuild, CachedMember},
+    InMemoryCache,
+};
+use twilight_http::{Client, Error};
+use twilight_model::{
+    channel::Channel,
+    id::{ChannelId, GuildId, UserId},
+    user::User,
+};
+
+/// Wrapper around an HTTP client and a cache that retrieves resources from
+/// the cache, falling back to fetching them over HTTP.
+///
+/// Resources fetched over HTTP are inserted into the cache, so retrieving
+/// them again doesn't make another request. Resources are only cached if the
+/// cache wants their [resource type].
+///
+/// Refer to the [module-level] documentation for an example.
+///
+/// [module-level]: self
+/// [resource type]: crate::ResourceType
+#[derive(Clone, Debug)]
+pub struct CachingClient {
+    cache: InMemoryCache,
+    http: Client,
+}
+
+impl CachingClient {
+    /// Create a new caching client from an HTTP client and a cache.
+    pub const fn new(http: Client, cache: InMemoryCache) -> Self {
+        Self { cache, http }
+    }
+
+    /// Immutable reference to the cache.
+    pub const fn cache(&self) -> &InMemoryCache {
+        &self.cache
+    }
+
+    /// Immutable reference to the HTTP client.
+    pub const fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Consume the caching client, returning the HTTP client and the cache.
+    pub fn into_parts(self) -> (Client, InMemoryCache) {
+        (self.http, self.cache)
+    }
+
+    /// Retrieve a channel, fetching it over HTTP if it isn't cached.
+    ///
+    /// Returns `None` if the channel doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the channel over HTTP failed.
+    pub async fn channel(&self, channel_id: ChannelId) -> Result<Option<Channel>, Error> {
+        let cached = self
+            .cache
+            .guild_channel(channel_id)
+            .map(Channel::Guild)
+            .or_else(|| self.cache.private_channel(channel_id).map(Channel::Private))
+            .or_else(|| self.cache.group(channel_id).map(Channel::Group));
+
+        if cached.is_some() {
+            return Ok(cached);
+        }
+
+        let channel = match self.http.channel(channel_id).await? {
+            Some(channel) => channel,
+            None => return Ok(None),
+        };
+
+        self.cache.insert_channel(channel.clone());
+
+        Ok(Some(channel))
+    }
+
+    /// Retrieve a guild, fetching it over HTTP if it isn't cached.
+    ///
+    /// Returns `None` if the guild doesn't exist, or if it isn't cached after
+    /// being fetched because the cache doesn't want the [`GUILD`] resource
+    /// type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the guild over HTTP failed.
+    ///
+    /// [`GUILD`]: crate::ResourceType::GUILD
+    pub async fn guild(&self, guild_id: GuildId) -> Result<Option<CachedGuild>, Error> {
+        if let Some(guild) = self.cache.guild(guild_id) {
+            return Ok(Some(guild));
+        }
+
+        if let Some(guild) = self.http.guild(guild_id).await? {
+            self.cache.insert_guild(guild);
+        }
+
+        Ok(self.cache.guild(guild_id))
+    }
+
+    /// Retrieve a member of a guild, fetching it over HTTP if it isn't cached.
+    ///
+    /// Returns `None` if the member doesn't exist, or if it isn't cached after
+    /// being fetched because the cache doesn't want the [`MEMBER`] resource
+    /// type or the member's user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the member over HTTP failed.
+    ///
+    /// [`MEMBER`]: crate::ResourceType::MEMBER
+    pub async fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<CachedMember>, Error> {
+        if let Some(member) = self.cache.member(guild_id, user_id) {
+            return Ok(Some(member));
+        }
+
+        if let Some(member) = self.http.guild_member(guild_id, user_id).await? {
+            self.cache.insert_member(member);
+        }
+
+        Ok(self.cache.member(guild_id, user_id))
+    }
+
+    /// Fetch all members of a guild over HTTP and insert them into the cache.
+    ///
+    /// Members are requested in pages of 1000, each starting after the last
+    /// member of the previous page, until a page with fewer members is
+    /// received. Requests go through the HTTP client's ratelimiter like any
+    /// other. Returns the number of fetched members.
+    ///
+    /// Members are only cached if the cache wants the [`MEMBER`] resource
+    /// type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching a page of members over HTTP failed.
+    /// Members of pages fetched before the error remain cached.
+    ///
+    /// [`MEMBER`]: crate::ResourceType::MEMBER
+    pub async fn fetch_and_cache_members(&self, guild_id: GuildId) -> Result<usize, Error> {
+        const LIMIT: u64 = 1000;
+
+        let mut after = None;
+        let mut fetched = 0;
+
+        loop {
+            let mut request = self
+                .http
+                .guild_members(guild_id)
+                .limit(LIMIT)
+                .expect("limit is valid");
+
+            if let Some(after) = after {
+                request = request.after(after);
+            }
+
+            let page = request.await?;
+            let full = page.len() as u64 >= LIMIT;
+            after = page.last().map(|member| member.user.id);
+            fetched += page.len();
+
+            for member in page {
+                self.cache.insert_member(member);
+            }
+
+            if !full {
+                return Ok(fetched);
+            }
+        }
+    }
+
+    /// Retrieve a user, fetching it over HTTP if it isn't cached.
+    ///
+    /// Returns `None` if the user doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the user over HTTP failed.
+    pub async fn user(&self, user_id: UserId) -> Result<Option<User>, Error> {
+        if let Some(user) = self.cache.user(user_id) {
+            return Ok(Some(user));
+        }
+
+        let user = match self.http.user(user_id).await? {
+            Some(user) => user,
+            None => return Ok(None),
+        };
+
+        self.cache.insert_user(user.clone());
+
+        Ok(Some(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachingClient;
+    use crate::{
+        test_server::{json_response, TestServer},
+        InMemoryCache,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, net::SocketAddr};
+    use twilight_http::Client;
+    use twilight_model::{
+        channel::Channel,
+        id::{ChannelId, GuildId, UserId},
+    };
+
+    assert_impl_all!(CachingClient: Clone, Debug, Send, Sync);
+
+    const USER: &str = r#"{"avatar":null,"discriminator":"0001","id":"2","username":"test"}"#;
+
+    /// Respond to every request with a JSON body.
+    async fn serve(body: &'static str) -> TestServer {
+        TestServer::respond(json_response("200 OK", body)).await
+    }
+
+    fn client(addr: SocketAddr) -> CachingClient {
+        let http = Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        CachingClient::new(http, InMemoryCache::new())
+    }
+
+    #[tokio::test]
+    async fn test_user() {
+        let server = serve(USER).await;
+        let client = client(server.addr());
+
+        let user = client.user(UserId(2)).await.unwrap().unwrap();
+        assert_eq!(UserId(2), user.id);
+        assert_eq!(1, server.received());
+
+        let cached = client.user(UserId(2)).await.unwrap().unwrap();
+        assert_eq!(user, cached);
+        assert_eq!(1, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_channel() {
+        const CHANNEL: &str = r#"{"id":"3","recipients":[{"avatar":null,"discriminator":"0001","id":"2","username":"test"}],"type":1}"#;
+
+        let server = serve(CHANNEL).await;
+        let client = client(server.addr());
+
+        let channel = client.channel(ChannelId(3)).await.unwrap().unwrap();
+        assert!(matches!(channel, Channel::Private(_)));
+        assert_eq!(1, server.received());
+
+        let cached = client.channel(ChannelId(3)).await.unwrap().unwrap();
+        assert_eq!(channel, cached);
+        assert_eq!(1, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_cache_members() {
+        const MEMBERS: &str = r#"[{"deaf":false,"guild_id":"1","hoisted_role":null,"joined_at":"2021-01-01T00:00:00.000000+00:00","mute":false,"nick":null,"roles":[],"user":{"avatar":null,"discriminator":"0001","id":"2","username":"test"}},{"deaf":false,"guild_id":"1","hoisted_role":null,"joined_at":"2021-01-01T00:00:00.000000+00:00","mute":false,"nick":"nick","roles":[],"user":{"avatar":null,"discriminator":"0002","id":"3","username":"test2"}}]"#;
+
+        let server = serve(MEMBERS).await;
+        let client = client(server.addr());
+
+        let fetched = client.fetch_and_cache_members(GuildId(1)).await.unwrap();
+        assert_eq!(2, fetched);
+        assert_eq!(1, server.received());
+
+        let members = client.cache().guild_members(GuildId(1)).unwrap();
+        assert_eq!(2, members.len());
+        assert!(members.contains(&UserId(2)));
+        assert_eq!(
+            Some("nick"),
+            client
+                .cache()
+                .member(GuildId(1), UserId(3))
+                .unwrap()
+                .nick
+                .as_deref()
+        );
+        assert!(client.cache().user(UserId(3)).is_some());
+    }
+}
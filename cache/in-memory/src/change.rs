@@ -0,0 +1,197 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+use twilight_model::id::{ChannelId, GuildId, MessageId, RoleId, UserId};
+
+/// Notification that a resource in the cache has changed.
+///
+/// Notifications are sent to the callback configured via
+/// [`InMemoryCacheBuilder::on_change`] after the cache has been updated.
+///
+/// [`InMemoryCacheBuilder::on_change`]: crate::InMemoryCacheBuilder::on_change
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CacheUpdate {
+    /// A channel has been added to the cache.
+    ChannelAdded(ChannelId),
+    /// A channel has been removed from the cache.
+    ChannelRemoved(ChannelId),
+    /// A channel in the cache has been updated.
+    ChannelUpdated(ChannelId),
+    /// A guild has been added to the cache.
+    GuildAdded(GuildId),
+    /// A guild has been removed from the cache.
+    GuildRemoved(GuildId),
+    /// A guild in the cache has been updated.
+    GuildUpdated(GuildId),
+    /// A member has been added to the cache.
+    MemberAdded {
+        /// ID of the guild the member is in.
+        guild_id: GuildId,
+        /// ID of the member's user.
+        user_id: UserId,
+    },
+    /// A member has been removed from the cache.
+    MemberRemoved {
+        /// ID of the guild the member was in.
+        guild_id: GuildId,
+        /// ID of the member's user.
+        user_id: UserId,
+    },
+    /// A member in the cache has been updated.
+    MemberUpdated {
+        /// ID of the guild the member is in.
+        guild_id: GuildId,
+        /// ID of the member's user.
+        user_id: UserId,
+    },
+    /// A message has been added to the cache.
+    MessageAdded {
+        /// ID of the channel the message is in.
+        channel_id: ChannelId,
+        /// ID of the message.
+        message_id: MessageId,
+    },
+    /// A message has been removed from the cache.
+    MessageRemoved {
+        /// ID of the channel the message was in.
+        channel_id: ChannelId,
+        /// ID of the message.
+        message_id: MessageId,
+    },
+    /// A message in the cache has been updated.
+    MessageUpdated {
+        /// ID of the channel the message is in.
+        channel_id: ChannelId,
+        /// ID of the message.
+        message_id: MessageId,
+    },
+    /// A role has been added to the cache.
+    RoleAdded {
+        /// ID of the guild the role is in.
+        guild_id: GuildId,
+        /// ID of the role.
+        role_id: RoleId,
+    },
+    /// A role has been removed from the cache.
+    RoleRemoved {
+        /// ID of the guild the role was in.
+        guild_id: GuildId,
+        /// ID of the role.
+        role_id: RoleId,
+    },
+    /// A role in the cache has been updated.
+    RoleUpdated {
+        /// ID of the guild the role is in.
+        guild_id: GuildId,
+        /// ID of the role.
+        role_id: RoleId,
+    },
+}
+
+/// Callback called with notifications of changes to the cache.
+#[derive(Clone)]
+pub(crate) struct OnChange(Arc<dyn Fn(CacheUpdate) + Send + Sync>);
+
+impl OnChange {
+    pub(crate) fn new(callback: impl Fn(CacheUpdate) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn call(&self, update: CacheUpdate) {
+        (self.0)(update);
+    }
+}
+
+impl Debug for OnChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("OnChange").finish()
+    }
+}
+
+impl Eq for OnChange {}
+
+/// Callbacks are equal if they're the same callback, such as in clones of a
+/// builder.
+impl PartialEq for OnChange {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.0).cast::<()>() == Arc::as_ptr(&other.0).cast::<()>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheUpdate, OnChange};
+    use crate::{test, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::{
+        gateway::payload::{RoleCreate, RoleDelete},
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(CacheUpdate: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(OnChange: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_role_added() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let cache = {
+            let updates = Arc::clone(&updates);
+
+            InMemoryCache::builder()
+                .on_change(move |update| updates.lock().unwrap().push(update))
+                .build()
+        };
+
+        cache.update(&RoleCreate {
+            guild_id: GuildId(1),
+            role: test::role(RoleId(2)),
+        });
+
+        assert_eq!(
+            [CacheUpdate::RoleAdded {
+                guild_id: GuildId(1),
+                role_id: RoleId(2),
+            }],
+            updates.lock().unwrap().as_slice(),
+        );
+    }
+    #[test]
+    fn test_role_removed() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let cache = {
+            let updates = Arc::clone(&updates);
+
+            InMemoryCache::builder()
+                .on_change(move |update| updates.lock().unwrap().push(update))
+                .build()
+        };
+
+        cache.update(&RoleCreate {
+            guild_id: GuildId(1),
+            role: test::role(RoleId(2)),
+        });
+        updates.lock().unwrap().clear();
+
+        let delete = RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(2),
+        };
+        cache.update(&delete);
+        cache.update(&delete);
+
+        assert_eq!(
+            [CacheUpdate::RoleRemoved {
+                guild_id: GuildId(1),
+                role_id: RoleId(2),
+            }],
+            updates.lock().unwrap().as_slice(),
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 bitflags! {
     /// A set of bitflags which can be used to specify what resource to process
@@ -7,6 +8,9 @@ bitflags! {
     /// For example, specifying [`CHANNEL`] but not [`MESSAGE`] will cache
     /// created channels, channel updates, and channel deletes, but not their
     /// messages.
+    ///
+    /// Events whose resources are all unwanted are ignored without modifying
+    /// the cache, even if the intents for them are enabled.
     pub struct ResourceType: u64 {
         /// Information relating to channels.
         const CHANNEL = 1;
@@ -34,16 +38,65 @@ bitflags! {
         const STAGE_INSTANCE = 1 << 11;
         /// Information relating to guild integrations.
         const INTEGRATION = 1 << 12;
+        /// Information relating to guild invites.
+        const INVITE = 1 << 13;
+        /// Information relating to users typing in channels.
+        ///
+        /// This isn't enabled by default.
+        const TYPING = 1 << 14;
+        /// Information relating to voice regions.
+        ///
+        /// Voice regions aren't sent over the gateway, so they are only
+        /// cached when inserted into the cache manually.
+        const VOICE_REGION = 1 << 15;
+        /// Information relating to guild scheduled events.
+        const SCHEDULED_EVENT = 1 << 16;
+        /// Information relating to threads.
+        const THREAD = 1 << 17;
+        /// Information relating to the current application's commands.
+        ///
+        /// Commands aren't sent over the gateway, so they are only cached
+        /// when inserted into the cache manually.
+        const COMMAND = 1 << 18;
+        /// Information relating to webhooks.
+        ///
+        /// Webhooks aren't sent over the gateway, so they are only cached
+        /// when inserted into the cache manually. This isn't enabled by
+        /// default.
+        const WEBHOOK = 1 << 19;
+        /// Information relating to the voice servers of guilds.
+        ///
+        /// This isn't enabled by default.
+        const VOICE_SERVER = 1 << 20;
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bits())
     }
 }
 
 /// Configuration for an [`InMemoryCache`].
 ///
+/// Fields missing when deserializing are set to their defaults.
+///
 /// [`InMemoryCache`]: crate::InMemoryCache
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
 pub struct Config {
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) member_cache_size: Option<usize>,
 }
 
 impl Config {
@@ -52,11 +105,27 @@ impl Config {
     /// Refer to individual getters for their defaults.
     pub const fn new() -> Self {
         Self {
-            resource_types: ResourceType::all(),
+            resource_types: ResourceType::all()
+                .difference(ResourceType::TYPING)
+                .difference(ResourceType::WEBHOOK)
+                .difference(ResourceType::VOICE_SERVER),
             message_cache_size: 100,
+            member_cache_size: None,
         }
     }
 
+    /// Returns an immutable reference to the member cache size.
+    ///
+    /// Defaults to no limit.
+    pub const fn member_cache_size(&self) -> Option<usize> {
+        self.member_cache_size
+    }
+
+    /// Returns a mutable reference to the member cache size.
+    pub fn member_cache_size_mut(&mut self) -> &mut Option<usize> {
+        &mut self.member_cache_size
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -70,7 +139,8 @@ impl Config {
     }
     /// Returns an immutable reference to the resource types enabled.
     ///
-    /// Defaults to all resource types.
+    /// Defaults to all resource types except for [`ResourceType::TYPING`],
+    /// [`ResourceType::WEBHOOK`], and [`ResourceType::VOICE_SERVER`].
     pub const fn resource_types(&self) -> ResourceType {
         self.resource_types
     }
@@ -90,9 +160,10 @@ impl Default for Config {
 #[cfg(test)]
 mod tests {
     use super::{Config, ResourceType};
+    use serde_json::json;
     use static_assertions::assert_fields;
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(Config: resource_types, message_cache_size, member_cache_size);
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -109,16 +180,70 @@ mod tests {
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
         assert_eq!(1 << 11, ResourceType::STAGE_INSTANCE.bits());
+        assert_eq!(1 << 12, ResourceType::INTEGRATION.bits());
+        assert_eq!(1 << 13, ResourceType::INVITE.bits());
+        assert_eq!(1 << 14, ResourceType::TYPING.bits());
+        assert_eq!(1 << 15, ResourceType::VOICE_REGION.bits());
+        assert_eq!(1 << 16, ResourceType::SCHEDULED_EVENT.bits());
+        assert_eq!(1 << 17, ResourceType::THREAD.bits());
+        assert_eq!(1 << 18, ResourceType::COMMAND.bits());
+        assert_eq!(1 << 19, ResourceType::WEBHOOK.bits());
+        assert_eq!(1 << 20, ResourceType::VOICE_SERVER.bits());
     }
 
     #[test]
     fn test_defaults() {
         let conf = Config {
-            resource_types: ResourceType::all(),
+            resource_types: ResourceType::all()
+                - ResourceType::TYPING
+                - ResourceType::WEBHOOK
+                - ResourceType::VOICE_SERVER,
             message_cache_size: 100,
+            member_cache_size: None,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.member_cache_size, default.member_cache_size);
+    }
+
+    #[test]
+    fn test_resource_type_serde() {
+        let resource_types = ResourceType::CHANNEL | ResourceType::MESSAGE;
+
+        assert_eq!(json!(17), serde_json::to_value(resource_types).unwrap());
+        assert_eq!(
+            resource_types,
+            serde_json::from_value::<ResourceType>(json!(17)).unwrap()
+        );
+        assert_eq!(
+            ResourceType::CHANNEL,
+            serde_json::from_value::<ResourceType>(json!(1_u64 | 1 << 63)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_config_serde() {
+        let mut config = Config::new();
+        *config.resource_types_mut() = ResourceType::GUILD | ResourceType::MESSAGE;
+        *config.message_cache_size_mut() = 50;
+
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            json!({
+                "resource_types": 20,
+                "message_cache_size": 50,
+                "member_cache_size": null,
+            }),
+            value
+        );
+        assert_eq!(config, serde_json::from_value::<Config>(value).unwrap());
+
+        let partial = serde_json::from_value::<Config>(json!({
+            "message_cache_size": 10,
+        }))
+        .unwrap();
+        assert_eq!(Config::default().resource_types(), partial.resource_types());
+        assert_eq!(10, partial.message_cache_size());
     }
 }
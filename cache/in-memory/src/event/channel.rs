@@ -1,4 +1,4 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{config::ResourceType, CacheUpdate, InMemoryCache, UpdateCache};
 use twilight_model::{
     channel::{Channel, Group, GuildChannel, PrivateChannel},
     gateway::payload::{ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate},
@@ -43,7 +43,7 @@ impl InMemoryCache {
     }
 
     fn cache_group(&self, group: Group) {
-        crate::upsert_item(&self.0.groups, group.id, group)
+        crate::upsert_item(&self.0.groups, group.id, group);
     }
 
     fn cache_private_channel(&self, private_channel: PrivateChannel) {
@@ -88,6 +88,9 @@ impl UpdateCache for ChannelCreate {
                 cache.cache_private_channel(c.clone());
             }
         }
+
+        cache.increment_generation();
+        cache.notify(CacheUpdate::ChannelAdded(self.0.id()));
     }
 }
 
@@ -103,11 +106,24 @@ impl UpdateCache for ChannelDelete {
             }
             Channel::Guild(ref c) => {
                 cache.delete_guild_channel(c.id());
+
+                if cache.wants(ResourceType::THREAD) {
+                    cache.delete_channel_threads(c.id());
+                }
+
+                if cache.wants(ResourceType::WEBHOOK) {
+                    cache.delete_channel_webhooks(c.id());
+                }
+
+                cache.0.typing.remove(&c.id());
             }
             Channel::Private(ref c) => {
                 cache.0.channels_private.remove(&c.id);
             }
         }
+
+        cache.increment_generation();
+        cache.notify(CacheUpdate::ChannelRemoved(self.0.id()));
     }
 }
 
@@ -122,6 +138,7 @@ impl UpdateCache for ChannelPinsUpdate {
 
             if let GuildChannel::Text(ref mut text) = value.data {
                 text.last_pin_timestamp = self.last_pin_timestamp.clone();
+                cache.increment_generation();
             }
 
             return;
@@ -129,12 +146,14 @@ impl UpdateCache for ChannelPinsUpdate {
 
         if let Some(mut channel) = cache.0.channels_private.get_mut(&self.channel_id) {
             channel.last_pin_timestamp = self.last_pin_timestamp.clone();
+            cache.increment_generation();
 
             return;
         }
 
         if let Some(mut group) = cache.0.groups.get_mut(&self.channel_id) {
             group.last_pin_timestamp = self.last_pin_timestamp.clone();
+            cache.increment_generation();
         }
     }
 }
@@ -158,6 +177,9 @@ impl UpdateCache for ChannelUpdate {
                 cache.cache_private_channel(c);
             }
         }
+
+        cache.increment_generation();
+        cache.notify(CacheUpdate::ChannelUpdated(self.0.id()));
     }
 }
 
@@ -165,7 +187,15 @@ impl UpdateCache for ChannelUpdate {
 mod tests {
     use super::*;
     use crate::test;
-    use twilight_model::gateway::event::Event;
+    use twilight_model::{
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelType,
+        },
+        gateway::event::Event,
+        guild::Permissions,
+        id::UserId,
+    };
 
     #[test]
     fn test_channel_delete_guild() {
@@ -202,4 +232,50 @@ mod tests {
             .unwrap()
             .contains(&channel_id));
     }
+
+    #[test]
+    fn test_dm_recipient() {
+        let cache = InMemoryCache::new();
+        cache.cache_current_user(test::current_user(1));
+        cache.update(&ChannelCreate(Channel::Private(PrivateChannel {
+            id: ChannelId(2),
+            last_message_id: None,
+            last_pin_timestamp: None,
+            kind: ChannelType::Private,
+            recipients: vec![test::user(UserId(1)), test::user(UserId(3))],
+        })));
+
+        assert_eq!(Some(UserId(3)), cache.dm_recipient(ChannelId(2)));
+        assert!(cache.dm_recipient(ChannelId(4)).is_none());
+    }
+
+    /// Test that a channel update replaces the cached permission overwrites,
+    /// removing overwrites that are no longer present.
+    #[test]
+    fn test_channel_update_removes_permission_overwrite() {
+        let cache = InMemoryCache::new();
+        let (_, channel_id, mut channel) = test::guild_channel_text();
+        let overwrite = PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Member(UserId(3)),
+        };
+
+        if let GuildChannel::Text(ref mut c) = channel {
+            c.permission_overwrites.push(overwrite.clone());
+        }
+
+        cache.update(&ChannelUpdate(Channel::Guild(channel.clone())));
+
+        if let GuildChannel::Text(ref mut c) = channel {
+            c.permission_overwrites.clear();
+        }
+
+        cache.update(&ChannelUpdate(Channel::Guild(channel)));
+
+        match cache.guild_channel(channel_id) {
+            Some(GuildChannel::Text(c)) => assert!(c.permission_overwrites.is_empty()),
+            other => panic!("expected cached text channel, got {:?}", other),
+        }
+    }
 }
@@ -77,6 +77,8 @@ impl UpdateCache for GuildEmojisUpdate {
         }
 
         cache.cache_emojis(self.guild_id, self.emojis.clone());
+
+        cache.increment_generation();
     }
 }
 
@@ -152,6 +154,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_guild_emoji_by_name() {
+        let cache = InMemoryCache::new();
+        let mut wave = test::emoji(EmojiId(1), None);
+        wave.name = "wave".to_owned();
+        let mut smile = test::emoji(EmojiId(2), None);
+        smile.name = "smile".to_owned();
+        cache.cache_emojis(GuildId(1), vec![wave, smile]);
+
+        assert_eq!(
+            EmojiId(2),
+            cache.guild_emoji_by_name(GuildId(1), "smile").unwrap().id
+        );
+        assert_eq!(
+            EmojiId(1),
+            cache.guild_emoji_by_name(GuildId(1), "wave").unwrap().id
+        );
+        assert!(cache.guild_emoji_by_name(GuildId(1), "Wave").is_none());
+        assert!(cache.guild_emoji_by_name(GuildId(1), "unknown").is_none());
+        assert!(cache.guild_emoji_by_name(GuildId(2), "wave").is_none());
+    }
+
     #[test]
     fn test_emoji_removal() {
         let cache = InMemoryCache::new();
@@ -207,4 +231,28 @@ mod tests {
         assert!(cache.0.emojis.is_empty());
         assert!(cache.0.guild_emojis.get(&guild_id).unwrap().is_empty());
     }
+
+    #[test]
+    fn test_emojis_update_generation() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let emote = test::emoji(EmojiId(1), None);
+        let emote_2 = test::emoji(EmojiId(2), None);
+
+        cache.update(&GuildEmojisUpdate {
+            emojis: vec![emote.clone(), emote_2],
+            guild_id,
+        });
+        let generation = cache.generation();
+        assert_eq!(Some(2), cache.stats().guild_emojis(guild_id));
+
+        cache.update(&GuildEmojisUpdate {
+            emojis: vec![emote],
+            guild_id,
+        });
+
+        assert!(cache.generation() > generation);
+        assert_eq!(Some(1), cache.stats().guild_emojis(guild_id));
+        assert_eq!(1, cache.stats().emojis());
+    }
 }
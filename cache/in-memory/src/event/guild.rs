@@ -1,10 +1,10 @@
 use crate::{
     config::ResourceType,
     model::{CachedGuild, CachedPresence},
-    InMemoryCache, UpdateCache,
+    CacheUpdate, InMemoryCache, UpdateCache, UpdateCacheReturning,
 };
 use dashmap::DashMap;
-use std::{collections::HashSet, hash::Hash};
+use std::{collections::HashSet, hash::Hash, mem};
 use twilight_model::{
     gateway::payload::{GuildCreate, GuildDelete, GuildUpdate},
     guild::Guild,
@@ -12,88 +12,144 @@ use twilight_model::{
 };
 
 impl InMemoryCache {
-    fn cache_guild(&self, guild: Guild) {
+    fn cache_guild(&self, mut guild: Guild) {
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
             self.0.guild_channels.insert(guild.id, HashSet::new());
-            self.cache_guild_channels(guild.id, guild.channels);
+            self.cache_guild_channels(guild.id, mem::take(&mut guild.channels));
         }
 
         if self.wants(ResourceType::EMOJI) {
             self.0.guild_emojis.insert(guild.id, HashSet::new());
-            self.cache_emojis(guild.id, guild.emojis);
+            self.cache_emojis(guild.id, mem::take(&mut guild.emojis));
         }
 
         if self.wants(ResourceType::MEMBER) {
             self.0.guild_members.insert(guild.id, HashSet::new());
-            self.cache_members(guild.id, guild.members);
         }
 
         if self.wants(ResourceType::PRESENCE) {
             self.0.guild_presences.insert(guild.id, HashSet::new());
             self.cache_presences(
                 guild.id,
-                guild.presences.into_iter().map(CachedPresence::from),
+                mem::take(&mut guild.presences)
+                    .into_iter()
+                    .map(CachedPresence::from),
             );
         }
 
         if self.wants(ResourceType::ROLE) {
             self.0.guild_roles.insert(guild.id, HashSet::new());
-            self.cache_roles(guild.id, guild.roles);
+            self.cache_roles(guild.id, mem::take(&mut guild.roles));
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
             self.0.voice_state_guilds.insert(guild.id, HashSet::new());
-            self.cache_voice_states(guild.voice_states);
+            self.cache_voice_states(mem::take(&mut guild.voice_states));
         }
 
         if self.wants(ResourceType::STAGE_INSTANCE) {
             self.0
                 .guild_stage_instances
                 .insert(guild.id, HashSet::new());
-            self.cache_stage_instances(guild.id, guild.stage_instances);
+            self.cache_stage_instances(guild.id, mem::take(&mut guild.stage_instances));
         }
 
-        let guild = CachedGuild {
-            id: guild.id,
-            afk_channel_id: guild.afk_channel_id,
-            afk_timeout: guild.afk_timeout,
-            application_id: guild.application_id,
-            banner: guild.banner,
-            default_message_notifications: guild.default_message_notifications,
-            description: guild.description,
-            discovery_splash: guild.discovery_splash,
-            explicit_content_filter: guild.explicit_content_filter,
-            features: guild.features,
-            icon: guild.icon,
-            joined_at: guild.joined_at,
-            large: guild.large,
-            max_members: guild.max_members,
-            max_presences: guild.max_presences,
-            member_count: guild.member_count,
-            mfa_level: guild.mfa_level,
-            name: guild.name,
-            nsfw_level: guild.nsfw_level,
-            owner: guild.owner,
-            owner_id: guild.owner_id,
-            permissions: guild.permissions,
-            preferred_locale: guild.preferred_locale,
-            premium_subscription_count: guild.premium_subscription_count,
-            premium_tier: guild.premium_tier,
-            rules_channel_id: guild.rules_channel_id,
-            splash: guild.splash,
-            system_channel_id: guild.system_channel_id,
-            system_channel_flags: guild.system_channel_flags,
-            unavailable: guild.unavailable,
-            verification_level: guild.verification_level,
-            vanity_url_code: guild.vanity_url_code,
-            widget_channel_id: guild.widget_channel_id,
-            widget_enabled: guild.widget_enabled,
-        };
+        let members = mem::take(&mut guild.members);
+        let guild_id = guild.id;
+        self.0.unavailable_guilds.remove(&guild_id);
+        self.0.guilds.insert(guild_id, cached_guild(guild));
 
-        self.0.unavailable_guilds.remove(&guild.id);
-        self.0.guilds.insert(guild.id, guild);
+        // Members are cached after the guild so that its owner is known when
+        // evicting members over the configured member cache size.
+        if self.wants(ResourceType::MEMBER) {
+            self.cache_members(guild_id, members);
+        }
+    }
+
+    /// Cache a guild that isn't from a gateway event, keeping the guild's
+    /// other cached resources if it's already cached.
+    pub(crate) fn replace_guild(&self, mut guild: Guild) {
+        if !self.wants(ResourceType::GUILD) {
+            return;
+        }
+
+        if !self.0.guilds.contains_key(&guild.id) {
+            self.update(&GuildCreate(guild));
+
+            return;
+        }
+
+        let guild_id = guild.id;
+
+        if self.wants(ResourceType::EMOJI) {
+            self.cache_emojis(guild_id, mem::take(&mut guild.emojis));
+        }
+
+        if self.wants(ResourceType::ROLE) {
+            self.cache_roles(guild_id, mem::take(&mut guild.roles));
+        }
+
+        let mut cached = cached_guild(guild);
+
+        if let Some(mut previous) = self.0.guilds.get_mut(&guild_id) {
+            // Guilds retrieved over HTTP don't include the fields that are only
+            // sent over the gateway, so keep the cached values of those.
+            cached.joined_at = cached.joined_at.or_else(|| previous.joined_at.take());
+            cached.large |= previous.large;
+            cached.member_count = cached.member_count.or(previous.member_count);
+            cached.owner = cached.owner.or(previous.owner);
+            cached.permissions = cached.permissions.or(previous.permissions);
+
+            *previous = cached;
+        } else {
+            self.0.guilds.insert(guild_id, cached);
+        }
+
+        self.increment_generation();
+        self.notify(CacheUpdate::GuildUpdated(guild_id));
+    }
+}
+
+/// Convert a guild into its cached form, ignoring its resources such as
+/// channels and members.
+fn cached_guild(guild: Guild) -> CachedGuild {
+    CachedGuild {
+        id: guild.id,
+        afk_channel_id: guild.afk_channel_id,
+        afk_timeout: guild.afk_timeout,
+        application_id: guild.application_id,
+        banner: guild.banner,
+        default_message_notifications: guild.default_message_notifications,
+        description: guild.description,
+        discovery_splash: guild.discovery_splash,
+        explicit_content_filter: guild.explicit_content_filter,
+        features: guild.features,
+        icon: guild.icon,
+        joined_at: guild.joined_at,
+        large: guild.large,
+        max_members: guild.max_members,
+        max_presences: guild.max_presences,
+        member_count: guild.member_count,
+        mfa_level: guild.mfa_level,
+        name: guild.name,
+        nsfw_level: guild.nsfw_level,
+        owner: guild.owner,
+        owner_id: guild.owner_id,
+        permissions: guild.permissions,
+        preferred_locale: guild.preferred_locale,
+        premium_subscription_count: guild.premium_subscription_count,
+        premium_tier: guild.premium_tier,
+        rules_channel_id: guild.rules_channel_id,
+        splash: guild.splash,
+        system_channel_id: guild.system_channel_id,
+        system_channel_flags: guild.system_channel_flags,
+        unavailable: guild.unavailable,
+        verification_level: guild.verification_level,
+        vanity_url_code: guild.vanity_url_code,
+        widget_channel_id: guild.widget_channel_id,
+        widget_enabled: guild.widget_enabled,
     }
 }
 
@@ -104,6 +160,8 @@ impl UpdateCache for GuildCreate {
         }
 
         cache.cache_guild(self.0.clone());
+        cache.increment_generation();
+        cache.notify(CacheUpdate::GuildAdded(self.0.id));
     }
 }
 
@@ -129,6 +187,14 @@ impl UpdateCache for GuildDelete {
 
         cache.0.guilds.remove(&id);
 
+        if self.unavailable {
+            cache.0.unavailable_guilds.insert(id);
+        }
+
+        if cache.wants(ResourceType::WEBHOOK) {
+            cache.delete_guild_webhooks(id);
+        }
+
         if cache.wants(ResourceType::CHANNEL) {
             remove_ids(&cache.0.guild_channels, &cache.0.channels_guild, id);
         }
@@ -141,17 +207,55 @@ impl UpdateCache for GuildDelete {
             remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
         }
 
+        if cache.wants(ResourceType::SCHEDULED_EVENT) {
+            remove_ids(
+                &cache.0.guild_scheduled_events,
+                &cache.0.scheduled_events,
+                id,
+            );
+        }
+
+        if cache.wants(ResourceType::THREAD) {
+            if let Some((_, ids)) = cache.0.guild_threads.remove(&id) {
+                for thread_id in ids {
+                    cache.delete_thread(thread_id);
+                }
+            }
+        }
+
         if cache.wants(ResourceType::VOICE_STATE) {
             // Clear out a guilds voice states when a guild leaves
             cache.0.voice_state_guilds.remove(&id);
         }
 
+        if cache.wants(ResourceType::VOICE_SERVER) {
+            cache.0.voice_servers.remove(&id);
+        }
+
         if cache.wants(ResourceType::MEMBER) {
             if let Some((_, ids)) = cache.0.guild_members.remove(&id) {
                 for user_id in ids {
                     cache.0.members.remove(&(id, user_id));
                 }
             }
+
+            cache.0.guild_members_order.remove(&id);
+        }
+
+        if cache.wants(ResourceType::INTEGRATION) {
+            if let Some((_, ids)) = cache.0.guild_integrations.remove(&id) {
+                for integration_id in ids {
+                    cache.0.integrations.remove(&(id, integration_id));
+                }
+            }
+        }
+
+        if cache.wants(ResourceType::INVITE) {
+            if let Some((_, codes)) = cache.0.guild_invites.remove(&id) {
+                for code in codes {
+                    cache.0.invites.remove(&code);
+                }
+            }
         }
 
         if cache.wants(ResourceType::PRESENCE) {
@@ -161,49 +265,116 @@ impl UpdateCache for GuildDelete {
                 }
             }
         }
+
+        cache.increment_generation();
+        cache.notify(CacheUpdate::GuildRemoved(id));
     }
 }
 
 impl UpdateCache for GuildUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::GUILD) {
-            return;
+        update_guild(self, cache, false);
+    }
+}
+
+impl UpdateCacheReturning for GuildUpdate {
+    type Previous = CachedGuild;
+
+    fn update_returning(&self, cache: &InMemoryCache) -> Option<CachedGuild> {
+        update_guild(self, cache, true).flatten()
+    }
+}
+
+/// Apply a guild update, returning `None` if the guild isn't cached.
+///
+/// The previously cached guild is only cloned and returned if `keep_previous`
+/// is set.
+fn update_guild(
+    update: &GuildUpdate,
+    cache: &InMemoryCache,
+    keep_previous: bool,
+) -> Option<Option<CachedGuild>> {
+    if !cache.wants(ResourceType::GUILD) {
+        return None;
+    }
+
+    let previous = {
+        let mut guild = cache.0.guilds.get_mut(&update.0.id)?;
+        let previous = if keep_previous {
+            Some(guild.clone())
+        } else {
+            None
+        };
+
+        guild.afk_channel_id = update.afk_channel_id;
+        guild.afk_timeout = update.afk_timeout;
+        guild.application_id = update.application_id;
+        guild.banner = update.banner.clone();
+        guild.default_message_notifications = update.default_message_notifications;
+        guild.description = update.description.clone();
+        guild.discovery_splash = update.discovery_splash.clone();
+        guild.explicit_content_filter = update.explicit_content_filter;
+        guild.features = update.features.clone();
+        guild.icon = update.icon.clone();
+        guild.max_presences = Some(update.max_presences.unwrap_or(25000));
+        guild.mfa_level = update.mfa_level;
+        guild.name = update.name.clone();
+        guild.nsfw_level = update.nsfw_level;
+        guild.owner_id = update.owner_id;
+        guild.preferred_locale = update.preferred_locale.clone();
+        guild.premium_tier = update.premium_tier;
+        guild.rules_channel_id = update.rules_channel_id;
+        guild.splash = update.splash.clone();
+        guild.system_channel_flags = update.system_channel_flags;
+        guild.system_channel_id = update.system_channel_id;
+        guild.verification_level = update.verification_level;
+        guild.vanity_url_code = update.vanity_url_code.clone();
+
+        // These fields aren't always sent, so only replace the cached
+        // values if they were.
+        if let Some(max_members) = update.max_members {
+            guild.max_members.replace(max_members);
+        }
+
+        if let Some(member_count) = update.member_count {
+            guild.member_count.replace(member_count);
+        }
+
+        if let Some(owner) = update.owner {
+            guild.owner.replace(owner);
+        }
+
+        if let Some(permissions) = update.permissions {
+            guild.permissions.replace(permissions);
         }
 
-        if let Some(mut guild) = cache.0.guilds.get_mut(&self.0.id) {
-            guild.afk_channel_id = self.afk_channel_id;
-            guild.afk_timeout = self.afk_timeout;
-            guild.banner = self.banner.clone();
-            guild.default_message_notifications = self.default_message_notifications;
-            guild.description = self.description.clone();
-            guild.features = self.features.clone();
-            guild.icon = self.icon.clone();
-            guild.max_members = self.max_members;
-            guild.max_presences = Some(self.max_presences.unwrap_or(25000));
-            guild.mfa_level = self.mfa_level;
-            guild.name = self.name.clone();
-            guild.nsfw_level = self.nsfw_level;
-            guild.owner = self.owner;
-            guild.owner_id = self.owner_id;
-            guild.permissions = self.permissions;
-            guild.preferred_locale = self.preferred_locale.clone();
-            guild.premium_tier = self.premium_tier;
+        if let Some(premium_subscription_count) = update.premium_subscription_count {
             guild
                 .premium_subscription_count
-                .replace(self.premium_subscription_count.unwrap_or_default());
-            guild.splash = self.splash.clone();
-            guild.system_channel_id = self.system_channel_id;
-            guild.verification_level = self.verification_level;
-            guild.vanity_url_code = self.vanity_url_code.clone();
-            guild.widget_channel_id = self.widget_channel_id;
-            guild.widget_enabled = self.widget_enabled;
-        };
-    }
+                .replace(premium_subscription_count);
+        }
+
+        if let Some(widget_channel_id) = update.widget_channel_id {
+            guild.widget_channel_id = widget_channel_id;
+        }
+
+        if let Some(widget_enabled) = update.widget_enabled {
+            guild.widget_enabled.replace(widget_enabled);
+        }
+
+        previous
+    };
+
+    cache.increment_generation();
+    cache.notify(CacheUpdate::GuildUpdated(update.0.id));
+
+    Some(previous)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test;
     use twilight_model::{
         channel::{ChannelType, GuildChannel, TextChannel},
         guild::{
@@ -378,14 +549,108 @@ mod tests {
             system_channel_id: guild.system_channel_id,
             verification_level: guild.verification_level,
             vanity_url_code: guild.vanity_url_code,
-            widget_channel_id: guild.widget_channel_id,
-            widget_enabled: guild.widget_enabled,
+            widget_channel_id: Some(Some(ChannelId(3))),
+            widget_enabled: Some(true),
         };
 
+        assert_eq!(None, cache.guild_widget_enabled(guild.id));
+        assert_eq!(None, cache.guild_widget_channel_id(guild.id));
+
         cache.update(&GuildUpdate(mutation.clone()));
 
         assert_eq!(cache.guild(guild.id).unwrap().name, mutation.name);
         assert_eq!(cache.guild(guild.id).unwrap().owner_id, mutation.owner_id);
         assert_eq!(cache.guild(guild.id).unwrap().id, mutation.id);
+        assert_eq!(Some(true), cache.guild_widget_enabled(guild.id));
+        assert_eq!(Some(ChannelId(3)), cache.guild_widget_channel_id(guild.id));
+    }
+
+    #[test]
+    fn test_guild_update_keeps_unsent_fields() {
+        let cache = InMemoryCache::new();
+        let mut guild = test::guild(GuildId(1), UserId(2));
+        guild.joined_at = Some("2021-01-01T00:00:00.000000+00:00".to_owned());
+        guild.large = true;
+        guild.member_count = Some(300);
+        guild.owner = Some(false);
+        guild.permissions = Some(Permissions::SEND_MESSAGES);
+        guild.premium_subscription_count = Some(7);
+        guild.widget_channel_id = Some(ChannelId(4));
+        guild.widget_enabled = Some(true);
+        cache.update(&GuildCreate(guild.clone()));
+
+        let mut update = PartialGuild {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            application_id: guild.application_id,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description,
+            discovery_splash: guild.discovery_splash,
+            emojis: guild.emojis,
+            explicit_content_filter: guild.explicit_content_filter,
+            features: guild.features,
+            icon: guild.icon,
+            max_members: None,
+            max_presences: None,
+            member_count: None,
+            mfa_level: guild.mfa_level,
+            name: "renamed".to_owned(),
+            nsfw_level: guild.nsfw_level,
+            owner_id: guild.owner_id,
+            owner: None,
+            permissions: None,
+            preferred_locale: guild.preferred_locale,
+            premium_subscription_count: None,
+            premium_tier: guild.premium_tier,
+            roles: guild.roles,
+            rules_channel_id: Some(ChannelId(3)),
+            splash: guild.splash,
+            system_channel_flags: guild.system_channel_flags,
+            system_channel_id: guild.system_channel_id,
+            verification_level: guild.verification_level,
+            vanity_url_code: guild.vanity_url_code,
+            widget_channel_id: None,
+            widget_enabled: None,
+        };
+        cache.update(&GuildUpdate(update.clone()));
+
+        let cached = cache.guild(guild.id).unwrap();
+        assert_eq!("renamed", cached.name);
+        assert_eq!(Some(ChannelId(3)), cached.rules_channel_id);
+        assert_eq!(guild.joined_at, cached.joined_at);
+        assert!(cached.large);
+        assert_eq!(Some(300), cached.member_count);
+        assert_eq!(Some(false), cached.owner);
+        assert_eq!(Some(Permissions::SEND_MESSAGES), cached.permissions);
+        assert_eq!(Some(7), cached.premium_subscription_count);
+        assert_eq!(Some(ChannelId(4)), cached.widget_channel_id);
+        assert_eq!(Some(true), cached.widget_enabled);
+
+        // A null widget channel clears it.
+        update.widget_channel_id = Some(None);
+        cache.update(&GuildUpdate(update));
+        assert!(cache.guild_widget_channel_id(guild.id).is_none());
+    }
+
+    #[test]
+    fn test_insert_guild_keeps_gateway_fields() {
+        let cache = InMemoryCache::new();
+        let mut guild = test::guild(GuildId(1), UserId(2));
+        guild.joined_at = Some("2021-01-01T00:00:00.000000+00:00".to_owned());
+        guild.large = true;
+        guild.member_count = Some(300);
+        cache.update(&GuildCreate(guild.clone()));
+
+        let mut rest_guild = test::guild(GuildId(1), UserId(2));
+        rest_guild.name = "renamed".to_owned();
+        cache.insert_guild(rest_guild);
+
+        let cached = cache.guild(guild.id).unwrap();
+        assert_eq!("renamed", cached.name);
+        assert_eq!(guild.joined_at, cached.joined_at);
+        assert!(cached.large);
+        assert_eq!(Some(300), cached.member_count);
     }
 }
@@ -42,13 +42,10 @@ impl UpdateCache for IntegrationCreate {
         }
 
         if let Some(guild_id) = self.guild_id {
-            crate::upsert_guild_item(
-                &cache.0.integrations,
-                guild_id,
-                (guild_id, self.id),
-                self.0.clone(),
-            );
+            cache.cache_integration(guild_id, self.0.clone());
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -59,6 +56,8 @@ impl UpdateCache for IntegrationDelete {
         }
 
         cache.delete_integration(self.guild_id, self.id);
+
+        cache.increment_generation();
     }
 }
 
@@ -71,5 +70,62 @@ impl UpdateCache for IntegrationUpdate {
         if let Some(guild_id) = self.guild_id {
             cache.cache_integration(guild_id, self.0.clone());
         }
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twilight_model::guild::IntegrationAccount;
+
+    fn integration(guild_id: GuildId, id: IntegrationId) -> GuildIntegration {
+        GuildIntegration {
+            account: IntegrationAccount {
+                id: "abcd".to_owned(),
+                name: "account name".to_owned(),
+            },
+            application: None,
+            enable_emoticons: None,
+            enabled: true,
+            expire_behavior: None,
+            expire_grace_period: None,
+            guild_id: Some(guild_id),
+            id,
+            kind: "twitch".to_owned(),
+            name: "integration".to_owned(),
+            revoked: None,
+            role_id: None,
+            subscriber_count: None,
+            synced_at: None,
+            syncing: None,
+            user: None,
+        }
+    }
+
+    #[test]
+    fn test_guild_integrations() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let first = integration(guild_id, IntegrationId(2));
+        let second = integration(guild_id, IntegrationId(3));
+
+        cache.update(&IntegrationCreate(first.clone()));
+        cache.update(&IntegrationCreate(second.clone()));
+
+        let mut integrations = cache.guild_integrations(guild_id).unwrap();
+        integrations.sort_by_key(|integration| integration.id);
+        assert_eq!(vec![first.clone(), second.clone()], integrations);
+        assert_eq!(Some(first), cache.integration(guild_id, IntegrationId(2)));
+
+        cache.update(&IntegrationDelete {
+            application_id: None,
+            guild_id,
+            id: IntegrationId(2),
+        });
+
+        assert!(cache.integration(guild_id, IntegrationId(2)).is_none());
+        assert_eq!(Some(vec![second]), cache.guild_integrations(guild_id));
     }
 }
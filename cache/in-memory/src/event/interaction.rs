@@ -4,12 +4,19 @@ use twilight_model::{application::interaction::Interaction, gateway::payload::In
 
 impl UpdateCache for InteractionCreate {
     fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::USER)
+            && !cache.wants(ResourceType::MEMBER)
+            && !cache.wants(ResourceType::ROLE)
+        {
+            return;
+        }
+
         #[allow(clippy::single_match)]
         match &self.0 {
             Interaction::ApplicationCommand(command) => {
                 if cache.wants(ResourceType::MEMBER) {
                     if let Some(member) = &command.member {
-                        if let Some(user) = &member.user {
+                        if let Some(user) = member.user.as_ref().filter(|u| cache.wants_user(u)) {
                             cache.cache_user(Cow::Borrowed(user), command.guild_id);
 
                             cache.cache_borrowed_partial_member(
@@ -29,7 +36,10 @@ impl UpdateCache for InteractionCreate {
                     for u in &resolved.users {
                         cache.cache_user(Cow::Borrowed(u), command.guild_id);
 
-                        if !cache.wants(ResourceType::MEMBER) || command.guild_id.is_none() {
+                        if !cache.wants(ResourceType::MEMBER)
+                            || command.guild_id.is_none()
+                            || !cache.wants_user(u)
+                        {
                             continue;
                         }
 
@@ -48,6 +58,8 @@ impl UpdateCache for InteractionCreate {
                         }
                     }
                 }
+
+                cache.increment_generation();
             }
             _ => {}
         };
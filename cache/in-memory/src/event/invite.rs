@@ -0,0 +1,106 @@
+use crate::{config::ResourceType, model::CachedInvite, InMemoryCache, UpdateCache};
+use twilight_model::gateway::payload::{InviteCreate, InviteDelete};
+
+impl InMemoryCache {
+    fn cache_invite(&self, invite: CachedInvite) {
+        self.0
+            .guild_invites
+            .entry(invite.guild_id)
+            .or_default()
+            .insert(invite.code.clone());
+
+        self.0.invites.insert(invite.code.clone(), invite);
+    }
+
+    fn delete_invite(&self, invite: &InviteDelete) {
+        if self.0.invites.remove(&invite.code).is_some() {
+            if let Some(mut invites) = self.0.guild_invites.get_mut(&invite.guild_id) {
+                invites.remove(&invite.code);
+            }
+        }
+    }
+}
+
+impl UpdateCache for InviteCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.cache_invite(CachedInvite::from(self.clone()));
+
+        cache.increment_generation();
+    }
+}
+
+impl UpdateCache for InviteDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.delete_invite(self);
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InMemoryCache;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::{InviteCreate, InviteDelete},
+        },
+        id::{ChannelId, GuildId},
+    };
+
+    fn invite_create(code: &str) -> InviteCreate {
+        InviteCreate {
+            channel_id: ChannelId(2),
+            code: code.to_owned(),
+            created_at: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+            guild_id: GuildId(1),
+            inviter: None,
+            max_age: 86400,
+            max_uses: 0,
+            target_user_type: None,
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        }
+    }
+
+    #[test]
+    fn test_invite_create_and_delete() {
+        let cache = InMemoryCache::new();
+
+        cache.update(&Event::InviteCreate(Box::new(invite_create("twilight"))));
+        cache.update(&Event::InviteCreate(Box::new(invite_create("rust"))));
+
+        let invite = cache.invite("twilight").unwrap();
+        assert_eq!(GuildId(1), invite.guild_id);
+        assert_eq!(ChannelId(2), invite.channel_id);
+
+        let mut codes = cache.guild_invites(GuildId(1)).unwrap();
+        codes.sort();
+        assert_eq!(vec!["rust".to_owned(), "twilight".to_owned()], codes);
+
+        cache.update(&Event::InviteDelete(InviteDelete {
+            channel_id: ChannelId(2),
+            code: "twilight".to_owned(),
+            guild_id: GuildId(1),
+        }));
+
+        assert!(cache.invite("twilight").is_none());
+        assert_eq!(
+            vec!["rust".to_owned()],
+            cache.guild_invites(GuildId(1)).unwrap()
+        );
+
+        cache.clear();
+        assert!(cache.invite("rust").is_none());
+        assert!(cache.guild_invites(GuildId(1)).is_none());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{config::ResourceType, model::CachedMember, InMemoryCache, UpdateCache};
+use crate::{
+    config::ResourceType, model::CachedMember, CacheUpdate, InMemoryCache, UpdateCache,
+    UpdateCacheReturning,
+};
 use std::borrow::Cow;
 use twilight_model::{
     application::interaction::application_command::InteractionMember,
@@ -19,11 +22,18 @@ impl InMemoryCache {
     }
 
     pub(crate) fn cache_member(&self, guild_id: GuildId, member: Member) {
+        if !self.wants_user(&member.user) {
+            return;
+        }
+
         let member_id = member.user.id;
         let id = (guild_id, member_id);
 
         if let Some(m) = self.0.members.get(&id) {
             if *m == member {
+                drop(m);
+                self.touch_member(guild_id, member_id);
+
                 return;
             }
         }
@@ -48,6 +58,7 @@ impl InMemoryCache {
             .entry(guild_id)
             .or_default()
             .insert(member_id);
+        self.touch_member(guild_id, member_id);
     }
 
     pub(crate) fn cache_borrowed_partial_member(
@@ -60,6 +71,9 @@ impl InMemoryCache {
 
         if let Some(m) = self.0.members.get(&id) {
             if *m == member {
+                drop(m);
+                self.touch_member(guild_id, user_id);
+
                 return;
             }
         }
@@ -82,6 +96,7 @@ impl InMemoryCache {
             user_id,
         };
         self.0.members.insert(id, cached);
+        self.touch_member(guild_id, user_id);
     }
 
     pub(crate) fn cache_borrowed_interaction_member(
@@ -92,7 +107,12 @@ impl InMemoryCache {
         let id = (guild_id, member.id);
 
         let (deaf, mute) = match self.0.members.get(&id) {
-            Some(m) if *m == member => return,
+            Some(m) if *m == member => {
+                drop(m);
+                self.touch_member(guild_id, member.id);
+
+                return;
+            }
             Some(m) => (m.deaf, m.mute),
             None => (None, None),
         };
@@ -116,6 +136,110 @@ impl InMemoryCache {
         };
 
         self.0.members.insert(id, cached);
+        self.touch_member(guild_id, member.id);
+    }
+
+    /// Mark a member as the most recently accessed member of its guild.
+    ///
+    /// If a member cache size is configured and the guild has more members
+    /// cached than allowed, then the least recently accessed members are
+    /// evicted. The current user and the guild owner are never evicted.
+    pub(crate) fn touch_member(&self, guild_id: GuildId, user_id: UserId) {
+        let member_cache_size = match self.0.config.member_cache_size() {
+            Some(member_cache_size) => member_cache_size,
+            None => return,
+        };
+
+        let evicted = {
+            let mut order = self.0.guild_members_order.entry(guild_id).or_default();
+            order.touch(user_id);
+
+            let mut excess = order.len().saturating_sub(member_cache_size);
+
+            if excess == 0 {
+                return;
+            }
+
+            let owner_id = self.0.guilds.get(&guild_id).map(|guild| guild.owner_id);
+            let current_user_id = self.current_user().map(|user| user.id);
+            let mut evicted = Vec::new();
+            let mut kept = Vec::new();
+
+            while excess > 0 {
+                let id = match order.pop_oldest() {
+                    Some(id) => id,
+                    None => break,
+                };
+
+                if Some(id) == owner_id || Some(id) == current_user_id {
+                    kept.push(id);
+
+                    continue;
+                }
+
+                evicted.push(id);
+                excess -= 1;
+            }
+
+            for id in kept {
+                order.touch(id);
+            }
+
+            evicted
+        };
+
+        for id in evicted {
+            self.remove_member(guild_id, id);
+        }
+    }
+
+    /// Mark a member read from the cache as the most recently accessed member
+    /// of its guild.
+    ///
+    /// Unlike [`touch_member`], this never evicts members, and members that
+    /// aren't tracked are ignored.
+    ///
+    /// [`touch_member`]: Self::touch_member
+    pub(crate) fn access_member(&self, guild_id: GuildId, user_id: UserId) {
+        if self.0.config.member_cache_size().is_none() {
+            return;
+        }
+
+        if let Some(mut order) = self.0.guild_members_order.get_mut(&guild_id) {
+            if order.contains(user_id) {
+                order.touch(user_id);
+            }
+        }
+    }
+
+    pub(crate) fn remove_member(&self, guild_id: GuildId, user_id: UserId) {
+        self.0.members.remove(&(guild_id, user_id));
+
+        if let Some(mut members) = self.0.guild_members.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+
+        if let Some(mut order) = self.0.guild_members_order.get_mut(&guild_id) {
+            order.remove(user_id);
+        }
+
+        // Avoid a deadlock by mutating the user, dropping the lock to the map,
+        // and then maybe conditionally removing the user later.
+        let mut maybe_remove_user = false;
+
+        if let Some(mut user_tuple) = self.0.users.get_mut(&user_id) {
+            user_tuple.1.remove(&guild_id);
+
+            maybe_remove_user = true;
+        }
+
+        if maybe_remove_user {
+            self.0
+                .users
+                .remove_if(&user_id, |_, guild_set| guild_set.1.is_empty());
+        }
+
+        self.notify(CacheUpdate::MemberRemoved { guild_id, user_id });
     }
 }
 
@@ -127,12 +251,11 @@ impl UpdateCache for MemberAdd {
 
         cache.cache_member(self.guild_id, self.0.clone());
 
-        cache
-            .0
-            .guild_members
-            .entry(self.guild_id)
-            .or_default()
-            .insert(self.0.user.id);
+        cache.increment_generation();
+        cache.notify(CacheUpdate::MemberAdded {
+            guild_id: self.guild_id,
+            user_id: self.0.user.id,
+        });
     }
 }
 
@@ -147,8 +270,7 @@ impl UpdateCache for MemberChunk {
         }
 
         cache.cache_members(self.guild_id, self.members.clone());
-        let mut guild = cache.0.guild_members.entry(self.guild_id).or_default();
-        guild.extend(self.members.iter().map(|member| member.user.id));
+        cache.increment_generation();
     }
 }
 
@@ -158,49 +280,68 @@ impl UpdateCache for MemberRemove {
             return;
         }
 
-        cache.0.members.remove(&(self.guild_id, self.user.id));
+        cache.remove_member(self.guild_id, self.user.id);
 
-        if let Some(mut members) = cache.0.guild_members.get_mut(&self.guild_id) {
-            members.remove(&self.user.id);
-        }
-
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then maybe conditionally removing the user later.
-        let mut maybe_remove_user = false;
+        cache.increment_generation();
+    }
+}
 
-        if let Some(mut user_tuple) = cache.0.users.get_mut(&self.user.id) {
-            user_tuple.1.remove(&self.guild_id);
+impl UpdateCache for MemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        update_member(self, cache, false);
+    }
+}
 
-            maybe_remove_user = true;
-        }
+impl UpdateCacheReturning for MemberUpdate {
+    type Previous = CachedMember;
 
-        if maybe_remove_user {
-            cache
-                .0
-                .users
-                .remove_if(&self.user.id, |_, guild_set| guild_set.1.is_empty());
-        }
+    fn update_returning(&self, cache: &InMemoryCache) -> Option<CachedMember> {
+        update_member(self, cache, true).flatten()
     }
 }
 
-impl UpdateCache for MemberUpdate {
-    fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MEMBER) {
-            return;
-        }
+/// Apply a member update, returning `None` if the member isn't cached.
+///
+/// The previously cached member is only cloned and returned if
+/// `keep_previous` is set.
+fn update_member(
+    update: &MemberUpdate,
+    cache: &InMemoryCache,
+    keep_previous: bool,
+) -> Option<Option<CachedMember>> {
+    if !cache.wants(ResourceType::MEMBER) {
+        return None;
+    }
 
-        let mut member = match cache.0.members.get_mut(&(self.guild_id, self.user.id)) {
-            Some(member) => member,
-            None => return,
+    let previous = {
+        let mut member = cache
+            .0
+            .members
+            .get_mut(&(update.guild_id, update.user.id))?;
+        let previous = if keep_previous {
+            Some(member.clone())
+        } else {
+            None
         };
 
-        member.deaf = self.deaf.or(member.deaf);
-        member.mute = self.mute.or(member.mute);
-        member.nick = self.nick.clone();
-        member.roles = self.roles.clone();
-        member.joined_at.replace(self.joined_at.clone());
-        member.pending = self.pending;
-    }
+        member.deaf = update.deaf.or(member.deaf);
+        member.mute = update.mute.or(member.mute);
+        member.nick = update.nick.clone();
+        member.roles = update.roles.clone();
+        member.joined_at.replace(update.joined_at.clone());
+        member.pending = update.pending;
+
+        previous
+    };
+
+    cache.touch_member(update.guild_id, update.user.id);
+    cache.increment_generation();
+    cache.notify(CacheUpdate::MemberUpdated {
+        guild_id: update.guild_id,
+        user_id: update.user.id,
+    });
+
+    Some(previous)
 }
 
 #[cfg(test)]
@@ -308,4 +449,63 @@ mod tests {
         });
         assert!(!cache.0.users.contains_key(&user_id));
     }
+
+    #[test]
+    fn test_member_chunk_empty() {
+        let cache = InMemoryCache::new();
+        cache.update(&MemberChunk {
+            chunk_count: 1,
+            chunk_index: 0,
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            nonce: None,
+            not_found: vec![UserId(2)],
+            presences: Vec::new(),
+        });
+
+        assert_eq!(0, cache.generation());
+    }
+
+    #[test]
+    fn test_member_cache_size_evicts_oldest() {
+        let cache = InMemoryCache::builder().member_cache_size(2).build();
+
+        for id in 1..=3 {
+            cache.update(&MemberAdd(test::member(UserId(id), GuildId(1))));
+        }
+
+        assert!(cache.member(GuildId(1), UserId(1)).is_none());
+        assert!(cache.member(GuildId(1), UserId(2)).is_some());
+        assert!(cache.member(GuildId(1), UserId(3)).is_some());
+        assert_eq!(2, cache.guild_members(GuildId(1)).unwrap().len());
+        assert!(cache.user(UserId(1)).is_none());
+    }
+
+    #[test]
+    fn test_member_cache_size_evicts_least_recently_read() {
+        let cache = InMemoryCache::builder().member_cache_size(2).build();
+        cache.update(&MemberAdd(test::member(UserId(1), GuildId(1))));
+        cache.update(&MemberAdd(test::member(UserId(2), GuildId(1))));
+
+        assert!(cache.member(GuildId(1), UserId(1)).is_some());
+        cache.update(&MemberAdd(test::member(UserId(3), GuildId(1))));
+
+        assert!(cache.member(GuildId(1), UserId(1)).is_some());
+        assert!(cache.member(GuildId(1), UserId(2)).is_none());
+        assert!(cache.member(GuildId(1), UserId(3)).is_some());
+    }
+
+    #[test]
+    fn test_member_cache_size_keeps_current_user() {
+        let cache = InMemoryCache::builder().member_cache_size(2).build();
+        cache.cache_current_user(test::current_user(1));
+
+        for id in 1..=3 {
+            cache.update(&MemberAdd(test::member(UserId(id), GuildId(1))));
+        }
+
+        assert!(cache.member(GuildId(1), UserId(1)).is_some());
+        assert!(cache.member(GuildId(1), UserId(2)).is_none());
+        assert!(cache.member(GuildId(1), UserId(3)).is_some());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{config::ResourceType, model::CachedMessage, InMemoryCache, UpdateCache};
+use crate::{config::ResourceType, model::CachedMessage, CacheUpdate, InMemoryCache, UpdateCache};
 use std::borrow::Cow;
 use twilight_model::gateway::payload::{
     MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
@@ -6,29 +6,41 @@ use twilight_model::gateway::payload::{
 
 impl UpdateCache for MessageCreate {
     fn update(&self, cache: &InMemoryCache) {
+        let wants_message = cache.wants(ResourceType::MESSAGE);
+
+        if !wants_message && !cache.wants(ResourceType::USER) && !cache.wants(ResourceType::MEMBER)
+        {
+            return;
+        }
+
         if cache.wants(ResourceType::USER) {
             cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
         }
 
-        if let (Some(member), Some(guild_id), true) = (
-            &self.member,
-            self.guild_id,
-            cache.wants(ResourceType::MEMBER),
-        ) {
-            cache.cache_borrowed_partial_member(guild_id, member, self.author.id)
+        if cache.wants(ResourceType::MEMBER) && cache.wants_user(&self.author) {
+            if let (Some(member), Some(guild_id)) = (&self.member, self.guild_id) {
+                cache.cache_borrowed_partial_member(guild_id, member, self.author.id);
+            }
         }
 
-        if !cache.wants(ResourceType::MESSAGE) {
-            return;
-        }
+        if wants_message {
+            let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
 
-        let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
+            if channel.len() > cache.0.config.message_cache_size() {
+                channel.pop_back();
+            }
 
-        if channel.len() > cache.0.config.message_cache_size() {
-            channel.pop_back();
+            channel.push_front(CachedMessage::from(self.0.clone()));
         }
 
-        channel.push_front(CachedMessage::from(self.0.clone()));
+        cache.increment_generation();
+
+        if wants_message {
+            cache.notify(CacheUpdate::MessageAdded {
+                channel_id: self.0.channel_id,
+                message_id: self.0.id,
+            });
+        }
     }
 }
 
@@ -38,10 +50,22 @@ impl UpdateCache for MessageDelete {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        let removed = {
+            let mut channel = cache.0.messages.entry(self.channel_id).or_default();
 
-        if let Some(idx) = channel.iter().position(|msg| msg.id == self.id) {
-            channel.remove(idx);
+            channel
+                .iter()
+                .position(|msg| msg.id == self.id)
+                .and_then(|idx| channel.remove(idx))
+                .is_some()
+        };
+
+        if removed {
+            cache.increment_generation();
+            cache.notify(CacheUpdate::MessageRemoved {
+                channel_id: self.channel_id,
+                message_id: self.id,
+            });
         }
     }
 }
@@ -52,13 +76,31 @@ impl UpdateCache for MessageDeleteBulk {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        let mut removed = Vec::new();
 
-        for id in &self.ids {
-            if let Some(idx) = channel.iter().position(|msg| &msg.id == id) {
-                channel.remove(idx);
+        {
+            let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+
+            for id in &self.ids {
+                if let Some(idx) = channel.iter().position(|msg| &msg.id == id) {
+                    channel.remove(idx);
+                    removed.push(*id);
+                }
             }
         }
+
+        if removed.is_empty() {
+            return;
+        }
+
+        cache.increment_generation();
+
+        for message_id in removed {
+            cache.notify(CacheUpdate::MessageRemoved {
+                channel_id: self.channel_id,
+                message_id,
+            });
+        }
     }
 }
 
@@ -68,9 +110,14 @@ impl UpdateCache for MessageUpdate {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        {
+            let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+
+            let message = match channel.iter_mut().find(|msg| msg.id == self.id) {
+                Some(message) => message,
+                None => return,
+            };
 
-        if let Some(mut message) = channel.iter_mut().find(|msg| msg.id == self.id) {
             if let Some(attachments) = &self.attachments {
                 message.attachments = attachments.clone();
             }
@@ -111,26 +158,31 @@ impl UpdateCache for MessageUpdate {
                 message.tts = tts;
             }
         }
+
+        cache.increment_generation();
+        cache.notify(CacheUpdate::MessageUpdated {
+            channel_id: self.channel_id,
+            message_id: self.id,
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{
+        convert::TryFrom,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
     use twilight_model::{
-        channel::message::{Message, MessageFlags, MessageType},
+        channel::message::{Mention, Message, MessageFlags, MessageType},
         guild::PartialMember,
-        id::{ChannelId, GuildId, MessageId, UserId},
-        user::User,
+        id::{ChannelId, GuildId, MessageId, RoleId, UserId, DISCORD_EPOCH},
+        user::{User, UserFlags},
     };
 
-    #[test]
-    fn test_message_create() {
-        let cache = InMemoryCache::builder()
-            .resource_types(ResourceType::MESSAGE | ResourceType::MEMBER | ResourceType::USER)
-            .message_cache_size(1)
-            .build();
-        let msg = Message {
+    fn message() -> Message {
+        Message {
             activity: None,
             application: None,
             application_id: None,
@@ -181,7 +233,16 @@ mod tests {
             timestamp: String::new(),
             tts: false,
             webhook_id: None,
-        };
+        }
+    }
+
+    #[test]
+    fn test_message_create() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::MESSAGE | ResourceType::MEMBER | ResourceType::USER)
+            .message_cache_size(1)
+            .build();
+        let msg = message();
 
         cache.update(&MessageCreate(msg));
 
@@ -198,4 +259,106 @@ mod tests {
             assert_eq!(entry.value().len(), 1);
         }
     }
+
+    #[test]
+    fn test_message_create_not_wanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::ROLE)
+            .build();
+        cache.update(&MessageCreate(message()));
+
+        assert!(cache.0.messages.is_empty());
+        assert_eq!(0, cache.generation());
+    }
+
+    #[test]
+    fn test_message_create_mentions() {
+        let cache = InMemoryCache::new();
+        let mut msg = message();
+        msg.mention_everyone = true;
+        msg.mention_roles = vec![RoleId(5)];
+        msg.mentions = vec![Mention {
+            avatar: None,
+            bot: false,
+            discriminator: "0002".to_owned(),
+            id: UserId(6),
+            member: None,
+            name: "mentioned".to_owned(),
+            public_flags: UserFlags::empty(),
+        }];
+
+        cache.update(&MessageCreate(msg));
+
+        let cached = cache.message(ChannelId(2), MessageId(4)).unwrap();
+        assert!(cached.mention_everyone);
+        assert_eq!(vec![RoleId(5)], cached.mention_roles);
+        assert_eq!(vec![UserId(6)], cached.mentions);
+    }
+
+    #[test]
+    fn test_message_update_edited() {
+        let cache = InMemoryCache::new();
+        cache.update(&MessageCreate(message()));
+        assert!(cache.message_edited(ChannelId(2), MessageId(4)).is_none());
+
+        cache.update(&MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id: ChannelId(2),
+            content: Some("pong".to_owned()),
+            edited_timestamp: Some("2021-08-10T12:18:37.000000+00:00".to_owned()),
+            embeds: None,
+            guild_id: Some(GuildId(1)),
+            id: MessageId(4),
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: None,
+            timestamp: None,
+            tts: None,
+        });
+
+        assert_eq!(
+            Some("2021-08-10T12:18:37.000000+00:00"),
+            cache.message_edited(ChannelId(2), MessageId(4)).as_deref()
+        );
+        assert_eq!(
+            "pong",
+            cache.message(ChannelId(2), MessageId(4)).unwrap().content
+        );
+        assert!(cache.message_edited(ChannelId(2), MessageId(5)).is_none());
+    }
+
+    #[test]
+    fn test_prune_messages() {
+        let message_id = |age: Duration, increment| {
+            let created_at = SystemTime::now() - age;
+            let millis = created_at.duration_since(UNIX_EPOCH).unwrap().as_millis();
+            let millis = u64::try_from(millis).unwrap();
+
+            MessageId(((millis - DISCORD_EPOCH) << 22) | increment)
+        };
+        let hour = Duration::from_secs(60 * 60);
+
+        let cache = InMemoryCache::new();
+        let old = [
+            (ChannelId(2), message_id(hour * 3, 0)),
+            (ChannelId(3), message_id(hour * 2, 1)),
+        ];
+        let new = [(ChannelId(2), message_id(Duration::from_secs(60), 2))];
+
+        for (channel_id, id) in old.iter().chain(new.iter()) {
+            let mut msg = message();
+            msg.channel_id = *channel_id;
+            msg.id = *id;
+            cache.update(&MessageCreate(msg));
+        }
+
+        assert_eq!(2, cache.prune_messages(hour));
+        assert!(cache.message(ChannelId(2), new[0].1).is_some());
+        assert!(cache.message(ChannelId(2), old[0].1).is_none());
+        assert!(cache.stats().channel_messages(ChannelId(3)).is_none());
+        assert_eq!(0, cache.prune_messages(hour));
+    }
 }
@@ -3,13 +3,19 @@ pub mod emoji;
 pub mod guild;
 pub mod integration;
 pub mod interaction;
+pub mod invite;
 pub mod member;
 pub mod message;
 pub mod presence;
 pub mod reaction;
 pub mod role;
+pub mod scheduled_event;
 pub mod stage_instance;
+pub mod thread;
+pub mod typing;
+pub mod voice_server;
 pub mod voice_state;
+pub mod webhook;
 
 use crate::{config::ResourceType, InMemoryCache, UpdateCache};
 use std::{borrow::Cow, collections::BTreeSet};
@@ -29,6 +35,10 @@ impl InMemoryCache {
     }
 
     fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<GuildId>) {
+        if !self.wants(ResourceType::USER) || !self.wants_user(&user) {
+            return;
+        }
+
         match self.0.users.get_mut(&user.id) {
             Some(mut u) if u.0 == *user => {
                 if let Some(guild_id) = guild_id {
@@ -56,6 +66,10 @@ impl InMemoryCache {
 
 impl UpdateCache for Ready {
     fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::USER_CURRENT) && !cache.wants(ResourceType::GUILD) {
+            return;
+        }
+
         if cache.wants(ResourceType::USER_CURRENT) {
             cache.cache_current_user(self.user.clone());
         }
@@ -65,6 +79,8 @@ impl UpdateCache for Ready {
                 cache.unavailable_guild(guild.id);
             }
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -76,6 +92,8 @@ impl UpdateCache for UnavailableGuild {
 
         cache.0.guilds.remove(&self.id);
         cache.0.unavailable_guilds.insert(self.id);
+
+        cache.increment_generation();
     }
 }
 
@@ -86,6 +104,8 @@ impl UpdateCache for UserUpdate {
         }
 
         cache.cache_current_user(self.0.clone());
+
+        cache.increment_generation();
     }
 }
 
@@ -93,6 +113,10 @@ impl UpdateCache for UserUpdate {
 mod tests {
     use super::*;
     use crate::test;
+    use twilight_model::{
+        gateway::payload::{GuildCreate, GuildDelete},
+        id::UserId,
+    };
 
     /// Test retrieval of the current user, notably that it doesn't simply
     /// panic or do anything funny. This is the only synchronous mutex that we
@@ -104,4 +128,25 @@ mod tests {
         cache.cache_current_user(test::current_user(1));
         assert!(cache.current_user().is_some());
     }
+
+    #[test]
+    fn test_unavailable_guild() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        assert!(!cache.guild_is_unavailable(guild_id));
+
+        cache.update(&UnavailableGuild { id: guild_id });
+        assert!(cache.guild_is_unavailable(guild_id));
+        assert_eq!(vec![guild_id], cache.unavailable_guilds());
+
+        cache.update(&GuildCreate(test::guild(guild_id, UserId(2))));
+        assert!(!cache.guild_is_unavailable(guild_id));
+        assert!(cache.unavailable_guilds().is_empty());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: true,
+        });
+        assert!(cache.guild_is_unavailable(guild_id));
+    }
 }
@@ -44,5 +44,80 @@ impl UpdateCache for PresenceUpdate {
         };
 
         cache.cache_presence(self.guild_id, presence);
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceType;
+    use twilight_model::gateway::presence::{Activity, ActivityType, ClientStatus, Status};
+
+    #[test]
+    fn test_presence_update_not_wanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::PRESENCE)
+            .build();
+
+        cache.update(&PresenceUpdate {
+            activities: Vec::new(),
+            client_status: ClientStatus {
+                desktop: Some(Status::Online),
+                mobile: None,
+                web: None,
+            },
+            game: None,
+            guild_id: GuildId(1),
+            status: Status::Online,
+            user: UserOrId::UserId { id: UserId(2) },
+        });
+
+        assert_eq!(0, cache.stats().presences());
+        assert_eq!(0, cache.generation());
+    }
+
+    #[test]
+    fn test_user_activity() {
+        let activity = Activity {
+            application_id: None,
+            assets: None,
+            buttons: Vec::new(),
+            created_at: None,
+            details: None,
+            emoji: None,
+            flags: None,
+            id: None,
+            instance: None,
+            kind: ActivityType::Playing,
+            name: "a game".to_owned(),
+            party: None,
+            secrets: None,
+            state: None,
+            timestamps: None,
+            url: None,
+        };
+
+        let cache = InMemoryCache::new();
+        assert!(cache.user_activity(GuildId(1), UserId(2)).is_none());
+
+        cache.update(&PresenceUpdate {
+            activities: vec![activity.clone()],
+            client_status: ClientStatus {
+                desktop: Some(Status::Online),
+                mobile: None,
+                web: None,
+            },
+            game: None,
+            guild_id: GuildId(1),
+            status: Status::Online,
+            user: UserOrId::UserId { id: UserId(2) },
+        });
+
+        assert_eq!(
+            Some(vec![activity]),
+            cache.user_activity(GuildId(1), UserId(2))
+        );
     }
 }
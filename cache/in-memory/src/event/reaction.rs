@@ -43,6 +43,8 @@ impl UpdateCache for ReactionAdd {
                 me,
             });
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -78,6 +80,8 @@ impl UpdateCache for ReactionRemove {
                 message.reactions.retain(|e| !(e.emoji == self.0.emoji));
             }
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -95,6 +99,8 @@ impl UpdateCache for ReactionRemoveAll {
         };
 
         message.reactions.clear();
+
+        cache.increment_generation();
     }
 }
 
@@ -116,6 +122,8 @@ impl UpdateCache for ReactionRemoveEmoji {
         if let Some(index) = maybe_index {
             message.reactions.remove(index);
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -183,6 +191,37 @@ mod tests {
         assert_eq!(smiley_react.unwrap().count, 1);
     }
 
+    #[test]
+    fn test_message_reactions() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.update(&ReactionRemove(Reaction {
+            channel_id: ChannelId(2),
+            emoji: ReactionType::Unicode {
+                name: "🗺️".to_owned(),
+            },
+            guild_id: Some(GuildId(1)),
+            member: None,
+            message_id: MessageId(4),
+            user_id: UserId(5),
+        }));
+
+        let reactions = cache.message_reactions(ChannelId(2), MessageId(4)).unwrap();
+
+        // The last reaction with an emoji removes the emoji's entry entirely.
+        assert_eq!(1, reactions.len());
+        assert_eq!(
+            ReactionType::Unicode {
+                name: "😀".to_owned()
+            },
+            reactions[0].emoji
+        );
+        assert_eq!(2, reactions[0].count);
+
+        assert!(cache
+            .message_reactions(ChannelId(2), MessageId(5))
+            .is_none());
+    }
+
     #[test]
     fn test_reaction_remove_all() {
         let cache = test::cache_with_message_and_reactions();
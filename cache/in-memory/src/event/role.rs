@@ -1,4 +1,4 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{config::ResourceType, CacheUpdate, InMemoryCache, UpdateCache, UpdateCacheReturning};
 use twilight_model::{
     gateway::payload::{RoleCreate, RoleDelete, RoleUpdate},
     guild::Role,
@@ -12,7 +12,7 @@ impl InMemoryCache {
         }
     }
 
-    fn cache_role(&self, guild_id: GuildId, role: Role) {
+    fn cache_role(&self, guild_id: GuildId, role: Role) -> Option<Role> {
         // Insert the role into the guild_roles map
         self.0
             .guild_roles
@@ -21,15 +21,20 @@ impl InMemoryCache {
             .insert(role.id);
 
         // Insert the role into the all roles map
-        crate::upsert_guild_item(&self.0.roles, guild_id, role.id, role);
+        crate::upsert_guild_item(&self.0.roles, guild_id, role.id, role)
     }
 
-    fn delete_role(&self, role_id: RoleId) {
-        if let Some((_, role)) = self.0.roles.remove(&role_id) {
-            if let Some(mut roles) = self.0.guild_roles.get_mut(&role.guild_id) {
-                roles.remove(&role_id);
-            }
+    fn delete_role(&self, role_id: RoleId) -> bool {
+        let role = match self.0.roles.remove(&role_id) {
+            Some((_, role)) => role,
+            None => return false,
+        };
+
+        if let Some(mut roles) = self.0.guild_roles.get_mut(&role.guild_id) {
+            roles.remove(&role_id);
         }
+
+        true
     }
 }
 
@@ -40,6 +45,11 @@ impl UpdateCache for RoleCreate {
         }
 
         cache.cache_role(self.guild_id, self.role.clone());
+        cache.increment_generation();
+        cache.notify(CacheUpdate::RoleAdded {
+            guild_id: self.guild_id,
+            role_id: self.role.id,
+        });
     }
 }
 
@@ -49,17 +59,38 @@ impl UpdateCache for RoleDelete {
             return;
         }
 
-        cache.delete_role(self.role_id);
+        if cache.delete_role(self.role_id) {
+            cache.increment_generation();
+            cache.notify(CacheUpdate::RoleRemoved {
+                guild_id: self.guild_id,
+                role_id: self.role_id,
+            });
+        }
     }
 }
 
 impl UpdateCache for RoleUpdate {
     fn update(&self, cache: &InMemoryCache) {
+        self.update_returning(cache);
+    }
+}
+
+impl UpdateCacheReturning for RoleUpdate {
+    type Previous = Role;
+
+    fn update_returning(&self, cache: &InMemoryCache) -> Option<Role> {
         if !cache.wants(ResourceType::ROLE) {
-            return;
+            return None;
         }
 
-        cache.cache_role(self.guild_id, self.role.clone());
+        let previous = cache.cache_role(self.guild_id, self.role.clone());
+        cache.increment_generation();
+        cache.notify(CacheUpdate::RoleUpdated {
+            guild_id: self.guild_id,
+            role_id: self.role.id,
+        });
+
+        previous
     }
 }
 
@@ -85,6 +116,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_update_returning_previous_role() {
+        let cache = InMemoryCache::new();
+        let mut role = test::role(RoleId(2));
+
+        // The role isn't cached yet, so there's no previous role.
+        assert!(cache
+            .update_returning(&RoleUpdate {
+                guild_id: GuildId(1),
+                role: role.clone(),
+            })
+            .is_none());
+
+        role.name = "changed".to_owned();
+        let previous = cache.update_returning(&RoleUpdate {
+            guild_id: GuildId(1),
+            role,
+        });
+
+        assert_eq!("test", previous.unwrap().name);
+        assert_eq!("changed", cache.role(RoleId(2)).unwrap().name);
+    }
+
     #[test]
     fn test_cache_role() {
         let cache = InMemoryCache::new();
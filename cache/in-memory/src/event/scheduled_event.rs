@@ -0,0 +1,125 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use twilight_model::{
+    gateway::payload::{
+        GuildScheduledEventCreate, GuildScheduledEventDelete, GuildScheduledEventUpdate,
+    },
+    guild::scheduled_event::GuildScheduledEvent,
+    id::ScheduledEventId,
+};
+
+impl InMemoryCache {
+    fn cache_scheduled_event(&self, event: GuildScheduledEvent) {
+        let guild_id = event.guild_id;
+
+        self.0
+            .guild_scheduled_events
+            .entry(guild_id)
+            .or_default()
+            .insert(event.id);
+
+        crate::upsert_guild_item(&self.0.scheduled_events, guild_id, event.id, event);
+    }
+
+    fn delete_scheduled_event(&self, event_id: ScheduledEventId) {
+        if let Some((_, data)) = self.0.scheduled_events.remove(&event_id) {
+            let guild_id = data.guild_id;
+
+            if let Some(mut events) = self.0.guild_scheduled_events.get_mut(&guild_id) {
+                events.remove(&event_id);
+            }
+        }
+    }
+}
+
+impl UpdateCache for GuildScheduledEventCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.0.clone());
+
+        cache.increment_generation();
+    }
+}
+
+impl UpdateCache for GuildScheduledEventDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.delete_scheduled_event(self.id);
+
+        cache.increment_generation();
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.0.clone());
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twilight_model::{
+        guild::scheduled_event::{EntityType, PrivacyLevel, Status},
+        id::{ChannelId, GuildId},
+    };
+
+    fn scheduled_event(guild_id: GuildId, id: ScheduledEventId) -> GuildScheduledEvent {
+        GuildScheduledEvent {
+            channel_id: Some(ChannelId(1)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id,
+            id,
+            image: None,
+            name: "event".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-12-01T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: None,
+        }
+    }
+
+    #[test]
+    fn test_scheduled_events() {
+        let cache = InMemoryCache::new();
+        let event = scheduled_event(GuildId(2), ScheduledEventId(3));
+
+        cache.update(&GuildScheduledEventCreate(event.clone()));
+        assert_eq!(Some(event.clone()), cache.scheduled_event(event.id));
+        assert!(cache
+            .guild_scheduled_events(event.guild_id)
+            .unwrap()
+            .contains(&event.id));
+
+        let updated = GuildScheduledEvent {
+            status: Status::Active,
+            ..event.clone()
+        };
+        cache.update(&GuildScheduledEventUpdate(updated.clone()));
+        assert_eq!(Some(updated.clone()), cache.scheduled_event(event.id));
+
+        cache.update(&GuildScheduledEventDelete(updated));
+        assert!(cache.scheduled_event(event.id).is_none());
+        assert!(cache
+            .guild_scheduled_events(event.guild_id)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -49,6 +49,8 @@ impl UpdateCache for StageInstanceCreate {
         }
 
         cache.cache_stage_instance(self.guild_id, self.0.clone());
+
+        cache.increment_generation();
     }
 }
 
@@ -59,6 +61,8 @@ impl UpdateCache for StageInstanceDelete {
         }
 
         cache.delete_stage_instance(self.id);
+
+        cache.increment_generation();
     }
 }
 
@@ -69,13 +73,20 @@ impl UpdateCache for StageInstanceUpdate {
         }
 
         cache.cache_stage_instance(self.guild_id, self.0.clone());
+
+        cache.increment_generation();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use twilight_model::{channel::stage_instance::PrivacyLevel, id::ChannelId};
+    use crate::test;
+    use twilight_model::{
+        channel::stage_instance::PrivacyLevel,
+        gateway::payload::VoiceStateUpdate,
+        id::{ChannelId, UserId},
+    };
 
     #[test]
     fn test_stage_channels() {
@@ -131,4 +142,28 @@ mod tests {
             assert_eq!(cached_instance, None);
         }
     }
+
+    #[test]
+    fn test_stage_speakers() {
+        let cache = InMemoryCache::new();
+        assert!(cache.stage_speakers(StageId(3)).is_none());
+
+        cache.update(&StageInstanceCreate(StageInstance {
+            channel_id: ChannelId(1),
+            discoverable_disabled: true,
+            guild_id: GuildId(2),
+            id: StageId(3),
+            privacy_level: PrivacyLevel::GuildOnly,
+            topic: "topic".into(),
+        }));
+        assert_eq!(Some(Vec::new()), cache.stage_speakers(StageId(3)));
+
+        let speaker = test::voice_state(GuildId(2), Some(ChannelId(1)), UserId(4));
+        let mut audience = test::voice_state(GuildId(2), Some(ChannelId(1)), UserId(5));
+        audience.suppress = true;
+        cache.update(&VoiceStateUpdate(speaker));
+        cache.update(&VoiceStateUpdate(audience));
+
+        assert_eq!(Some(vec![UserId(4)]), cache.stage_speakers(StageId(3)));
+    }
 }
@@ -0,0 +1,237 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use twilight_model::{
+    channel::thread::ThreadChannel,
+    gateway::payload::{ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate},
+    id::{ChannelId, GuildId},
+};
+
+impl InMemoryCache {
+    fn cache_thread(&self, guild_id: GuildId, mut thread: ThreadChannel) {
+        thread.guild_id.replace(guild_id);
+
+        let id = thread.id;
+
+        self.0.guild_threads.entry(guild_id).or_default().insert(id);
+
+        if let Some(parent_id) = thread.parent_id {
+            self.0
+                .channel_threads
+                .entry(parent_id)
+                .or_default()
+                .insert(id);
+        }
+
+        crate::upsert_guild_item(&self.0.threads, guild_id, id, thread);
+    }
+
+    /// Delete a thread from the cache.
+    ///
+    /// The thread itself and its entries in its guild's and parent channel's
+    /// lists of threads will be deleted, along with the lists if they become
+    /// empty.
+    pub(crate) fn delete_thread(&self, thread_id: ChannelId) {
+        if let Some((_, item)) = self.0.threads.remove(&thread_id) {
+            if let Some(mut guild_threads) = self.0.guild_threads.get_mut(&item.guild_id) {
+                guild_threads.remove(&thread_id);
+            }
+
+            self.0
+                .guild_threads
+                .remove_if(&item.guild_id, |_, threads| threads.is_empty());
+
+            if let Some(parent_id) = item.data.parent_id {
+                if let Some(mut channel_threads) = self.0.channel_threads.get_mut(&parent_id) {
+                    channel_threads.remove(&thread_id);
+                }
+
+                self.0
+                    .channel_threads
+                    .remove_if(&parent_id, |_, threads| threads.is_empty());
+            }
+        }
+    }
+
+    /// Delete all of the threads of a parent channel from the cache.
+    pub(crate) fn delete_channel_threads(&self, parent_id: ChannelId) {
+        if let Some((_, thread_ids)) = self.0.channel_threads.remove(&parent_id) {
+            for thread_id in thread_ids {
+                self.delete_thread(thread_id);
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::THREAD) {
+            return;
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            cache.cache_thread(guild_id, self.0.clone());
+            cache.increment_generation();
+        }
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::THREAD) {
+            return;
+        }
+
+        cache.delete_thread(self.id);
+
+        cache.increment_generation();
+    }
+}
+
+impl UpdateCache for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::THREAD) {
+            return;
+        }
+
+        // The threads are the full list of active threads in the synced
+        // channels, so any other cached threads in them are stale.
+        if self.channel_ids.is_empty() {
+            let thread_ids = cache
+                .0
+                .guild_threads
+                .get(&self.guild_id)
+                .map(|ids| ids.iter().copied().collect::<Vec<_>>());
+
+            for thread_id in thread_ids.into_iter().flatten() {
+                cache.delete_thread(thread_id);
+            }
+        } else {
+            for parent_id in &self.channel_ids {
+                cache.delete_channel_threads(*parent_id);
+            }
+        }
+
+        for thread in &self.threads {
+            cache.cache_thread(self.guild_id, thread.clone());
+        }
+
+        cache.increment_generation();
+    }
+}
+
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::THREAD) {
+            return;
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            cache.cache_thread(guild_id, self.0.clone());
+            cache.increment_generation();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twilight_model::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            ChannelType,
+        },
+        id::UserId,
+    };
+
+    fn thread(guild_id: GuildId, parent_id: ChannelId, id: ChannelId) -> ThreadChannel {
+        ThreadChannel {
+            guild_id: Some(guild_id),
+            id,
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(4)),
+            parent_id: Some(parent_id),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                auto_archive_duration: AutoArchiveDuration::Day,
+                invitable: None,
+                locked: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_thread_create_delete() {
+        let cache = InMemoryCache::new();
+        let thread = thread(GuildId(1), ChannelId(2), ChannelId(3));
+        cache.update(&ThreadCreate(thread.clone()));
+
+        assert_eq!(Some(thread), cache.thread(ChannelId(3)));
+        assert_eq!(
+            Some([ChannelId(3)].iter().copied().collect()),
+            cache.channel_threads(ChannelId(2))
+        );
+
+        cache.update(&ThreadDelete {
+            guild_id: GuildId(1),
+            id: ChannelId(3),
+            kind: ChannelType::GuildPublicThread,
+            parent_id: ChannelId(2),
+        });
+
+        assert!(cache.thread(ChannelId(3)).is_none());
+        assert!(cache.channel_threads(ChannelId(2)).is_none());
+        assert!(cache.0.guild_threads.is_empty());
+    }
+
+    #[test]
+    fn test_thread_list_sync() {
+        let cache = InMemoryCache::new();
+        cache.update(&ThreadCreate(thread(
+            GuildId(1),
+            ChannelId(2),
+            ChannelId(3),
+        )));
+        cache.update(&ThreadCreate(thread(
+            GuildId(1),
+            ChannelId(5),
+            ChannelId(6),
+        )));
+
+        cache.update(&ThreadListSync {
+            channel_ids: vec![ChannelId(2)],
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            threads: vec![thread(GuildId(1), ChannelId(2), ChannelId(4))],
+        });
+
+        // Only threads in the synced channel are replaced.
+        assert!(cache.thread(ChannelId(3)).is_none());
+        assert!(cache.thread(ChannelId(4)).is_some());
+        assert!(cache.thread(ChannelId(6)).is_some());
+        assert_eq!(
+            Some([ChannelId(4)].iter().copied().collect()),
+            cache.channel_threads(ChannelId(2))
+        );
+    }
+
+    #[test]
+    fn test_thread_not_wanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::THREAD)
+            .build();
+        cache.update(&ThreadCreate(thread(
+            GuildId(1),
+            ChannelId(2),
+            ChannelId(3),
+        )));
+
+        assert!(cache.thread(ChannelId(3)).is_none());
+        assert_eq!(0, cache.generation());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use std::time::Instant;
+use twilight_model::{
+    gateway::payload::TypingStart,
+    id::{ChannelId, UserId},
+};
+
+impl InMemoryCache {
+    fn cache_typing(&self, channel_id: ChannelId, user_id: UserId, now: Instant) {
+        let mut typing = self.0.typing.entry(channel_id).or_default();
+
+        // Drop stale entries while we're here so that channels with a lot of
+        // different typing users don't grow without bound.
+        typing.retain(|_, started| now.duration_since(*started) < crate::TYPING_DURATION);
+        typing.insert(user_id, now);
+    }
+}
+
+impl UpdateCache for TypingStart {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::TYPING) {
+            return;
+        }
+
+        cache.cache_typing(self.channel_id, self.user_id, Instant::now());
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::ResourceType, test, InMemoryCache};
+    use std::time::{Duration, Instant};
+    use twilight_model::{
+        channel::Channel,
+        gateway::payload::{ChannelCreate, ChannelDelete, TypingStart},
+        id::{ChannelId, GuildId, UserId},
+    };
+
+    fn typing_start(channel_id: ChannelId, user_id: UserId) -> TypingStart {
+        TypingStart {
+            channel_id,
+            guild_id: Some(GuildId(1)),
+            member: None,
+            timestamp: 1_632_072_645,
+            user_id,
+        }
+    }
+
+    #[test]
+    fn test_typing_users() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::TYPING)
+            .build();
+        cache.update(&typing_start(ChannelId(2), UserId(3)));
+        cache.update(&typing_start(ChannelId(2), UserId(4)));
+        cache.update(&typing_start(ChannelId(5), UserId(6)));
+
+        let mut users = cache.typing_users(ChannelId(2));
+        users.sort();
+        assert_eq!(vec![UserId(3), UserId(4)], users);
+        assert_eq!(vec![UserId(6)], cache.typing_users(ChannelId(5)));
+        assert!(cache.typing_users(ChannelId(7)).is_empty());
+
+        let later = Instant::now() + Duration::from_secs(11);
+        assert!(cache.typing_users_at(ChannelId(2), later).is_empty());
+
+        // Reading the typing users prunes the stale entries.
+        assert!(!cache.0.typing.contains_key(&ChannelId(2)));
+
+        // Typing again afterwards prunes the stale entries too.
+        cache.cache_typing(ChannelId(5), UserId(3), later);
+        assert_eq!(vec![UserId(3)], cache.typing_users_at(ChannelId(5), later));
+        assert_eq!(1, cache.0.typing.get(&ChannelId(5)).unwrap().len());
+
+        cache.clear();
+        assert!(cache.0.typing.is_empty());
+    }
+
+    #[test]
+    fn test_typing_disabled_by_default() {
+        let cache = InMemoryCache::new();
+        cache.update(&typing_start(ChannelId(2), UserId(3)));
+
+        assert!(cache.typing_users(ChannelId(2)).is_empty());
+        assert_eq!(0, cache.generation());
+    }
+
+    #[test]
+    fn test_channel_delete_removes_typing() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::CHANNEL | ResourceType::TYPING)
+            .build();
+        let (_, channel_id, channel) = test::guild_channel_text();
+        cache.update(&ChannelCreate(Channel::Guild(channel.clone())));
+        cache.update(&typing_start(channel_id, UserId(3)));
+        assert_eq!(vec![UserId(3)], cache.typing_users(channel_id));
+
+        cache.update(&ChannelDelete(Channel::Guild(channel)));
+        assert!(!cache.0.typing.contains_key(&channel_id));
+    }
+}
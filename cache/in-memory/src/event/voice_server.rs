@@ -0,0 +1,79 @@
+use crate::{config::ResourceType, model::CachedVoiceServer, InMemoryCache, UpdateCache};
+use twilight_model::gateway::payload::VoiceServerUpdate;
+
+impl UpdateCache for VoiceServerUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::VOICE_SERVER) {
+            return;
+        }
+
+        let guild_id = match self.guild_id {
+            Some(guild_id) => guild_id,
+            None => return,
+        };
+
+        cache.0.voice_servers.insert(
+            guild_id,
+            CachedVoiceServer {
+                endpoint: self.endpoint.clone(),
+                guild_id,
+                token: self.token.clone(),
+            },
+        );
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::ResourceType, InMemoryCache};
+    use twilight_model::{
+        gateway::payload::{GuildDelete, VoiceServerUpdate},
+        id::GuildId,
+    };
+
+    fn voice_server_update(guild_id: GuildId, token: &str) -> VoiceServerUpdate {
+        VoiceServerUpdate {
+            channel_id: None,
+            endpoint: Some("us-east1.discord.media:443".to_owned()),
+            guild_id: Some(guild_id),
+            token: token.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_voice_server_update() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::GUILD | ResourceType::VOICE_SERVER)
+            .build();
+        cache.update(&voice_server_update(GuildId(1), "a"));
+
+        let update = voice_server_update(GuildId(1), "b");
+        cache.update(&update);
+
+        let cached = cache.voice_server(GuildId(1)).unwrap();
+        assert_eq!(cached, update);
+        assert_eq!("b", cached.token);
+        assert!(cache.voice_server(GuildId(2)).is_none());
+
+        cache.update(&GuildDelete {
+            id: GuildId(1),
+            unavailable: false,
+        });
+        assert!(cache.voice_server(GuildId(1)).is_none());
+
+        cache.update(&voice_server_update(GuildId(2), "c"));
+        cache.clear();
+        assert!(cache.voice_server(GuildId(2)).is_none());
+    }
+
+    #[test]
+    fn test_voice_server_disabled_by_default() {
+        let cache = InMemoryCache::new();
+        cache.update(&voice_server_update(GuildId(1), "a"));
+
+        assert!(cache.voice_server(GuildId(1)).is_none());
+        assert_eq!(0, cache.generation());
+    }
+}
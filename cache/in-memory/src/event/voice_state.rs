@@ -88,9 +88,13 @@ impl UpdateCache for VoiceStateUpdate {
 
         cache.cache_voice_state(self.0.clone());
 
-        if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
-            cache.cache_member(guild_id, member.clone());
+        if cache.wants(ResourceType::MEMBER) {
+            if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
+                cache.cache_member(guild_id, member.clone());
+            }
         }
+
+        cache.increment_generation();
     }
 }
 
@@ -249,6 +253,25 @@ mod tests {
         assert!(cache.voice_channel_states(ChannelId(0)).is_none());
     }
 
+    #[test]
+    fn test_guild_voice_states() {
+        let cache = InMemoryCache::new();
+        cache.cache_voice_state(test::voice_state(GuildId(1), Some(ChannelId(2)), UserId(3)));
+        cache.cache_voice_state(test::voice_state(GuildId(1), Some(ChannelId(5)), UserId(4)));
+        cache.cache_voice_state(test::voice_state(GuildId(6), Some(ChannelId(7)), UserId(8)));
+
+        let mut user_ids = cache
+            .guild_voice_states(GuildId(1))
+            .unwrap()
+            .into_iter()
+            .map(|voice_state| voice_state.user_id)
+            .collect::<Vec<_>>();
+        user_ids.sort();
+        assert_eq!(vec![UserId(3), UserId(4)], user_ids);
+
+        assert!(cache.guild_voice_states(GuildId(9)).is_none());
+    }
+
     #[test]
     fn test_voice_states_with_no_cached_guilds() {
         let cache = InMemoryCache::builder()
@@ -331,4 +354,19 @@ mod tests {
             UserId(3),
         );
     }
+
+    #[test]
+    fn test_voice_states_members_not_wanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::VOICE_STATE)
+            .build();
+
+        let mut voice_state = test::voice_state(GuildId(1), Some(ChannelId(2)), UserId(3));
+        voice_state.member = Some(test::member(UserId(3), GuildId(1)));
+        cache.update(&VoiceStateUpdate(voice_state));
+
+        assert!(cache.voice_state(UserId(3), GuildId(1)).is_some());
+        assert!(cache.member(GuildId(1), UserId(3)).is_none());
+        assert!(cache.user(UserId(3)).is_none());
+    }
 }
@@ -0,0 +1,161 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use twilight_model::{
+    gateway::payload::WebhooksUpdate,
+    id::{ChannelId, GuildId},
+};
+
+impl InMemoryCache {
+    /// Delete the webhooks of a channel from the cache.
+    ///
+    /// The channel is no longer marked as having stale webhooks.
+    pub(crate) fn delete_channel_webhooks(&self, channel_id: ChannelId) {
+        self.0.stale_webhook_channels.remove(&channel_id);
+
+        if let Some((_, webhook_ids)) = self.0.channel_webhooks.remove(&channel_id) {
+            for webhook_id in webhook_ids {
+                self.0.webhooks.remove(&webhook_id);
+            }
+        }
+    }
+
+    /// Delete the webhooks of all of the channels in a guild from the cache.
+    pub(crate) fn delete_guild_webhooks(&self, guild_id: GuildId) {
+        let mut channel_ids = self
+            .0
+            .webhooks
+            .iter()
+            .filter(|webhook| webhook.guild_id == Some(guild_id))
+            .map(|webhook| webhook.channel_id)
+            .collect::<Vec<_>>();
+
+        if let Some(guild_channels) = self.0.guild_channels.get(&guild_id) {
+            channel_ids.extend(guild_channels.iter().copied());
+        }
+
+        for channel_id in channel_ids {
+            self.delete_channel_webhooks(channel_id);
+        }
+    }
+}
+
+impl UpdateCache for WebhooksUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::WEBHOOK) {
+            return;
+        }
+
+        cache.0.stale_webhook_channels.insert(self.channel_id);
+
+        cache.increment_generation();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InMemoryCache, ResourceType};
+    use std::collections::HashSet;
+    use twilight_model::{
+        channel::webhook::{Webhook, WebhookType},
+        gateway::payload::{GuildDelete, WebhooksUpdate},
+        id::{ChannelId, GuildId, WebhookId},
+    };
+
+    fn cache() -> InMemoryCache {
+        InMemoryCache::builder()
+            .resource_types(ResourceType::all())
+            .build()
+    }
+
+    fn webhook(id: WebhookId, channel_id: ChannelId) -> Webhook {
+        Webhook {
+            application_id: None,
+            avatar: None,
+            channel_id,
+            guild_id: Some(GuildId(1)),
+            id,
+            kind: WebhookType::Incoming,
+            name: Some("test".to_owned()),
+            source_channel: None,
+            source_guild: None,
+            token: None,
+            url: None,
+            user: None,
+        }
+    }
+
+    #[test]
+    fn test_insert_channel_webhooks() {
+        let cache = cache();
+        cache.insert_channel_webhooks(
+            ChannelId(2),
+            vec![
+                webhook(WebhookId(3), ChannelId(2)),
+                webhook(WebhookId(4), ChannelId(2)),
+                webhook(WebhookId(5), ChannelId(6)),
+            ],
+        );
+
+        assert_eq!(
+            Some(ChannelId(2)),
+            cache
+                .webhook(WebhookId(3))
+                .map(|webhook| webhook.channel_id)
+        );
+        assert!(cache.webhook(WebhookId(5)).is_none());
+        assert_eq!(
+            Some(
+                vec![WebhookId(3), WebhookId(4)]
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+            ),
+            cache.channel_webhooks(ChannelId(2))
+        );
+
+        cache.insert_channel_webhooks(ChannelId(2), vec![webhook(WebhookId(4), ChannelId(2))]);
+        assert!(cache.webhook(WebhookId(3)).is_none());
+        assert_eq!(1, cache.channel_webhooks(ChannelId(2)).unwrap().len());
+    }
+
+    #[test]
+    fn test_webhooks_update() {
+        let cache = cache();
+        cache.insert_channel_webhooks(ChannelId(2), vec![webhook(WebhookId(3), ChannelId(2))]);
+        assert!(!cache.channel_webhooks_stale(ChannelId(2)));
+
+        cache.update(&WebhooksUpdate {
+            channel_id: ChannelId(2),
+            guild_id: GuildId(1),
+        });
+        assert!(cache.channel_webhooks_stale(ChannelId(2)));
+        assert!(cache.webhook(WebhookId(3)).is_some());
+
+        cache.insert_channel_webhooks(ChannelId(2), vec![webhook(WebhookId(3), ChannelId(2))]);
+        assert!(!cache.channel_webhooks_stale(ChannelId(2)));
+    }
+
+    #[test]
+    fn test_webhooks_update_unwanted() {
+        let cache = InMemoryCache::new();
+        cache.insert_channel_webhooks(ChannelId(2), vec![webhook(WebhookId(3), ChannelId(2))]);
+        cache.update(&WebhooksUpdate {
+            channel_id: ChannelId(2),
+            guild_id: GuildId(1),
+        });
+
+        assert!(cache.webhook(WebhookId(3)).is_none());
+        assert!(!cache.channel_webhooks_stale(ChannelId(2)));
+    }
+
+    #[test]
+    fn test_guild_delete() {
+        let cache = cache();
+        cache.insert_channel_webhooks(ChannelId(2), vec![webhook(WebhookId(3), ChannelId(2))]);
+        cache.update(&GuildDelete {
+            id: GuildId(1),
+            unavailable: false,
+        });
+
+        assert!(cache.webhook(WebhookId(3)).is_none());
+        assert!(cache.channel_webhooks(ChannelId(2)).is_none());
+    }
+}
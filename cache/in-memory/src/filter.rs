@@ -0,0 +1,82 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+use twilight_model::user::User;
+
+/// Predicate configured via [`InMemoryCacheBuilder::user_filter`] deciding
+/// whether a user should be cached.
+///
+/// [`InMemoryCacheBuilder::user_filter`]: crate::InMemoryCacheBuilder::user_filter
+#[derive(Clone)]
+pub(crate) struct UserFilter(Arc<dyn Fn(&User) -> bool + Send + Sync>);
+
+impl UserFilter {
+    pub(crate) fn new(predicate: impl Fn(&User) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(predicate))
+    }
+
+    pub(crate) fn wants(&self, user: &User) -> bool {
+        (self.0)(user)
+    }
+}
+
+impl Debug for UserFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("UserFilter").finish()
+    }
+}
+
+impl Eq for UserFilter {}
+
+/// Predicates are equal if they're the same predicate, such as in clones of a
+/// builder.
+impl PartialEq for UserFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.0).cast::<()>() == Arc::as_ptr(&other.0).cast::<()>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserFilter;
+    use crate::{test, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::{MemberAdd, UserUpdate},
+        id::{GuildId, UserId},
+    };
+
+    assert_impl_all!(UserFilter: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_user_filter_bots() {
+        let cache = InMemoryCache::builder()
+            .user_filter(|user| !user.bot)
+            .build();
+
+        let mut bot = test::member(UserId(2), GuildId(1));
+        bot.user.bot = true;
+        cache.update(&MemberAdd(bot));
+        cache.update(&MemberAdd(test::member(UserId(3), GuildId(1))));
+
+        assert!(cache.user(UserId(2)).is_none());
+        assert!(cache.member(GuildId(1), UserId(2)).is_none());
+        assert!(cache.user(UserId(3)).is_some());
+        assert!(cache.member(GuildId(1), UserId(3)).is_some());
+    }
+
+    #[test]
+    fn test_user_filter_current_user() {
+        let cache = InMemoryCache::builder().user_filter(|_| false).build();
+        cache.update(&UserUpdate(test::current_user(2)));
+
+        cache.update(&MemberAdd(test::member(UserId(2), GuildId(1))));
+        cache.update(&MemberAdd(test::member(UserId(3), GuildId(1))));
+
+        assert!(cache.user(UserId(2)).is_some());
+        assert!(cache.member(GuildId(1), UserId(2)).is_some());
+        assert!(cache.user(UserId(3)).is_none());
+    }
+}
@@ -10,6 +10,13 @@
 //!
 //! By default no feature is enabled.
 //!
+//! ### `caching-client`
+//!
+//! The `caching-client` feature flag will bring in support for the
+//! `CachingClient`; a wrapper around an HTTP client and the cache that
+//! retrieves resources from the cache, falling back to fetching them over
+//! HTTP and caching them.
+//!
 //! ### `permission-calculator`
 //!
 //! The `permission-calculator` feature flag will bring in support for the
@@ -71,46 +78,79 @@
 
 pub mod model;
 
+#[cfg(feature = "caching-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "caching-client")))]
+pub mod caching_client;
+
 #[cfg(feature = "permission-calculator")]
 #[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
 pub mod permission;
 
 mod builder;
+mod change;
 mod config;
 mod event;
+mod filter;
+mod member_order;
 mod stats;
 
 #[cfg(test)]
 mod test;
 
+#[cfg(all(test, feature = "caching-client"))]
+mod test_server;
+
 pub use self::{
     builder::InMemoryCacheBuilder,
+    change::CacheUpdate,
     config::{Config, ResourceType},
-    stats::InMemoryCacheStats,
+    stats::{CacheStatsSummary, InMemoryCacheStats},
 };
 
+#[cfg(feature = "caching-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "caching-client")))]
+pub use self::caching_client::CachingClient;
+
 #[cfg(feature = "permission-calculator")]
 #[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::model::*;
+#[cfg(feature = "permission-calculator")]
+use twilight_model::guild::Permissions;
+
+use self::{change::OnChange, filter::UserFilter, member_order::MemberOrder, model::*};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
 };
 use std::{
-    collections::{BTreeSet, HashSet, VecDeque},
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
     hash::Hash,
     ops::Deref,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant, SystemTime},
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel, StageInstance},
-    gateway::event::Event,
-    guild::{GuildIntegration, Role},
-    id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, StageId, UserId},
+    application::command::Command,
+    channel::{
+        message::MessageReaction, thread::ThreadChannel, webhook::Webhook, Channel, ChannelType,
+        Group, GuildChannel, PrivateChannel, StageInstance,
+    },
+    gateway::{
+        event::Event,
+        payload::{ChannelCreate, MemberAdd, RoleCreate},
+        presence::Activity,
+    },
+    guild::{scheduled_event::GuildScheduledEvent, Guild, GuildIntegration, Member, Role},
+    id::{
+        ChannelId, CommandId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, ScheduledEventId,
+        StageId, UserId, WebhookId,
+    },
     user::{CurrentUser, User},
-    voice::VoiceState,
+    voice::{VoiceRegion, VoiceState},
 };
 
 #[derive(Debug)]
@@ -119,33 +159,47 @@ struct GuildItem<T> {
     guild_id: GuildId,
 }
 
+/// Insert or update a guild item, returning the previously cached value if
+/// there was one.
 fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
     map: &DashMap<K, GuildItem<V>>,
     guild_id: GuildId,
     key: K,
     value: V,
-) {
+) -> Option<V> {
     match map.entry(key) {
-        Entry::Occupied(entry) if entry.get().data == value => {}
+        // The cached value is equal to the new value, so there's no need to
+        // replace it and the new value can be returned as the previous value.
+        Entry::Occupied(entry) if entry.get().data == value => Some(value),
         Entry::Occupied(mut entry) => {
-            entry.insert(GuildItem {
+            let previous = entry.insert(GuildItem {
                 data: value,
                 guild_id,
             });
+
+            Some(previous.data)
         }
         Entry::Vacant(entry) => {
             entry.insert(GuildItem {
                 data: value,
                 guild_id,
             });
+
+            None
         }
     }
 }
 
-fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, V>, k: K, v: V) {
-    map.insert(k, v);
+/// Insert or update an item, returning the previously cached value if there
+/// was one.
+fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, V>, k: K, v: V) -> Option<V> {
+    map.insert(k, v)
 }
 
+/// Duration after a typing start event for which the user is considered to
+/// still be typing.
+const TYPING_DURATION: Duration = Duration::from_secs(10);
+
 // When adding a field here, be sure to add it to `InMemoryCache::clear` if
 // necessary.
 #[derive(Debug, Default)]
@@ -153,32 +207,63 @@ struct InMemoryCacheRef {
     config: Config,
     channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>>,
     channels_private: DashMap<ChannelId, PrivateChannel>,
+    /// Mapping of parent channels and the threads created in them.
+    channel_threads: DashMap<ChannelId, HashSet<ChannelId>>,
+    /// Mapping of channels and the webhooks in them.
+    channel_webhooks: DashMap<ChannelId, HashSet<WebhookId>>,
+    /// Mapping of the names of commands, along with the guild they're in if
+    /// they aren't global, to their IDs.
+    command_names: DashMap<(Option<GuildId>, String), CommandId>,
+    commands: DashMap<CommandId, Command>,
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<CurrentUser>>,
     emojis: DashMap<EmojiId, GuildItem<CachedEmoji>>,
+    /// Number of updates that have been processed by the cache.
+    generation: AtomicU64,
     groups: DashMap<ChannelId, Group>,
     guilds: DashMap<GuildId, CachedGuild>,
     guild_channels: DashMap<GuildId, HashSet<ChannelId>>,
     guild_emojis: DashMap<GuildId, HashSet<EmojiId>>,
     guild_integrations: DashMap<GuildId, HashSet<IntegrationId>>,
+    guild_invites: DashMap<GuildId, HashSet<String>>,
     guild_members: DashMap<GuildId, HashSet<UserId>>,
+    /// Order in which members of guilds have been accessed. Only tracked when
+    /// a member cache size is configured.
+    guild_members_order: DashMap<GuildId, MemberOrder>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
+    guild_scheduled_events: DashMap<GuildId, HashSet<ScheduledEventId>>,
     guild_stage_instances: DashMap<GuildId, HashSet<StageId>>,
+    guild_threads: DashMap<GuildId, HashSet<ChannelId>>,
     integrations: DashMap<(GuildId, IntegrationId), GuildItem<GuildIntegration>>,
+    invites: DashMap<String, CachedInvite>,
     members: DashMap<(GuildId, UserId), CachedMember>,
     messages: DashMap<ChannelId, VecDeque<CachedMessage>>,
+    on_change: Option<OnChange>,
     presences: DashMap<(GuildId, UserId), CachedPresence>,
     roles: DashMap<RoleId, GuildItem<Role>>,
+    scheduled_events: DashMap<ScheduledEventId, GuildItem<GuildScheduledEvent>>,
     stage_instances: DashMap<StageId, GuildItem<StageInstance>>,
+    /// Channels whose webhooks have been updated since they were last
+    /// inserted.
+    stale_webhook_channels: DashSet<ChannelId>,
+    threads: DashMap<ChannelId, GuildItem<ThreadChannel>>,
+    /// Mapping of channels and when users in them last started typing.
+    typing: DashMap<ChannelId, HashMap<UserId, Instant>>,
     unavailable_guilds: DashSet<GuildId>,
+    user_filter: Option<UserFilter>,
     users: DashMap<UserId, (User, BTreeSet<GuildId>)>,
+    /// Mapping of voice region IDs to the voice regions.
+    voice_regions: DashMap<String, VoiceRegion>,
+    /// Mapping of guilds and their latest voice server.
+    voice_servers: DashMap<GuildId, CachedVoiceServer>,
     /// Mapping of channels and the users currently connected.
     voice_state_channels: DashMap<ChannelId, HashSet<(GuildId, UserId)>>,
     /// Mapping of guilds and users currently connected to its voice channels.
     voice_state_guilds: DashMap<GuildId, HashSet<UserId>>,
     /// Mapping of guild ID and user ID pairs to their voice states.
     voice_states: DashMap<(GuildId, UserId), VoiceState>,
+    webhooks: DashMap<WebhookId, Webhook>,
 }
 
 /// A thread-safe, in-memory-process cache of Discord data. It can be cloned and
@@ -251,6 +336,10 @@ impl InMemoryCache {
     pub fn clear(&self) {
         self.0.channels_guild.clear();
         self.0.channels_private.clear();
+        self.0.channel_threads.clear();
+        self.0.channel_webhooks.clear();
+        self.0.command_names.clear();
+        self.0.commands.clear();
         self.0
             .current_user
             .lock()
@@ -262,20 +351,103 @@ impl InMemoryCache {
         self.0.guild_channels.clear();
         self.0.guild_emojis.clear();
         self.0.guild_integrations.clear();
+        self.0.guild_invites.clear();
         self.0.guild_members.clear();
+        self.0.guild_members_order.clear();
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
+        self.0.guild_scheduled_events.clear();
         self.0.guild_stage_instances.clear();
+        self.0.guild_threads.clear();
         self.0.integrations.clear();
+        self.0.invites.clear();
         self.0.members.clear();
         self.0.messages.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.scheduled_events.clear();
+        self.0.stale_webhook_channels.clear();
+        self.0.threads.clear();
+        self.0.typing.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
+        self.0.voice_regions.clear();
+        self.0.voice_servers.clear();
         self.0.voice_state_channels.clear();
         self.0.voice_state_guilds.clear();
         self.0.voice_states.clear();
+        self.0.webhooks.clear();
+        self.increment_generation();
+    }
+
+    /// Remove cached messages that were created longer ago than the provided
+    /// duration, returning the number of removed messages.
+    ///
+    /// The creation time of a message is derived from its ID. Channels left
+    /// without any cached messages are removed.
+    ///
+    /// This is an O(n) operation, where n is the amount of cached messages.
+    ///
+    /// # Examples
+    ///
+    /// Remove messages older than a day:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let removed = cache.prune_messages(Duration::from_secs(24 * 60 * 60));
+    /// println!("removed {} messages", removed);
+    /// ```
+    pub fn prune_messages(&self, older_than: Duration) -> usize {
+        let cutoff = match SystemTime::now().checked_sub(older_than) {
+            Some(cutoff) => cutoff,
+            None => return 0,
+        };
+        let mut removed = 0;
+
+        self.0.messages.retain(|_, messages| {
+            let len = messages.len();
+            messages.retain(|message| message.created_at() >= cutoff);
+            removed += len - messages.len();
+
+            !messages.is_empty()
+        });
+
+        if removed > 0 {
+            self.increment_generation();
+        }
+
+        removed
+    }
+
+    /// Returns the current generation of the cache.
+    ///
+    /// The generation is a counter that is incremented every time an update
+    /// is processed into the cache. It can be used to cheaply determine
+    /// whether the cache has changed since it was last looked at, without
+    /// needing to compare cached resources.
+    ///
+    /// Updates that the cache doesn't process, such as events for resource
+    /// types that aren't enabled, don't increment the generation. Clearing
+    /// the cache increments the generation rather than resetting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let generation = cache.generation();
+    ///
+    /// // later on...
+    /// if cache.generation() != generation {
+    ///     println!("the cache has changed");
+    /// }
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.0.generation.load(Ordering::Relaxed)
     }
 
     /// Returns a copy of the config cache.
@@ -339,11 +511,257 @@ impl InMemoryCache {
         InMemoryCachePermissions::new(self)
     }
 
+    /// Calculate the permissions of a member in a guild channel.
+    ///
+    /// This is a shorthand for [`InMemoryCachePermissions::in_channel`] that
+    /// returns `None` instead of an error if the channel, member, or any of
+    /// the member's roles aren't cached, or if the channel isn't in the guild.
+    ///
+    /// This requires the [`ResourceType::CHANNEL`], [`ResourceType::MEMBER`],
+    /// and [`ResourceType::ROLE`] resource types.
+    #[cfg(feature = "permission-calculator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
+    pub fn permissions_in(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        channel_id: ChannelId,
+    ) -> Option<Permissions> {
+        if self.channel_guild_id(channel_id)? != guild_id {
+            return None;
+        }
+
+        self.permissions().in_channel(user_id, channel_id).ok()
+    }
+
     /// Update the cache with an event from the gateway.
     pub fn update(&self, value: &impl UpdateCache) {
         value.update(self);
     }
 
+    /// Update the cache with an event from the gateway, returning the
+    /// previously cached version of the resource that the event replaced.
+    ///
+    /// Returns `None` if the resource wasn't previously cached or the
+    /// resource type isn't enabled. This is implemented for events that
+    /// replace a single cached resource; refer to [`UpdateCacheReturning`]
+    /// for which events are supported.
+    ///
+    /// # Examples
+    ///
+    /// Print the previous name of an updated role:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::{
+    ///     gateway::payload::RoleUpdate,
+    ///     guild::{Permissions, Role},
+    ///     id::{GuildId, RoleId},
+    /// };
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// // later on...
+    /// # let role = Role {
+    /// #     color: 0,
+    /// #     hoist: false,
+    /// #     id: RoleId(2),
+    /// #     managed: false,
+    /// #     mentionable: false,
+    /// #     name: "new name".to_owned(),
+    /// #     permissions: Permissions::empty(),
+    /// #     position: 0,
+    /// #     tags: None,
+    /// # };
+    /// let event = RoleUpdate {
+    ///     guild_id: GuildId(1),
+    ///     role,
+    /// };
+    ///
+    /// if let Some(previous) = cache.update_returning(&event) {
+    ///     println!("role renamed from {} to {}", previous.name, event.role.name);
+    /// }
+    /// ```
+    pub fn update_returning<T: UpdateCacheReturning>(&self, value: &T) -> Option<T::Previous> {
+        value.update_returning(self)
+    }
+
+    /// Insert a channel into the cache, such as one retrieved over HTTP.
+    ///
+    /// This behaves like receiving a [`ChannelCreate`] event. Guild channels
+    /// without a guild ID aren't cached.
+    ///
+    /// This requires the [`ResourceType::CHANNEL`] resource type.
+    pub fn insert_channel(&self, channel: Channel) {
+        self.update(&ChannelCreate(channel));
+    }
+
+    /// Insert a guild into the cache, such as one retrieved over HTTP.
+    ///
+    /// If the guild isn't cached yet then this behaves like receiving a
+    /// [`GuildCreate`] event. Otherwise the guild's information is replaced
+    /// and its emojis and roles are cached, while its other cached resources,
+    /// such as channels and members, are kept since guilds retrieved over HTTP
+    /// don't include them. Fields that are only sent over the gateway, such as
+    /// the member count, keep their cached values.
+    ///
+    /// This requires the [`ResourceType::GUILD`] resource type.
+    ///
+    /// [`GuildCreate`]: twilight_model::gateway::payload::GuildCreate
+    pub fn insert_guild(&self, guild: Guild) {
+        self.replace_guild(guild);
+    }
+
+    /// Insert a member into the cache, such as one retrieved over HTTP.
+    ///
+    /// This behaves like receiving a [`MemberAdd`] event.
+    ///
+    /// This requires the [`ResourceType::MEMBER`] resource type.
+    pub fn insert_member(&self, member: Member) {
+        self.update(&MemberAdd(member));
+    }
+
+    /// Insert a role of a guild into the cache, such as one retrieved over
+    /// HTTP.
+    ///
+    /// This behaves like receiving a [`RoleCreate`] event.
+    ///
+    /// This requires the [`ResourceType::ROLE`] resource type.
+    pub fn insert_role(&self, guild_id: GuildId, role: Role) {
+        self.update(&RoleCreate { guild_id, role });
+    }
+
+    /// Insert a user into the cache, such as one retrieved over HTTP.
+    ///
+    /// If the user is already cached then the user's information is replaced,
+    /// while the guilds the user is known to share with the current user are
+    /// kept.
+    ///
+    /// This requires the [`ResourceType::USER`] resource type.
+    pub fn insert_user(&self, user: User) {
+        if !self.wants(ResourceType::USER) || !self.wants_user(&user) {
+            return;
+        }
+
+        match self.0.users.entry(user.id) {
+            Entry::Occupied(mut entry) => entry.get_mut().0 = user,
+            Entry::Vacant(entry) => {
+                entry.insert((user, BTreeSet::new()));
+            }
+        }
+
+        self.increment_generation();
+    }
+
+    /// Insert voice regions into the cache, such as ones retrieved over HTTP.
+    ///
+    /// Voice regions that are already cached are replaced.
+    ///
+    /// This requires the [`ResourceType::VOICE_REGION`] resource type.
+    pub fn insert_voice_regions(&self, voice_regions: impl IntoIterator<Item = VoiceRegion>) {
+        if !self.wants(ResourceType::VOICE_REGION) {
+            return;
+        }
+
+        for voice_region in voice_regions {
+            self.0
+                .voice_regions
+                .insert(voice_region.id.clone(), voice_region);
+        }
+
+        self.increment_generation();
+    }
+
+    /// Insert the commands of the current application in a scope into the
+    /// cache, such as ones returned when setting commands over HTTP.
+    ///
+    /// Provide the ID of a guild to insert its guild commands, or `None` to
+    /// insert global commands. The previously cached commands of the scope
+    /// are replaced. Commands in other scopes and commands without an ID are
+    /// ignored.
+    ///
+    /// This requires the [`ResourceType::COMMAND`] resource type.
+    pub fn insert_commands(
+        &self,
+        guild_id: impl Into<Option<GuildId>>,
+        commands: impl IntoIterator<Item = Command>,
+    ) {
+        if !self.wants(ResourceType::COMMAND) {
+            return;
+        }
+
+        let guild_id = guild_id.into();
+
+        self.0
+            .commands
+            .retain(|_, command| command.guild_id != guild_id);
+        self.0
+            .command_names
+            .retain(|(scope, _), _| *scope != guild_id);
+
+        for command in commands {
+            if command.guild_id != guild_id {
+                continue;
+            }
+
+            let id = match command.id {
+                Some(id) => id,
+                None => continue,
+            };
+
+            self.0
+                .command_names
+                .insert((guild_id, command.name.clone()), id);
+            self.0.commands.insert(id, command);
+        }
+
+        self.increment_generation();
+    }
+
+    /// Insert the webhooks of a channel into the cache, such as ones
+    /// returned when getting the webhooks of the channel over HTTP.
+    ///
+    /// The previously cached webhooks of the channel are replaced, and the
+    /// channel is no longer marked as having [stale webhooks]. Webhooks in
+    /// other channels are ignored.
+    ///
+    /// This requires the [`ResourceType::WEBHOOK`] resource type.
+    ///
+    /// [stale webhooks]: Self::channel_webhooks_stale
+    pub fn insert_channel_webhooks(
+        &self,
+        channel_id: ChannelId,
+        webhooks: impl IntoIterator<Item = Webhook>,
+    ) {
+        if !self.wants(ResourceType::WEBHOOK) {
+            return;
+        }
+
+        self.delete_channel_webhooks(channel_id);
+
+        let mut webhook_ids = HashSet::new();
+
+        for webhook in webhooks {
+            if webhook.channel_id != channel_id {
+                continue;
+            }
+
+            webhook_ids.insert(webhook.id);
+
+            if let Some(previous) = self.0.webhooks.insert(webhook.id, webhook) {
+                // The webhook was moved from another channel.
+                if let Some(mut channel_webhooks) =
+                    self.0.channel_webhooks.get_mut(&previous.channel_id)
+                {
+                    channel_webhooks.remove(&previous.id);
+                }
+            }
+        }
+
+        self.0.channel_webhooks.insert(channel_id, webhook_ids);
+        self.increment_generation();
+    }
+
     /// Gets the current user.
     ///
     /// This is an O(1) operation.
@@ -355,6 +773,40 @@ impl InMemoryCache {
             .clone()
     }
 
+    /// Gets a command of the current application by ID.
+    ///
+    /// Commands are only cached when inserted with [`insert_commands`].
+    ///
+    /// This is an O(1) operation.
+    ///
+    /// [`insert_commands`]: Self::insert_commands
+    pub fn command(&self, command_id: CommandId) -> Option<Command> {
+        self.0.commands.get(&command_id).map(|r| r.clone())
+    }
+
+    /// Gets a command of the current application by name.
+    ///
+    /// Provide the ID of a guild to get one of its guild commands, or `None`
+    /// to get a global command.
+    ///
+    /// Commands are only cached when inserted with [`insert_commands`].
+    ///
+    /// This is an O(1) operation.
+    ///
+    /// [`insert_commands`]: Self::insert_commands
+    pub fn command_by_name(
+        &self,
+        guild_id: impl Into<Option<GuildId>>,
+        name: &str,
+    ) -> Option<Command> {
+        let id = *self
+            .0
+            .command_names
+            .get(&(guild_id.into(), name.to_owned()))?;
+
+        self.command(id)
+    }
+
     /// Gets an emoji by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_EMOJIS`] intent.
@@ -380,6 +832,81 @@ impl InMemoryCache {
         self.0.guilds.get(&guild_id).map(|r| r.clone())
     }
 
+    /// Gets whether a guild is unavailable due to an outage.
+    ///
+    /// Guilds are marked unavailable when an [`UnavailableGuild`] event or an
+    /// unavailable [`GuildDelete`] event is received, and become available
+    /// again when the guild is created. A guild the current user was removed
+    /// from isn't marked unavailable.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GuildDelete`]: twilight_model::gateway::payload::GuildDelete
+    /// [`UnavailableGuild`]: twilight_model::gateway::payload::UnavailableGuild
+    pub fn guild_is_unavailable(&self, guild_id: GuildId) -> bool {
+        self.0.unavailable_guilds.contains(&guild_id)
+    }
+
+    /// Gets the IDs of the guilds that are unavailable due to an outage,
+    /// sorted by ID.
+    ///
+    /// This is an O(n) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn unavailable_guilds(&self) -> Vec<GuildId> {
+        let mut guild_ids = self
+            .0
+            .unavailable_guilds
+            .iter()
+            .map(|r| *r.key())
+            .collect::<Vec<_>>();
+        guild_ids.sort_unstable();
+
+        guild_ids
+    }
+
+    /// Gets the total number of members in a guild as sent by Discord.
+    ///
+    /// Unlike the length of [`guild_members`], this includes members that
+    /// aren't cached.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`guild_members`]: Self::guild_members
+    pub fn guild_member_count(&self, guild_id: GuildId) -> Option<u64> {
+        self.0
+            .guilds
+            .get(&guild_id)
+            .and_then(|guild| guild.member_count)
+    }
+
+    /// Gets whether a guild's widget is enabled.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_widget_enabled(&self, guild_id: GuildId) -> Option<bool> {
+        self.0
+            .guilds
+            .get(&guild_id)
+            .and_then(|guild| guild.widget_enabled)
+    }
+
+    /// Gets the ID of the channel that a guild's widget generates an invite
+    /// to.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_widget_channel_id(&self, guild_id: GuildId) -> Option<ChannelId> {
+        self.0
+            .guilds
+            .get(&guild_id)
+            .and_then(|guild| guild.widget_channel_id)
+    }
+
     /// Gets a channel by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -392,6 +919,51 @@ impl InMemoryCache {
             .map(|r| r.data.clone())
     }
 
+    /// Gets the ID of the guild a channel is in.
+    ///
+    /// This is an O(1) operation. Unlike [`guild_channel`], this doesn't
+    /// clone the channel. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`guild_channel`]: Self::guild_channel
+    pub fn channel_guild_id(&self, channel_id: ChannelId) -> Option<GuildId> {
+        self.0.channels_guild.get(&channel_id).map(|r| r.guild_id)
+    }
+
+    /// Gets the set of threads in a text or news channel.
+    ///
+    /// This is an O(m) operation, where m is the amount of threads in the
+    /// channel. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn channel_threads(&self, parent_id: ChannelId) -> Option<HashSet<ChannelId>> {
+        self.0.channel_threads.get(&parent_id).map(|r| r.clone())
+    }
+
+    /// Gets the set of webhooks in a channel.
+    ///
+    /// Webhooks are only cached when inserted with
+    /// [`insert_channel_webhooks`].
+    ///
+    /// This is an O(m) operation, where m is the amount of webhooks in the
+    /// channel. This requires the [`ResourceType::WEBHOOK`] resource type.
+    ///
+    /// [`insert_channel_webhooks`]: Self::insert_channel_webhooks
+    pub fn channel_webhooks(&self, channel_id: ChannelId) -> Option<HashSet<WebhookId>> {
+        self.0.channel_webhooks.get(&channel_id).map(|r| r.clone())
+    }
+
+    /// Whether the webhooks of a channel have been updated since they were
+    /// last inserted, meaning that they should be fetched again.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_WEBHOOKS`] intent
+    /// and the [`ResourceType::WEBHOOK`] resource type.
+    ///
+    /// [`GUILD_WEBHOOKS`]: ::twilight_model::gateway::Intents::GUILD_WEBHOOKS
+    pub fn channel_webhooks_stale(&self, channel_id: ChannelId) -> bool {
+        self.0.stale_webhook_channels.contains(&channel_id)
+    }
+
     /// Gets the set of channels in a guild.
     ///
     /// This is a O(m) operation, where m is the amount of channels in the
@@ -402,6 +974,76 @@ impl InMemoryCache {
         self.0.guild_channels.get(&guild_id).map(|r| r.clone())
     }
 
+    /// Gets the IDs of the channels of a type in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of channels in the
+    /// guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_channel_ids_of_type(
+        &self,
+        guild_id: GuildId,
+        kind: ChannelType,
+    ) -> Option<Vec<ChannelId>> {
+        let channel_ids = self.0.guild_channels.get(&guild_id)?;
+
+        Some(
+            channel_ids
+                .iter()
+                .filter(|id| {
+                    self.0
+                        .channels_guild
+                        .get(id)
+                        .map_or(false, |r| r.data.kind() == kind)
+                })
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// Gets the channels of a type in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of channels in the
+    /// guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_channels_of_type(
+        &self,
+        guild_id: GuildId,
+        kind: ChannelType,
+    ) -> Option<Vec<GuildChannel>> {
+        let channel_ids = self.0.guild_channels.get(&guild_id)?;
+
+        Some(
+            channel_ids
+                .iter()
+                .filter_map(|id| self.0.channels_guild.get(id))
+                .filter(|r| r.data.kind() == kind)
+                .map(|r| r.data.clone())
+                .collect(),
+        )
+    }
+
+    /// Gets an emoji of a guild by its name.
+    ///
+    /// Names are compared case-sensitively. If multiple emojis of the guild
+    /// have the name, then any of them may be returned.
+    ///
+    /// This is a O(m) operation, where m is the amount of emojis in the guild.
+    /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
+    pub fn guild_emoji_by_name(&self, guild_id: GuildId, name: &str) -> Option<CachedEmoji> {
+        let emoji_ids = self.0.guild_emojis.get(&guild_id)?;
+
+        emoji_ids
+            .iter()
+            .filter_map(|id| self.0.emojis.get(id))
+            .find(|r| r.data.name == name)
+            .map(|r| r.data.clone())
+    }
+
     /// Gets the set of emojis in a guild.
     ///
     /// This is a O(m) operation, where m is the amount of emojis in the guild.
@@ -425,6 +1067,48 @@ impl InMemoryCache {
         self.0.guild_members.get(&guild_id).map(|r| r.clone())
     }
 
+    /// Gets the members in a guild along with their users.
+    ///
+    /// Members whose user isn't cached are skipped. This list may be
+    /// incomplete if not all members have been cached.
+    ///
+    /// This is an O(m) operation, where m is the amount of members in the
+    /// guild. This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn guild_members_full(&self, guild_id: GuildId) -> Option<Vec<(CachedMember, User)>> {
+        let user_ids = self.0.guild_members.get(&guild_id)?;
+
+        Some(
+            user_ids
+                .iter()
+                .filter_map(|user_id| {
+                    let member = self.0.members.get(&(guild_id, *user_id))?.clone();
+                    let user = self.0.users.get(user_id)?.0.clone();
+                    self.access_member(guild_id, *user_id);
+
+                    Some((member, user))
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets the member of a guild that owns it.
+    ///
+    /// Returns `None` if the guild or the owner's member isn't cached, which
+    /// may be the case in large guilds if members haven't been requested.
+    ///
+    /// This is an O(1) operation. This requires both the [`GUILDS`] and
+    /// [`GUILD_MEMBERS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn guild_owner(&self, guild_id: GuildId) -> Option<CachedMember> {
+        let owner_id = self.0.guilds.get(&guild_id)?.owner_id;
+
+        self.member(guild_id, owner_id)
+    }
+
     /// Gets the set of presences in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
@@ -447,7 +1131,77 @@ impl InMemoryCache {
         self.0.guild_roles.get(&guild_id).map(|r| r.clone())
     }
 
-    /// Gets the set of stage instances in a guild.
+    /// Gets the roles in a guild, sorted from highest to lowest position.
+    ///
+    /// Roles with the same position are sorted by ID. Unlike [`guild_roles`],
+    /// this returns the roles themselves rather than their IDs.
+    ///
+    /// This is an O(m log m) operation, where m is the amount of roles in the
+    /// guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`guild_roles`]: Self::guild_roles
+    pub fn guild_roles_full(&self, guild_id: GuildId) -> Option<Vec<Role>> {
+        let role_ids = self.0.guild_roles.get(&guild_id)?;
+
+        let mut roles = role_ids
+            .iter()
+            .filter_map(|id| self.0.roles.get(id).map(|r| r.data.clone()))
+            .collect::<Vec<_>>();
+        roles.sort_by(|a, b| b.position.cmp(&a.position).then(a.id.cmp(&b.id)));
+
+        Some(roles)
+    }
+
+    /// Gets the integrations in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of integrations in the
+    /// guild. This requires the [`GUILD_INTEGRATIONS`] intent.
+    ///
+    /// [`GUILD_INTEGRATIONS`]: twilight_model::gateway::Intents::GUILD_INTEGRATIONS
+    pub fn guild_integrations(&self, guild_id: GuildId) -> Option<Vec<GuildIntegration>> {
+        let integration_ids = self.0.guild_integrations.get(&guild_id)?;
+
+        Some(
+            integration_ids
+                .iter()
+                .filter_map(|id| {
+                    self.0
+                        .integrations
+                        .get(&(guild_id, *id))
+                        .map(|r| r.data.clone())
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets the codes of the invites in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of invites in the
+    /// guild. This requires the [`GUILD_INVITES`] intent.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn guild_invites(&self, guild_id: GuildId) -> Option<Vec<String>> {
+        self.0
+            .guild_invites
+            .get(&guild_id)
+            .map(|r| r.iter().cloned().collect())
+    }
+
+    /// Gets the set of scheduled events in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of scheduled events in
+    /// the guild. This requires the [`GUILD_SCHEDULED_EVENTS`] intent.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> Option<HashSet<ScheduledEventId>> {
+        self.0
+            .guild_scheduled_events
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets the set of stage instances in a guild.
     ///
     /// This is a O(m) operation, where m is the amount of stage instances in
     /// the guild. This requires the [`GUILDS`] intent.
@@ -460,13 +1214,122 @@ impl InMemoryCache {
             .map(|r| r.value().clone())
     }
 
+    /// Gets the voice states of users connected to voice channels in a guild.
+    ///
+    /// This is an O(m) operation, where m is the amount of users connected to
+    /// voice channels in the guild. This requires both the [`GUILDS`] and
+    /// [`GUILD_VOICE_STATES`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_VOICE_STATES`]: ::twilight_model::gateway::Intents::GUILD_VOICE_STATES
+    pub fn guild_voice_states(&self, guild_id: GuildId) -> Option<Vec<VoiceState>> {
+        let user_ids = self.0.voice_state_guilds.get(&guild_id)?;
+
+        Some(
+            user_ids
+                .iter()
+                .filter_map(|user_id| {
+                    self.0
+                        .voice_states
+                        .get(&(guild_id, *user_id))
+                        .map(|r| r.clone())
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets an integration in a guild by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_INTEGRATIONS`]
+    /// intent.
+    ///
+    /// [`GUILD_INTEGRATIONS`]: twilight_model::gateway::Intents::GUILD_INTEGRATIONS
+    pub fn integration(
+        &self,
+        guild_id: GuildId,
+        integration_id: IntegrationId,
+    ) -> Option<GuildIntegration> {
+        self.0
+            .integrations
+            .get(&(guild_id, integration_id))
+            .map(|r| r.data.clone())
+    }
+
+    /// Gets an invite by its code.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_INVITES`] intent.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn invite(&self, code: &str) -> Option<CachedInvite> {
+        self.0.invites.get(code).map(|r| r.clone())
+    }
+
     /// Gets a member by guild ID and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
     ///
     /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
     pub fn member(&self, guild_id: GuildId, user_id: UserId) -> Option<CachedMember> {
-        self.0.members.get(&(guild_id, user_id)).map(|r| r.clone())
+        let member = self
+            .0
+            .members
+            .get(&(guild_id, user_id))
+            .map(|r| r.clone())?;
+        self.access_member(guild_id, user_id);
+
+        Some(member)
+    }
+
+    /// Gets the ID and position of a member's highest role.
+    ///
+    /// Roles with equal positions are ordered by their IDs, with the lower ID
+    /// being higher, like Discord does. Returns `None` if the member or none
+    /// of their roles are cached, including if the member has no roles other
+    /// than the implicit `@everyone` role.
+    ///
+    /// This is an O(m) operation, where m is the amount of roles the member
+    /// has. This requires both the [`GUILD_MEMBERS`] and [`GUILDS`] intents.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn member_highest_role(&self, guild_id: GuildId, user_id: UserId) -> Option<(RoleId, i64)> {
+        let member = self.member(guild_id, user_id)?;
+
+        member
+            .roles
+            .iter()
+            .filter_map(|role_id| {
+                self.0
+                    .roles
+                    .get(role_id)
+                    .map(|role| (role.data.id, role.data.position))
+            })
+            .max_by(|(a_id, a_position), (b_id, b_position)| {
+                a_position.cmp(b_position).then(b_id.cmp(a_id))
+            })
+    }
+
+    /// Gets the users who are members of both of two guilds.
+    ///
+    /// This list may be incomplete if not all members have been cached.
+    ///
+    /// This is a O(m) operation, where m is the amount of members in the first
+    /// guild. This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn mutual_members(&self, guild_a: GuildId, guild_b: GuildId) -> Vec<UserId> {
+        // Clone the first set so that two locks to the same map aren't held at
+        // once.
+        let members_a = match self.0.guild_members.get(&guild_a) {
+            Some(members) => members.clone(),
+            None => return Vec::new(),
+        };
+
+        self.0
+            .guild_members
+            .get(&guild_b)
+            .map(|members_b| members_a.intersection(&members_b).copied().collect())
+            .unwrap_or_default()
     }
 
     /// Gets a message by channel ID and message ID.
@@ -482,6 +1345,48 @@ impl InMemoryCache {
         channel.iter().find(|msg| msg.id == message_id).cloned()
     }
 
+    /// Gets when a message was last edited by channel ID and message ID.
+    ///
+    /// Returns `None` if the message isn't cached or hasn't been edited.
+    ///
+    /// This is an O(n) operation. This requires one or both of the
+    /// [`GUILD_MESSAGES`] or [`DIRECT_MESSAGES`] intents.
+    ///
+    /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    pub fn message_edited(&self, channel_id: ChannelId, message_id: MessageId) -> Option<String> {
+        let channel = self.0.messages.get(&channel_id)?;
+
+        channel
+            .iter()
+            .find(|msg| msg.id == message_id)?
+            .edited_timestamp
+            .clone()
+    }
+
+    /// Gets the reactions to a message by channel ID and message ID.
+    ///
+    /// Each reaction contains the total count of users who reacted with its
+    /// emoji.
+    ///
+    /// This is an O(n) operation. This requires one or both of the
+    /// [`GUILD_MESSAGE_REACTIONS`] or [`DIRECT_MESSAGE_REACTIONS`] intents.
+    ///
+    /// [`GUILD_MESSAGE_REACTIONS`]: ::twilight_model::gateway::Intents::GUILD_MESSAGE_REACTIONS
+    /// [`DIRECT_MESSAGE_REACTIONS`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGE_REACTIONS
+    pub fn message_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Option<Vec<MessageReaction>> {
+        let channel = self.0.messages.get(&channel_id)?;
+
+        channel
+            .iter()
+            .find(|msg| msg.id == message_id)
+            .map(|msg| msg.reactions.clone())
+    }
+
     /// Gets a presence by, optionally, guild ID, and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_PRESENCES`] intent.
@@ -494,6 +1399,22 @@ impl InMemoryCache {
             .map(|r| r.clone())
     }
 
+    /// Gets the activities of a user in a guild, such as the game they're
+    /// playing or their custom status.
+    ///
+    /// Unlike [`presence`], this doesn't clone the rest of the presence.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_PRESENCES`] intent.
+    ///
+    /// [`GUILD_PRESENCES`]: ::twilight_model::gateway::Intents::GUILD_PRESENCES
+    /// [`presence`]: Self::presence
+    pub fn user_activity(&self, guild_id: GuildId, user_id: UserId) -> Option<Vec<Activity>> {
+        self.0
+            .presences
+            .get(&(guild_id, user_id))
+            .map(|r| r.activities.clone())
+    }
+
     /// Gets a private channel by ID.
     ///
     /// This is an O(1) operation. This requires the [`DIRECT_MESSAGES`] intent.
@@ -503,6 +1424,27 @@ impl InMemoryCache {
         self.0.channels_private.get(&channel_id).map(|r| r.clone())
     }
 
+    /// Gets the ID of the user the current user is in a private channel with.
+    ///
+    /// This is the first recipient of the private channel that isn't the
+    /// current user. Groups aren't private channels, so this returns `None`
+    /// for groups.
+    ///
+    /// This is an O(n) operation, where n is the number of recipients of the
+    /// private channel. This requires the [`DIRECT_MESSAGES`] intent.
+    ///
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    pub fn dm_recipient(&self, channel_id: ChannelId) -> Option<UserId> {
+        let current_user_id = self.current_user().map(|user| user.id);
+        let channel = self.0.channels_private.get(&channel_id)?;
+
+        channel
+            .recipients
+            .iter()
+            .map(|user| user.id)
+            .find(|id| Some(*id) != current_user_id)
+    }
+
     /// Gets a role by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -512,6 +1454,19 @@ impl InMemoryCache {
         self.0.roles.get(&role_id).map(|r| r.data.clone())
     }
 
+    /// Gets a guild scheduled event by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_SCHEDULED_EVENTS`]
+    /// intent.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn scheduled_event(&self, event_id: ScheduledEventId) -> Option<GuildScheduledEvent> {
+        self.0
+            .scheduled_events
+            .get(&event_id)
+            .map(|event| event.data.clone())
+    }
+
     /// Gets a stage instance by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -524,6 +1479,87 @@ impl InMemoryCache {
             .map(|role| role.data.clone())
     }
 
+    /// Gets a thread by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn thread(&self, channel_id: ChannelId) -> Option<ThreadChannel> {
+        self.0
+            .threads
+            .get(&channel_id)
+            .map(|thread| thread.data.clone())
+    }
+
+    /// Gets the IDs of the users currently speaking in a stage.
+    ///
+    /// Speakers are the users in the stage instance's channel whose voice
+    /// states aren't suppressed.
+    ///
+    /// This is an O(m) operation, where m is the amount of users connected to
+    /// the stage channel. This requires both the [`GUILDS`] and
+    /// [`GUILD_VOICE_STATES`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_VOICE_STATES`]: ::twilight_model::gateway::Intents::GUILD_VOICE_STATES
+    pub fn stage_speakers(&self, stage_id: StageId) -> Option<Vec<UserId>> {
+        let channel_id = self.0.stage_instances.get(&stage_id)?.data.channel_id;
+
+        let speakers = self
+            .0
+            .voice_state_channels
+            .get(&channel_id)
+            .map(|keys| {
+                keys.iter()
+                    .filter(|key| {
+                        self.0
+                            .voice_states
+                            .get(key)
+                            .map_or(false, |voice_state| !voice_state.suppress)
+                    })
+                    .map(|(_, user_id)| *user_id)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(speakers)
+    }
+
+    /// Gets the IDs of the users currently typing in a channel.
+    ///
+    /// Users are considered to be typing for 10 seconds after they last
+    /// started typing, after which they are removed from the cache.
+    ///
+    /// This is an O(m) operation, where m is the amount of users that have
+    /// recently typed in the channel. This requires the [`GUILD_MESSAGE_TYPING`]
+    /// or [`DIRECT_MESSAGE_TYPING`] intent and the [`ResourceType::TYPING`]
+    /// resource type, which isn't enabled by default.
+    ///
+    /// [`GUILD_MESSAGE_TYPING`]: ::twilight_model::gateway::Intents::GUILD_MESSAGE_TYPING
+    /// [`DIRECT_MESSAGE_TYPING`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGE_TYPING
+    pub fn typing_users(&self, channel_id: ChannelId) -> Vec<UserId> {
+        self.typing_users_at(channel_id, Instant::now())
+    }
+
+    fn typing_users_at(&self, channel_id: ChannelId, now: Instant) -> Vec<UserId> {
+        let users = match self.0.typing.get_mut(&channel_id) {
+            Some(mut typing) => {
+                typing.retain(|_, started| now.duration_since(*started) < TYPING_DURATION);
+
+                typing.keys().copied().collect::<Vec<_>>()
+            }
+            None => return Vec::new(),
+        };
+
+        if users.is_empty() {
+            self.0
+                .typing
+                .remove_if(&channel_id, |_, typing| typing.is_empty());
+        }
+
+        users
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -533,6 +1569,22 @@ impl InMemoryCache {
         self.0.users.get(&user_id).map(|r| r.0.clone())
     }
 
+    /// Gets the IDs of the guilds a user is known to share with the current
+    /// user, in ascending order.
+    ///
+    /// This list may be incomplete if not all members have been cached.
+    ///
+    /// This is an O(m) operation, where m is the amount of guilds the user is
+    /// in. This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn user_guilds(&self, user_id: UserId) -> Option<Vec<GuildId>> {
+        self.0
+            .users
+            .get(&user_id)
+            .map(|r| r.1.iter().copied().collect())
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -561,6 +1613,41 @@ impl InMemoryCache {
         )
     }
 
+    /// Gets the cached voice regions, sorted by their IDs.
+    ///
+    /// Voice regions are only cached when inserted with
+    /// [`insert_voice_regions`].
+    ///
+    /// This is an O(n) operation.
+    ///
+    /// [`insert_voice_regions`]: Self::insert_voice_regions
+    pub fn voice_regions(&self) -> Vec<VoiceRegion> {
+        let mut voice_regions = self
+            .0
+            .voice_regions
+            .iter()
+            .map(|r| r.value().clone())
+            .collect::<Vec<_>>();
+        voice_regions.sort_by(|a, b| a.id.cmp(&b.id));
+
+        voice_regions
+    }
+
+    /// Gets the latest voice server of a guild.
+    ///
+    /// Voice libraries, such as Lavalink clients, need its endpoint and token,
+    /// along with the session ID of the current user's voice state, to
+    /// connect to the guild's voice channel.
+    ///
+    /// This is an O(1) operation. This requires the
+    /// [`ResourceType::VOICE_SERVER`] resource type.
+    pub fn voice_server(&self, guild_id: GuildId) -> Option<CachedVoiceServer> {
+        self.0
+            .voice_servers
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets a voice state by user ID and Guild ID.
     ///
     /// This is an O(1) operation. This requires both the [`GUILDS`] and
@@ -575,13 +1662,68 @@ impl InMemoryCache {
             .map(|r| r.clone())
     }
 
-    fn new_with_config(config: Config) -> Self {
+    /// Gets a webhook by ID.
+    ///
+    /// Webhooks are only cached when inserted with
+    /// [`insert_channel_webhooks`].
+    ///
+    /// This is an O(1) operation. This requires the [`ResourceType::WEBHOOK`]
+    /// resource type.
+    ///
+    /// [`insert_channel_webhooks`]: Self::insert_channel_webhooks
+    pub fn webhook(&self, webhook_id: WebhookId) -> Option<Webhook> {
+        self.0.webhooks.get(&webhook_id).map(|r| r.clone())
+    }
+
+    fn new_with_config(
+        config: Config,
+        on_change: Option<OnChange>,
+        user_filter: Option<UserFilter>,
+    ) -> Self {
         Self(Arc::new(InMemoryCacheRef {
             config,
+            on_change,
+            user_filter,
             ..Default::default()
         }))
     }
 
+    /// Notify the configured callback, if any, of a change to the cache.
+    ///
+    /// No locks to the cache's maps may be held when calling this.
+    fn notify(&self, update: CacheUpdate) {
+        if let Some(on_change) = self.0.on_change.as_ref() {
+            on_change.call(update);
+        }
+    }
+
+    /// Determine whether a user should be cached according to the configured
+    /// user filter, if any.
+    ///
+    /// The current user is always wanted.
+    fn wants_user(&self, user: &User) -> bool {
+        let filter = match self.0.user_filter.as_ref() {
+            Some(filter) => filter,
+            None => return true,
+        };
+
+        let is_current_user = self
+            .0
+            .current_user
+            .lock()
+            .expect("current user poisoned")
+            .as_ref()
+            .map_or(false, |current_user| current_user.id == user.id);
+
+        is_current_user || filter.wants(user)
+    }
+
+    /// Increment the generation of the cache, marking that an update has been
+    /// processed.
+    fn increment_generation(&self) {
+        self.0.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Determine whether the configured cache wants a specific resource to be
     /// processed.
     fn wants(&self, resource_type: ResourceType) -> bool {
@@ -597,6 +1739,16 @@ pub trait UpdateCache {
     fn update(&self, cache: &InMemoryCache) {}
 }
 
+/// Implemented for dispatch events that replace a cached resource.
+pub trait UpdateCacheReturning: UpdateCache {
+    /// Type of the cached resource that the event replaces.
+    type Previous;
+
+    /// Updates the cache based on data contained within an event, returning
+    /// the previously cached version of the resource if there was one.
+    fn update_returning(&self, cache: &InMemoryCache) -> Option<Self::Previous>;
+}
+
 impl UpdateCache for Event {
     #[allow(clippy::cognitive_complexity)]
     fn update(&self, c: &InMemoryCache) {
@@ -619,13 +1771,16 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(_) => {}
+            GuildScheduledEventCreate(v) => c.update(v.deref()),
+            GuildScheduledEventDelete(v) => c.update(v.deref()),
+            GuildScheduledEventUpdate(v) => c.update(v.deref()),
             GuildUpdate(v) => c.update(v.deref()),
             IntegrationCreate(v) => c.update(v.deref()),
             IntegrationDelete(v) => c.update(v.deref()),
             IntegrationUpdate(v) => c.update(v.deref()),
             InteractionCreate(v) => c.update(v.deref()),
-            InviteCreate(_) => {}
-            InviteDelete(_) => {}
+            InviteCreate(v) => c.update(v.deref()),
+            InviteDelete(v) => c.update(v),
             MemberAdd(v) => c.update(v.deref()),
             MemberRemove(v) => c.update(v),
             MemberUpdate(v) => c.update(v.deref()),
@@ -655,24 +1810,211 @@ impl UpdateCache for Event {
             StageInstanceCreate(v) => c.update(v),
             StageInstanceDelete(v) => c.update(v),
             StageInstanceUpdate(v) => c.update(v),
-            TypingStart(_) => {}
+            ThreadCreate(v) => c.update(v.deref()),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v.deref()),
+            TypingStart(v) => c.update(v.deref()),
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
-            VoiceServerUpdate(_) => {}
+            VoiceServerUpdate(v) => c.update(v),
             VoiceStateUpdate(v) => c.update(v.deref()),
-            WebhooksUpdate(_) => {}
+            WebhooksUpdate(v) => c.update(v),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, InMemoryCache};
+    use crate::{test, InMemoryCache, ResourceType};
     use twilight_model::{
-        gateway::payload::RoleDelete,
-        id::{EmojiId, GuildId, RoleId, UserId},
+        application::command::Command,
+        channel::{Channel, ChannelType, GuildChannel, VoiceChannel},
+        gateway::{
+            event::Event,
+            payload::{GuildCreate, RoleCreate, RoleDelete},
+        },
+        guild::Role,
+        id::{ChannelId, CommandId, EmojiId, GuildId, RoleId, UserId},
+        voice::VoiceRegion,
     };
 
+    #[test]
+    fn test_insert_member() {
+        let cache = InMemoryCache::new();
+        let member = test::member(UserId(2), GuildId(1));
+        cache.insert_member(member.clone());
+
+        let cached = cache
+            .member(GuildId(1), UserId(2))
+            .expect("member is cached");
+        assert_eq!(cached, member);
+        assert!(cache.user(UserId(2)).is_some());
+        assert_eq!(
+            Some([UserId(2)].iter().copied().collect()),
+            cache.guild_members(GuildId(1)),
+        );
+        assert_eq!(1, cache.generation());
+    }
+
+    #[test]
+    fn test_insert_guild() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let mut guild = test::guild(guild_id, UserId(2));
+        guild.channels = vec![channel];
+        cache.update(&GuildCreate(guild));
+
+        // Guilds retrieved over HTTP don't include their channels.
+        let mut guild = test::guild(guild_id, UserId(2));
+        guild.name = "renamed".to_owned();
+        guild.roles = vec![test::role(RoleId(3))];
+        cache.insert_guild(guild);
+
+        assert_eq!("renamed", cache.guild(guild_id).unwrap().name);
+        assert!(cache.guild_channel(channel_id).is_some());
+        assert_eq!(
+            Some([channel_id].iter().copied().collect()),
+            cache.guild_channels(guild_id),
+        );
+        assert!(cache.role(RoleId(3)).is_some());
+
+        let guild_id = GuildId(4);
+        cache.insert_guild(test::guild(guild_id, UserId(2)));
+        assert!(cache.guild(guild_id).is_some());
+    }
+
+    #[test]
+    fn test_insert_channel_and_role() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        cache.insert_channel(Channel::Guild(channel));
+        cache.insert_role(guild_id, test::role(RoleId(3)));
+
+        assert!(cache.guild_channel(channel_id).is_some());
+        assert_eq!(
+            Some([RoleId(3)].iter().copied().collect()),
+            cache.guild_roles(guild_id),
+        );
+    }
+
+    #[test]
+    fn test_channel_guild_id() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        assert!(cache.channel_guild_id(channel_id).is_none());
+
+        cache.insert_channel(Channel::Guild(channel));
+        assert_eq!(Some(guild_id), cache.channel_guild_id(channel_id));
+    }
+
+    #[test]
+    fn test_guild_member_count() {
+        let cache = InMemoryCache::new();
+        let mut guild = test::guild(GuildId(1), UserId(2));
+        guild.member_count = Some(5000);
+        guild.members = vec![
+            test::member(UserId(2), GuildId(1)),
+            test::member(UserId(3), GuildId(1)),
+        ];
+        cache.update(&GuildCreate(guild));
+
+        assert_eq!(Some(2), cache.guild_members(GuildId(1)).map(|m| m.len()));
+        assert_eq!(Some(5000), cache.guild_member_count(GuildId(1)));
+    }
+
+    #[test]
+    fn test_commands() {
+        let command = |id, guild_id, name: &str| Command {
+            application_id: None,
+            guild_id,
+            name: name.to_owned(),
+            default_permission: None,
+            description: "description".to_owned(),
+            id: Some(CommandId(id)),
+            options: Vec::new(),
+        };
+
+        let cache = InMemoryCache::new();
+        cache.insert_commands(
+            None,
+            vec![
+                command(1, None, "ping"),
+                command(2, None, "help"),
+                command(3, Some(GuildId(4)), "ignored"),
+            ],
+        );
+        cache.insert_commands(GuildId(4), vec![command(3, Some(GuildId(4)), "ping")]);
+
+        assert_eq!(
+            Some(CommandId(1)),
+            cache.command_by_name(None, "ping").and_then(|c| c.id)
+        );
+        assert_eq!(
+            Some(CommandId(2)),
+            cache.command_by_name(None, "help").and_then(|c| c.id)
+        );
+        assert_eq!(
+            Some(CommandId(3)),
+            cache.command_by_name(GuildId(4), "ping").and_then(|c| c.id)
+        );
+        assert!(cache.command_by_name(None, "ignored").is_none());
+        assert!(cache.command_by_name(None, "missing").is_none());
+
+        // Inserting global commands replaces the global scope only.
+        cache.insert_commands(None, vec![command(1, None, "pong")]);
+        assert!(cache.command_by_name(None, "ping").is_none());
+        assert!(cache.command_by_name(None, "help").is_none());
+        assert!(cache.command(CommandId(2)).is_none());
+        assert_eq!(
+            Some(CommandId(1)),
+            cache.command_by_name(None, "pong").and_then(|c| c.id)
+        );
+        assert!(cache.command(CommandId(3)).is_some());
+
+        cache.insert_commands(GuildId(4), Vec::new());
+        assert!(cache.command(CommandId(3)).is_none());
+        assert!(cache.command_by_name(GuildId(4), "ping").is_none());
+        assert!(cache.command(CommandId(1)).is_some());
+
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::COMMAND)
+            .build();
+        cache.insert_commands(None, vec![command(1, None, "ping")]);
+        assert!(cache.command(CommandId(1)).is_none());
+    }
+
+    #[test]
+    fn test_voice_regions() {
+        let region = |id: &str, optimal| VoiceRegion {
+            custom: false,
+            deprecated: false,
+            id: id.to_owned(),
+            name: id.to_uppercase(),
+            optimal,
+            vip: false,
+        };
+
+        let cache = InMemoryCache::new();
+        assert!(cache.voice_regions().is_empty());
+
+        cache.insert_voice_regions(vec![region("us-west", false), region("europe", true)]);
+        cache.insert_voice_regions(vec![region("us-west", true)]);
+        assert_eq!(
+            vec![region("europe", true), region("us-west", true)],
+            cache.voice_regions(),
+        );
+
+        cache.clear();
+        assert!(cache.voice_regions().is_empty());
+
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::VOICE_REGION)
+            .build();
+        cache.insert_voice_regions(vec![region("europe", true)]);
+        assert!(cache.voice_regions().is_empty());
+    }
+
     #[test]
     fn test_syntax_update() {
         let cache = InMemoryCache::new();
@@ -682,6 +2024,220 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_generation() {
+        let cache = InMemoryCache::new();
+        assert_eq!(0, cache.generation());
+
+        cache.update(&Event::RoleCreate(RoleCreate {
+            guild_id: GuildId(1),
+            role: test::role(RoleId(2)),
+        }));
+        let generation = cache.generation();
+        assert!(generation > 0);
+
+        // Events that the cache doesn't process don't change the generation.
+        cache.update(&Event::GatewayHeartbeatAck);
+        assert_eq!(generation, cache.generation());
+    }
+
+    #[test]
+    fn test_mutual_members() {
+        let cache = InMemoryCache::new();
+        cache.cache_members(
+            GuildId(1),
+            (1..=3).map(|id| test::member(UserId(id), GuildId(1))),
+        );
+        cache.cache_members(
+            GuildId(2),
+            (2..=4).map(|id| test::member(UserId(id), GuildId(2))),
+        );
+
+        let mut mutual = cache.mutual_members(GuildId(1), GuildId(2));
+        mutual.sort();
+        assert_eq!(vec![UserId(2), UserId(3)], mutual);
+
+        assert!(cache.mutual_members(GuildId(1), GuildId(3)).is_empty());
+    }
+
+    #[test]
+    fn test_guild_channel_ids_of_type() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, text) = test::guild_channel_text();
+        let voice = GuildChannel::Voice(VoiceChannel {
+            bitrate: 64_000,
+            guild_id: Some(guild_id),
+            id: ChannelId(3),
+            kind: ChannelType::GuildVoice,
+            name: "voice".to_owned(),
+            parent_id: None,
+            permission_overwrites: Vec::new(),
+            position: 4,
+            rtc_region: None,
+            user_limit: None,
+            video_quality_mode: None,
+        });
+        cache.cache_guild_channels(guild_id, vec![text, voice]);
+
+        assert_eq!(
+            Some(vec![ChannelId(3)]),
+            cache.guild_channel_ids_of_type(guild_id, ChannelType::GuildVoice)
+        );
+        assert_eq!(
+            Some(vec![channel_id]),
+            cache.guild_channel_ids_of_type(guild_id, ChannelType::GuildText)
+        );
+        assert!(cache
+            .guild_channel_ids_of_type(GuildId(5), ChannelType::GuildVoice)
+            .is_none());
+    }
+
+    #[test]
+    fn test_guild_channels_of_type() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, text) = test::guild_channel_text();
+        let voice = GuildChannel::Voice(VoiceChannel {
+            bitrate: 64_000,
+            guild_id: Some(guild_id),
+            id: ChannelId(3),
+            kind: ChannelType::GuildVoice,
+            name: "voice".to_owned(),
+            parent_id: None,
+            permission_overwrites: Vec::new(),
+            position: 4,
+            rtc_region: None,
+            user_limit: None,
+            video_quality_mode: None,
+        });
+        cache.cache_guild_channels(guild_id, vec![text, voice]);
+
+        let texts = cache
+            .guild_channels_of_type(guild_id, ChannelType::GuildText)
+            .unwrap();
+        assert_eq!(1, texts.len());
+        assert_eq!(channel_id, texts[0].id());
+
+        let voices = cache
+            .guild_channels_of_type(guild_id, ChannelType::GuildVoice)
+            .unwrap();
+        assert_eq!(1, voices.len());
+        assert_eq!(ChannelId(3), voices[0].id());
+
+        assert!(cache
+            .guild_channels_of_type(guild_id, ChannelType::GuildCategory)
+            .unwrap()
+            .is_empty());
+        assert!(cache
+            .guild_channels_of_type(GuildId(5), ChannelType::GuildText)
+            .is_none());
+    }
+
+    #[test]
+    fn test_insert_user() {
+        let cache = InMemoryCache::new();
+        cache.cache_member(GuildId(1), test::member(UserId(2), GuildId(1)));
+
+        let mut user = test::user(UserId(2));
+        user.name = "renamed".to_owned();
+        cache.insert_user(user.clone());
+        cache.insert_user(test::user(UserId(3)));
+
+        assert_eq!(Some(user), cache.user(UserId(2)));
+        assert_eq!(Some(vec![GuildId(1)]), cache.user_guilds(UserId(2)));
+        assert_eq!(Some(Vec::new()), cache.user_guilds(UserId(3)));
+    }
+
+    #[test]
+    fn test_guild_members_full() {
+        let cache = InMemoryCache::new();
+        assert!(cache.guild_members_full(GuildId(1)).is_none());
+
+        cache.cache_member(GuildId(1), test::member(UserId(2), GuildId(1)));
+        cache.cache_member(GuildId(1), test::member(UserId(3), GuildId(1)));
+
+        let mut members = cache.guild_members_full(GuildId(1)).unwrap();
+        members.sort_by_key(|(member, _)| member.user_id);
+
+        assert_eq!(2, members.len());
+        assert_eq!(UserId(2), members[0].0.user_id);
+        assert_eq!(test::user(UserId(2)), members[0].1);
+        assert_eq!(UserId(3), members[1].0.user_id);
+        assert_eq!(test::user(UserId(3)), members[1].1);
+    }
+
+    #[test]
+    fn test_guild_owner() {
+        let cache = InMemoryCache::new();
+        assert!(cache.guild_owner(GuildId(1)).is_none());
+
+        cache.update(&GuildCreate(test::guild(GuildId(1), UserId(2))));
+        assert!(cache.guild_owner(GuildId(1)).is_none());
+
+        cache.cache_member(GuildId(1), test::member(UserId(2), GuildId(1)));
+        cache.cache_member(GuildId(1), test::member(UserId(3), GuildId(1)));
+
+        let owner = cache.guild_owner(GuildId(1)).expect("owner is cached");
+        assert_eq!(UserId(2), owner.user_id);
+    }
+
+    #[test]
+    fn test_member_highest_role() {
+        let cache = InMemoryCache::new();
+        let role = |id, position| Role {
+            position,
+            ..test::role(RoleId(id))
+        };
+        cache.insert_role(GuildId(1), role(3, 1));
+        cache.insert_role(GuildId(1), role(4, 2));
+        cache.insert_role(GuildId(1), role(5, 2));
+
+        let mut member = test::member(UserId(2), GuildId(1));
+        member.roles = vec![RoleId(3), RoleId(5), RoleId(4)];
+        cache.insert_member(member);
+        assert_eq!(
+            Some((RoleId(4), 2)),
+            cache.member_highest_role(GuildId(1), UserId(2))
+        );
+
+        cache.insert_member(test::member(UserId(6), GuildId(1)));
+        assert!(cache.member_highest_role(GuildId(1), UserId(6)).is_none());
+        assert!(cache.member_highest_role(GuildId(1), UserId(7)).is_none());
+    }
+
+    #[test]
+    fn test_guild_roles_full() {
+        let cache = InMemoryCache::new();
+        let role = |id, position| Role {
+            position,
+            ..test::role(RoleId(id))
+        };
+        cache.insert_role(GuildId(1), role(3, 1));
+        cache.insert_role(GuildId(1), role(4, 3));
+        cache.insert_role(GuildId(1), role(5, 2));
+
+        let ids = cache
+            .guild_roles_full(GuildId(1))
+            .expect("guild roles are cached")
+            .into_iter()
+            .map(|role| role.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![RoleId(4), RoleId(5), RoleId(3)], ids);
+        assert!(cache.guild_roles_full(GuildId(2)).is_none());
+    }
+
+    #[test]
+    fn test_user_guilds() {
+        let cache = InMemoryCache::new();
+        cache.cache_member(GuildId(1), test::member(UserId(3), GuildId(1)));
+        cache.cache_member(GuildId(2), test::member(UserId(3), GuildId(2)));
+
+        assert_eq!(
+            Some(vec![GuildId(1), GuildId(2)]),
+            cache.user_guilds(UserId(3))
+        );
+        assert!(cache.user_guilds(UserId(4)).is_none());
+    }
+
     #[test]
     fn test_clear() {
         let cache = InMemoryCache::new();
@@ -0,0 +1,97 @@
+use std::collections::{HashMap, VecDeque};
+use twilight_model::id::UserId;
+
+/// Order in which the members of a guild have been accessed, used to evict the
+/// least recently accessed members when a member cache size is configured.
+///
+/// Members are accessed when they're inserted or updated and when they're
+/// read from the cache.
+///
+/// Each touch stamps the member and queues the stamp, leaving the member's
+/// previous entry in the queue. Entries whose stamp isn't the member's latest
+/// one are stale: they are skipped when popping and compacted away once they
+/// outnumber the live entries, so touching a member is amortized O(1).
+#[derive(Debug, Default)]
+pub(crate) struct MemberOrder {
+    next_stamp: u64,
+    queue: VecDeque<(u64, UserId)>,
+    stamps: HashMap<UserId, u64>,
+}
+
+impl MemberOrder {
+    /// Number of members being tracked.
+    pub(crate) fn len(&self) -> usize {
+        self.stamps.len()
+    }
+
+    /// Whether a member is being tracked.
+    pub(crate) fn contains(&self, user_id: UserId) -> bool {
+        self.stamps.contains_key(&user_id)
+    }
+
+    /// Mark a member as the most recently accessed one.
+    pub(crate) fn touch(&mut self, user_id: UserId) {
+        let stamp = self.next_stamp;
+        self.next_stamp += 1;
+
+        self.stamps.insert(user_id, stamp);
+        self.queue.push_back((stamp, user_id));
+
+        if self.queue.len() > self.stamps.len() * 2 {
+            let stamps = &self.stamps;
+            self.queue
+                .retain(|(stamp, user_id)| stamps.get(user_id) == Some(stamp));
+        }
+    }
+
+    /// Stop tracking a member.
+    pub(crate) fn remove(&mut self, user_id: UserId) {
+        self.stamps.remove(&user_id);
+    }
+
+    /// Remove and return the least recently accessed member.
+    pub(crate) fn pop_oldest(&mut self) -> Option<UserId> {
+        while let Some((stamp, user_id)) = self.queue.pop_front() {
+            if self.stamps.get(&user_id) == Some(&stamp) {
+                self.stamps.remove(&user_id);
+
+                return Some(user_id);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemberOrder;
+    use twilight_model::id::UserId;
+
+    #[test]
+    fn test_pop_oldest() {
+        let mut order = MemberOrder::default();
+        order.touch(UserId(1));
+        order.touch(UserId(2));
+        order.touch(UserId(3));
+        order.touch(UserId(1));
+        order.remove(UserId(2));
+
+        assert_eq!(2, order.len());
+        assert_eq!(Some(UserId(3)), order.pop_oldest());
+        assert_eq!(Some(UserId(1)), order.pop_oldest());
+        assert_eq!(None, order.pop_oldest());
+    }
+
+    #[test]
+    fn test_stale_entries_compacted() {
+        let mut order = MemberOrder::default();
+
+        for _ in 0..100 {
+            order.touch(UserId(1));
+        }
+
+        assert_eq!(1, order.len());
+        assert!(order.queue.len() <= 2);
+    }
+}
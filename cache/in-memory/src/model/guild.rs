@@ -1,10 +1,11 @@
 use serde::Serialize;
+use std::time::SystemTime;
 use twilight_model::{
     guild::{
         DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, NSFWLevel, Permissions,
         PremiumTier, SystemChannelFlags, VerificationLevel,
     },
-    id::{ApplicationId, ChannelId, GuildId, UserId},
+    id::{self, ApplicationId, ChannelId, GuildId, UserId},
 };
 
 /// Represents a cached [`Guild`].
@@ -103,3 +104,10 @@ pub struct CachedGuild {
     /// Whether the widget is enabled.
     pub widget_enabled: Option<bool>,
 }
+
+impl CachedGuild {
+    /// Time at which the guild was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        id::created_at(self.id.0)
+    }
+}
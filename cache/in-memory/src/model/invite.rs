@@ -0,0 +1,71 @@
+use serde::Serialize;
+use twilight_model::{
+    gateway::payload::InviteCreate,
+    id::{ChannelId, GuildId, UserId},
+    invite::TargetType,
+};
+
+/// Represents a cached [`InviteCreate`].
+///
+/// [`InviteCreate`]: twilight_model::gateway::payload::InviteCreate
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedInvite {
+    /// ID of the channel the invite is for.
+    pub channel_id: ChannelId,
+    /// Unique code of the invite.
+    pub code: String,
+    /// When the invite was created.
+    pub created_at: String,
+    /// ID of the guild the invite is for.
+    pub guild_id: GuildId,
+    /// ID of the user who created the invite.
+    pub inviter_id: Option<UserId>,
+    /// Number of seconds the invite is valid for.
+    pub max_age: u64,
+    /// Maximum number of times the invite can be used.
+    pub max_uses: u64,
+    /// Type of target for the invite.
+    pub target_user_type: Option<TargetType>,
+    /// Whether the invite only grants temporary membership.
+    pub temporary: bool,
+    /// Number of times the invite has been used.
+    pub uses: u64,
+}
+
+impl From<InviteCreate> for CachedInvite {
+    fn from(invite: InviteCreate) -> Self {
+        Self {
+            channel_id: invite.channel_id,
+            code: invite.code,
+            created_at: invite.created_at,
+            guild_id: invite.guild_id,
+            inviter_id: invite.inviter.map(|user| user.id),
+            max_age: invite.max_age,
+            max_uses: invite.max_uses,
+            target_user_type: invite.target_user_type,
+            temporary: invite.temporary,
+            uses: invite.uses.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedInvite;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(
+        CachedInvite: channel_id,
+        code,
+        created_at,
+        guild_id,
+        inviter_id,
+        max_age,
+        max_uses,
+        target_user_type,
+        temporary,
+        uses
+    );
+    assert_impl_all!(CachedInvite: Clone, Debug, Eq, PartialEq);
+}
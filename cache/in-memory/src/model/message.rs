@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::time::SystemTime;
 use twilight_model::{
     channel::{
         embed::Embed,
@@ -9,7 +10,7 @@ use twilight_model::{
         Attachment, ChannelMention,
     },
     guild::PartialMember,
-    id::{ChannelId, GuildId, MessageId, RoleId, UserId, WebhookId},
+    id::{self, ChannelId, GuildId, MessageId, RoleId, UserId, WebhookId},
 };
 
 /// Represents a cached [`Message`].
@@ -72,6 +73,13 @@ pub struct CachedMessage {
     pub webhook_id: Option<WebhookId>,
 }
 
+impl CachedMessage {
+    /// Time at which the message was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        id::created_at(self.id.0)
+    }
+}
+
 impl From<Message> for CachedMessage {
     fn from(msg: Message) -> Self {
         #[allow(deprecated)]
@@ -0,0 +1,26 @@
+use serde::Serialize;
+use twilight_model::{gateway::payload::VoiceServerUpdate, id::GuildId};
+
+/// Represents the latest cached [`VoiceServerUpdate`] of a guild.
+///
+/// [`VoiceServerUpdate`]: twilight_model::gateway::payload::VoiceServerUpdate
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedVoiceServer {
+    /// Host of the voice server.
+    ///
+    /// `None` if the voice server the guild was connected to went away and a
+    /// new one hasn't been allocated yet.
+    pub endpoint: Option<String>,
+    /// ID of the guild of the voice connection.
+    pub guild_id: GuildId,
+    /// Token of the voice connection.
+    pub token: String,
+}
+
+impl PartialEq<VoiceServerUpdate> for CachedVoiceServer {
+    fn eq(&self, other: &VoiceServerUpdate) -> bool {
+        self.endpoint == other.endpoint
+            && Some(self.guild_id) == other.guild_id
+            && self.token == other.token
+    }
+}
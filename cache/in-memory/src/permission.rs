@@ -229,6 +229,7 @@ enum MemberRolesErrorType {
 }
 
 /// Member's roles' permissions and the guild's `@everyone` role's permissions.
+#[derive(Clone, Debug)]
 struct MemberRoles {
     /// User's roles and their permissions.
     assigned: Vec<(RoleId, Permissions)>,
@@ -236,6 +237,37 @@ struct MemberRoles {
     everyone: Permissions,
 }
 
+/// Information about a member retrieved from the cache used to create a
+/// [`PermissionCalculator`].
+///
+/// Created via [`InMemoryCachePermissions::calculator_data`].
+#[derive(Clone, Debug)]
+pub struct CalculatorData {
+    /// ID of the guild.
+    guild_id: GuildId,
+    /// Member's roles' permissions and the `@everyone` role's permissions.
+    member_roles: MemberRoles,
+    /// ID of the owner of the guild.
+    owner_id: UserId,
+    /// ID of the user.
+    user_id: UserId,
+}
+
+impl CalculatorData {
+    /// Create a permission calculator for the member, configured with the
+    /// guild's owner.
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub fn calculator(&self) -> PermissionCalculator<'_> {
+        PermissionCalculator::new(
+            self.guild_id,
+            self.user_id,
+            self.member_roles.everyone,
+            self.member_roles.assigned.as_slice(),
+        )
+        .owner_id(self.owner_id)
+    }
+}
+
 /// Calculate the permissions of a member with information from the cache.
 #[derive(Clone, Debug)]
 pub struct InMemoryCachePermissions<'a>(&'a InMemoryCache);
@@ -256,6 +288,56 @@ impl<'a> InMemoryCachePermissions<'a> {
         self.0
     }
 
+    /// Retrieve the information required to create a [`PermissionCalculator`]
+    /// for a member.
+    ///
+    /// This can be used to calculate permissions in situations not covered by
+    /// [`in_channel`] and [`root`], such as with overwrites that aren't cached.
+    ///
+    /// The following [`ResourceType`]s must be enabled:
+    ///
+    /// - [`ResourceType::GUILD`]
+    /// - [`ResourceType::MEMBER`]
+    /// - [`ResourceType::ROLE`]
+    ///
+    /// Returns `None` if the guild, the member, or one of the member's roles is
+    /// not in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::id::{GuildId, UserId};
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// // later on...
+    ///
+    /// let data = cache.permissions().calculator_data(UserId(5), GuildId(4));
+    ///
+    /// if let Some(data) = data {
+    ///     println!("Permissions: {:?}", data.calculator().root());
+    /// }
+    /// ```
+    ///
+    /// [`ResourceType::GUILD`]: crate::ResourceType::GUILD
+    /// [`ResourceType::MEMBER`]: crate::ResourceType::MEMBER
+    /// [`ResourceType::ROLE`]: crate::ResourceType::ROLE
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`in_channel`]: Self::in_channel
+    /// [`root`]: Self::root
+    pub fn calculator_data(&self, user_id: UserId, guild_id: GuildId) -> Option<CalculatorData> {
+        let owner_id = (self.0).0.guilds.get(&guild_id)?.owner_id;
+        let member_roles = self.member_roles(user_id, guild_id).ok()?;
+
+        Some(CalculatorData {
+            guild_id,
+            member_roles,
+            owner_id,
+            user_id,
+        })
+    }
+
     /// Calculate the permissions of a member in a guild channel.
     ///
     /// Returns [`Permissions::all`] if the user is the owner of the guild.
@@ -470,7 +552,8 @@ impl<'a> InMemoryCachePermissions<'a> {
 #[cfg(test)]
 mod tests {
     use super::{
-        ChannelError, ChannelErrorType, InMemoryCachePermissions, RootError, RootErrorType,
+        CalculatorData, ChannelError, ChannelErrorType, InMemoryCachePermissions, RootError,
+        RootErrorType,
     };
     use crate::{test, InMemoryCache};
     use static_assertions::{assert_fields, assert_impl_all};
@@ -487,10 +570,12 @@ mod tests {
         },
         id::{ChannelId, GuildId, RoleId, UserId},
     };
+    use twilight_util::permission_calculator::PermissionCalculator;
 
     assert_fields!(ChannelErrorType::ChannelUnavailable: channel_id);
     assert_fields!(ChannelErrorType::MemberUnavailable: guild_id, user_id);
     assert_fields!(ChannelErrorType::RoleUnavailable: role_id);
+    assert_impl_all!(CalculatorData: Clone, Debug, Send, Sync);
     assert_impl_all!(ChannelErrorType: Debug, Send, Sync);
     assert_impl_all!(ChannelError: Debug, Send, Sync);
     assert_impl_all!(InMemoryCachePermissions<'_>: Clone, Debug, Send, Sync);
@@ -680,6 +765,53 @@ mod tests {
         Ok(())
     }
 
+    /// Test that a calculator created from cached data calculates the same
+    /// permissions as one assembled by hand.
+    #[test]
+    fn test_calculator_data() {
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+        assert!(permissions.calculator_data(USER_ID, GUILD_ID).is_none());
+
+        cache.update(&GuildCreate(base_guild()));
+        assert!(permissions.calculator_data(USER_ID, GUILD_ID).is_none());
+
+        cache.update(&MemberAdd(test::member(USER_ID, GUILD_ID)));
+        cache.update(&MemberUpdate {
+            guild_id: GUILD_ID,
+            deaf: None,
+            joined_at: "foo".to_owned(),
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::from([OTHER_ROLE_ID]),
+            user: test::user(USER_ID),
+        });
+        assert!(permissions.calculator_data(USER_ID, GUILD_ID).is_none());
+
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(OTHER_ROLE_ID, Permissions::BAN_MEMBERS),
+        ));
+
+        let data = permissions.calculator_data(USER_ID, GUILD_ID).unwrap();
+        let member_roles = [(OTHER_ROLE_ID, Permissions::BAN_MEMBERS)];
+        let expected = PermissionCalculator::new(
+            GUILD_ID,
+            USER_ID,
+            Permissions::CREATE_INVITE | Permissions::VIEW_AUDIT_LOG,
+            &member_roles,
+        )
+        .owner_id(OWNER_ID);
+
+        assert_eq!(expected.root(), data.calculator().root());
+        assert_eq!(
+            expected.in_channel(ChannelType::GuildText, &[]),
+            data.calculator().in_channel(ChannelType::GuildText, &[]),
+        );
+    }
+
     /// Test that the permissions interface returns the correct errors and
     /// permissions depending on what information is unavailable during
     /// [`in_channel`] operations.
@@ -732,6 +864,51 @@ mod tests {
         Ok(())
     }
 
+    /// Test that [`InMemoryCache::permissions_in`] calculates the same
+    /// permissions as a manual calculation, and returns `None` when
+    /// information is missing.
+    #[test]
+    fn test_permissions_in() {
+        let cache = InMemoryCache::new();
+        cache.update(&GuildCreate(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        assert!(cache
+            .permissions_in(GUILD_ID, USER_ID, CHANNEL_ID)
+            .is_none());
+
+        let other_role = role_with_permissions(
+            OTHER_ROLE_ID,
+            Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+        );
+        cache.update(&role_create(GUILD_ID, other_role.clone()));
+        cache.update(&MemberAdd({
+            let mut member = test::member(USER_ID, GUILD_ID);
+            member.roles.push(OTHER_ROLE_ID);
+
+            member
+        }));
+
+        let everyone = Permissions::CREATE_INVITE | Permissions::VIEW_AUDIT_LOG;
+        let assigned = [(OTHER_ROLE_ID, other_role.permissions)];
+        let overwrites = match channel() {
+            Channel::Guild(GuildChannel::Text(channel)) => channel.permission_overwrites,
+            _ => unreachable!(),
+        };
+        let expected = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &assigned)
+            .in_channel(ChannelType::GuildText, &overwrites);
+
+        assert_eq!(
+            Some(expected),
+            cache.permissions_in(GUILD_ID, USER_ID, CHANNEL_ID)
+        );
+        assert!(cache
+            .permissions_in(GuildId(GUILD_ID.0 + 1), USER_ID, CHANNEL_ID)
+            .is_none());
+        assert!(cache
+            .permissions_in(GUILD_ID, USER_ID, ChannelId(CHANNEL_ID.0 + 1))
+            .is_none());
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
@@ -2,6 +2,31 @@ use twilight_model::id::{ChannelId, GuildId};
 
 use super::InMemoryCache;
 
+/// Number of entities of the most common resources in the cache.
+///
+/// Created via [`InMemoryCacheStats::summary`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CacheStatsSummary {
+    /// Number of guild channels, private channels, and groups.
+    pub channels: usize,
+    /// Number of emojis.
+    pub emojis: usize,
+    /// Number of guilds.
+    pub guilds: usize,
+    /// Number of members.
+    pub members: usize,
+    /// Number of messages across all channels.
+    pub messages: usize,
+    /// Number of presences.
+    pub presences: usize,
+    /// Number of roles.
+    pub roles: usize,
+    /// Number of users.
+    pub users: usize,
+    /// Number of voice states.
+    pub voice_states: usize,
+}
+
 /// Retrieve statistics about the number of entities of each resource in the
 /// cache.
 #[derive(Clone, Debug)]
@@ -134,6 +159,28 @@ impl<'a> InMemoryCacheStats<'a> {
         self.0 .0.roles.len()
     }
 
+    /// Number of entities of the most common resources in the cache.
+    ///
+    /// This is an O(n) operation, where n is the number of channels with
+    /// cached messages.
+    pub fn summary(&self) -> CacheStatsSummary {
+        let cache = &self.0 .0;
+
+        CacheStatsSummary {
+            channels: cache.channels_guild.len()
+                + cache.channels_private.len()
+                + cache.groups.len(),
+            emojis: cache.emojis.len(),
+            guilds: cache.guilds.len(),
+            members: cache.members.len(),
+            messages: cache.messages.iter().map(|channel| channel.len()).sum(),
+            presences: cache.presences.len(),
+            roles: cache.roles.len(),
+            users: cache.users.len(),
+            voice_states: cache.voice_states.len(),
+        }
+    }
+
     /// Number of unavailable_guilds in the cache.
     pub fn unavailable_guilds(&self) -> usize {
         self.0 .0.unavailable_guilds.len()
@@ -152,9 +199,107 @@ impl<'a> InMemoryCacheStats<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::InMemoryCacheStats;
+    use super::{CacheStatsSummary, InMemoryCacheStats};
+    use crate::{test, InMemoryCache};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
-
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{
+        gateway::payload::VoiceStateUpdate,
+        id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    };
+
+    assert_impl_all!(
+        CacheStatsSummary: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
     assert_impl_all!(InMemoryCacheStats<'_>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_channel_messages() {
+        let cache = test::cache_with_message_and_reactions();
+        let stats = cache.stats();
+
+        assert_eq!(Some(1), stats.channel_messages(ChannelId(2)));
+        assert!(stats.channel_messages(ChannelId(3)).is_none());
+        assert!(cache.message(ChannelId(2), MessageId(4)).is_some());
+    }
+
+    #[test]
+    fn test_members() {
+        let cache = InMemoryCache::new();
+        cache.cache_members(
+            GuildId(1),
+            (1..=3).map(|id| test::member(UserId(id), GuildId(1))),
+        );
+        cache.cache_member(GuildId(2), test::member(UserId(1), GuildId(2)));
+        let stats = cache.stats();
+
+        assert_eq!(4, stats.members());
+        assert_eq!(Some(3), stats.guild_members(GuildId(1)));
+        assert_eq!(Some(1), stats.guild_members(GuildId(2)));
+        assert!(stats.guild_members(GuildId(3)).is_none());
+    }
+
+    #[test]
+    fn test_summary() {
+        let cache = test::cache_with_message_and_reactions();
+        let (guild_id, _, channel) = test::guild_channel_text();
+        cache.cache_guild_channels(guild_id, vec![channel]);
+        cache.cache_roles(guild_id, (1..=3).map(|id| test::role(RoleId(id))));
+        cache.cache_emoji(guild_id, test::emoji(EmojiId(1), None));
+        cache.update(&VoiceStateUpdate(test::voice_state(
+            guild_id,
+            Some(ChannelId(2)),
+            UserId(3),
+        )));
+
+        let summary = cache.stats().summary();
+
+        assert_eq!(
+            CacheStatsSummary {
+                channels: 1,
+                emojis: 1,
+                guilds: 0,
+                members: 1,
+                messages: 1,
+                presences: 0,
+                roles: 3,
+                users: 1,
+                voice_states: 1,
+            },
+            summary
+        );
+    }
+
+    #[test]
+    fn test_voice_states() {
+        let cache = InMemoryCache::new();
+
+        for id in 1..=3 {
+            cache.update(&VoiceStateUpdate(test::voice_state(
+                GuildId(1),
+                Some(ChannelId(2)),
+                UserId(id),
+            )));
+        }
+
+        cache.update(&VoiceStateUpdate(test::voice_state(
+            GuildId(1),
+            Some(ChannelId(3)),
+            UserId(4),
+        )));
+        let stats = cache.stats();
+
+        assert_eq!(4, stats.voice_states());
+        assert_eq!(Some(4), stats.guild_voice_states(GuildId(1)));
+        assert_eq!(Some(3), stats.channel_voice_states(ChannelId(2)));
+        assert_eq!(Some(1), stats.channel_voice_states(ChannelId(3)));
+    }
 }
@@ -5,7 +5,11 @@ use twilight_model::{
         ChannelType, GuildChannel, Reaction, ReactionType, TextChannel,
     },
     gateway::payload::{MessageCreate, ReactionAdd},
-    guild::{Emoji, Member, PartialMember, Permissions, Role},
+    guild::{
+        DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
+        NSFWLevel, PartialMember, Permissions, PremiumTier, Role, SystemChannelFlags,
+        VerificationLevel,
+    },
     id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
     user::{CurrentUser, User},
     voice::VoiceState,
@@ -176,6 +180,55 @@ pub fn emoji(id: EmojiId, user: Option<User>) -> Emoji {
     }
 }
 
+pub fn guild(id: GuildId, owner_id: UserId) -> Guild {
+    Guild {
+        afk_channel_id: None,
+        afk_timeout: 300,
+        application_id: None,
+        approximate_member_count: None,
+        approximate_presence_count: None,
+        banner: None,
+        channels: Vec::new(),
+        default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+        description: None,
+        discovery_splash: None,
+        emojis: Vec::new(),
+        explicit_content_filter: ExplicitContentFilter::AllMembers,
+        features: Vec::new(),
+        icon: None,
+        id,
+        joined_at: None,
+        large: false,
+        max_members: None,
+        max_presences: None,
+        max_video_channel_users: None,
+        member_count: None,
+        members: Vec::new(),
+        mfa_level: MfaLevel::None,
+        name: "test".to_owned(),
+        nsfw_level: NSFWLevel::Default,
+        owner: None,
+        owner_id,
+        permissions: None,
+        preferred_locale: "en-US".to_owned(),
+        premium_subscription_count: None,
+        premium_tier: PremiumTier::None,
+        presences: Vec::new(),
+        roles: Vec::new(),
+        rules_channel_id: None,
+        splash: None,
+        stage_instances: Vec::new(),
+        system_channel_flags: SystemChannelFlags::empty(),
+        system_channel_id: None,
+        unavailable: false,
+        vanity_url_code: None,
+        verification_level: VerificationLevel::None,
+        voice_states: Vec::new(),
+        widget_channel_id: None,
+        widget_enabled: None,
+    }
+}
+
 pub fn guild_channel_text() -> (GuildId, ChannelId, GuildChannel) {
     let guild_id = GuildId(1);
     let channel_id = ChannelId(2);
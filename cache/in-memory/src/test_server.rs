@@ -0,0 +1,108 @@
+//! Mock HTTP server for the caching client's tests.
+//!
+//! Tests point an HTTP client at the server with `ClientBuilder::proxy`, and
+//! the server answers every request with the same response.
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// Build a response with a status line, such as `200 OK`, and a JSON body.
+pub fn json_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body,
+    )
+}
+
+/// Mock HTTP server counting the requests it receives.
+pub struct TestServer {
+    addr: SocketAddr,
+    received: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+    /// Start a server answering every request with the same response.
+    pub async fn respond(response: impl Into<String>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let received = Arc::new(AtomicUsize::new(0));
+        let response = Arc::new(response.into());
+
+        {
+            let received = Arc::clone(&received);
+
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(handle(stream, Arc::clone(&response), Arc::clone(&received)));
+                }
+            });
+        }
+
+        Self { addr, received }
+    }
+
+    /// Address to proxy requests to.
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Number of requests received.
+    pub fn received(&self) -> usize {
+        self.received.load(Ordering::SeqCst)
+    }
+}
+
+async fn handle(mut stream: TcpStream, response: Arc<String>, received: Arc<AtomicUsize>) {
+    read_request(&mut stream).await;
+    received.fetch_add(1, Ordering::SeqCst);
+
+    let _res = stream.write_all(response.as_bytes()).await;
+}
+
+/// Read a request, including its body if it has a content length.
+async fn read_request(stream: &mut TcpStream) {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    loop {
+        let len = stream.read(&mut buf).await.unwrap();
+
+        if len == 0 {
+            break;
+        }
+
+        request.extend_from_slice(&buf[..len]);
+
+        if let Some(headers_len) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            let headers = String::from_utf8_lossy(&request[..headers_len]);
+            let content_length = headers
+                .lines()
+                .find_map(|line| {
+                    let mut parts = line.splitn(2, ':');
+                    let name = parts.next()?;
+
+                    if !name.eq_ignore_ascii_case("content-length") {
+                        return None;
+                    }
+
+                    parts.next()?.trim().parse::<usize>().ok()
+                })
+                .unwrap_or(0);
+
+            if request.len() >= headers_len + 4 + content_length {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use serde::de::DeserializeSeed;
+use serde_json::Deserializer;
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_model::{
+    gateway::event::{Event, GatewayEventDeserializer},
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+
+/// Gateway events recorded from a session, in the order they were received.
+///
+/// The session consists of the guild becoming available, three messages being
+/// sent, one of the messages being partially edited, and a member's nickname
+/// being changed.
+const SESSION: &[&str] = &[
+    r#"{
+        "op": 0,
+        "s": 1,
+        "t": "READY",
+        "d": {
+            "application": { "flags": 0, "id": "100" },
+            "guilds": [{ "id": "1", "unavailable": true }],
+            "session_id": "session",
+            "shard": [0, 1],
+            "user": {
+                "avatar": null,
+                "bot": true,
+                "discriminator": "0001",
+                "id": "100",
+                "mfa_enabled": false,
+                "username": "bot",
+                "verified": true
+            },
+            "v": 8
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 2,
+        "t": "GUILD_CREATE",
+        "d": {
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "banner": null,
+            "channels": [{
+                "id": "2",
+                "last_message_id": null,
+                "name": "general",
+                "nsfw": false,
+                "parent_id": null,
+                "permission_overwrites": [],
+                "position": 0,
+                "rate_limit_per_user": 0,
+                "topic": null,
+                "type": 0
+            }],
+            "default_message_notifications": 1,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "id": "1",
+            "joined_at": "2021-01-01T00:00:00.000000+00:00",
+            "large": false,
+            "member_count": 2,
+            "members": [
+                {
+                    "deaf": false,
+                    "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "roles": [],
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0002",
+                        "id": "10",
+                        "username": "owner"
+                    }
+                },
+                {
+                    "deaf": false,
+                    "joined_at": "2021-01-02T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": "old nick",
+                    "roles": [],
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0003",
+                        "id": "11",
+                        "username": "member"
+                    }
+                }
+            ],
+            "mfa_level": 0,
+            "name": "guild",
+            "nsfw_level": 0,
+            "owner_id": "10",
+            "preferred_locale": "en-US",
+            "premium_subscription_count": 0,
+            "premium_tier": 0,
+            "presences": [],
+            "roles": [{
+                "color": 0,
+                "hoist": false,
+                "id": "1",
+                "managed": false,
+                "mentionable": false,
+                "name": "@everyone",
+                "permissions": "104324673",
+                "position": 0
+            }],
+            "rules_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "unavailable": false,
+            "vanity_url_code": null,
+            "verification_level": 0,
+            "voice_states": []
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 3,
+        "t": "MESSAGE_CREATE",
+        "d": {
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0002",
+                "id": "10",
+                "username": "owner"
+            },
+            "channel_id": "2",
+            "content": "first",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "20",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-03T00:00:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 4,
+        "t": "MESSAGE_CREATE",
+        "d": {
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0003",
+                "id": "11",
+                "username": "member"
+            },
+            "channel_id": "2",
+            "content": "second",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "21",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-03T00:01:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 5,
+        "t": "MESSAGE_CREATE",
+        "d": {
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0002",
+                "id": "10",
+                "username": "owner"
+            },
+            "channel_id": "2",
+            "content": "third",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "22",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-03T00:02:00.000000+00:00",
+            "tts": false,
+            "type": 0
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 6,
+        "t": "MESSAGE_UPDATE",
+        "d": {
+            "channel_id": "2",
+            "content": "second, edited",
+            "edited_timestamp": "2021-01-03T00:03:00.000000+00:00",
+            "guild_id": "1",
+            "id": "21"
+        }
+    }"#,
+    r#"{
+        "op": 0,
+        "s": 7,
+        "t": "GUILD_MEMBER_UPDATE",
+        "d": {
+            "guild_id": "1",
+            "joined_at": "2021-01-02T00:00:00.000000+00:00",
+            "nick": "new nick",
+            "pending": false,
+            "premium_since": null,
+            "roles": [],
+            "user": {
+                "avatar": null,
+                "discriminator": "0003",
+                "id": "11",
+                "username": "member"
+            }
+        }
+    }"#,
+];
+
+fn replay(cache: &InMemoryCache, session: &[&str]) {
+    for json in session {
+        let deserializer = GatewayEventDeserializer::from_json(json).expect("event is valid");
+        let mut json_deserializer = Deserializer::from_str(json);
+        let event = deserializer
+            .deserialize(&mut json_deserializer)
+            .expect("event deserializes");
+
+        cache.update(&Event::from(event));
+    }
+}
+
+#[test]
+fn test_recorded_session() {
+    let cache = InMemoryCache::new();
+    replay(&cache, SESSION);
+
+    let stats = cache.stats();
+    assert_eq!(1, stats.guilds());
+    assert_eq!(0, stats.unavailable_guilds());
+    assert_eq!(Some(2), stats.guild_members(GuildId(1)));
+    assert_eq!(Some(1), stats.guild_channels(GuildId(1)));
+    assert_eq!(
+        UserId(100),
+        cache.current_user().expect("ready is cached").id
+    );
+
+    assert_eq!(Some(3), stats.channel_messages(ChannelId(2)));
+    let latest = cache
+        .message(ChannelId(2), MessageId(22))
+        .expect("latest message is cached");
+    assert_eq!("third", latest.content);
+    assert_eq!(UserId(10), latest.author);
+
+    // The partial message update only changes the fields it contains.
+    let edited = cache
+        .message(ChannelId(2), MessageId(21))
+        .expect("edited message is cached");
+    assert_eq!("second, edited", edited.content);
+    assert_eq!(UserId(11), edited.author);
+    assert!(edited.edited_timestamp.is_some());
+
+    let member = cache
+        .member(GuildId(1), UserId(11))
+        .expect("member is cached");
+    assert_eq!(Some("new nick"), member.nick.as_deref());
+    assert_eq!(UserId(10), cache.guild(GuildId(1)).unwrap().owner_id);
+}
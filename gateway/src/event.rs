@@ -41,6 +41,12 @@ bitflags! {
         const GUILD_EMOJIS_UPDATE = 1 << 12;
         /// A guild's integrations have been updated.
         const GUILD_INTEGRATIONS_UPDATE = 1 << 13;
+        /// A guild scheduled event has been created.
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 50;
+        /// A guild scheduled event has been deleted.
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 51;
+        /// A guild scheduled event has been updated.
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 52;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
         /// A guild integration was created.
@@ -119,6 +125,15 @@ bitflags! {
         const STAGE_INSTANCE_DELETE = 1 << 58;
         /// Stage instance was updated in a stage channel.
         const STAGE_INSTANCE_UPDATE = 1 << 59;
+        /// Thread has been created or the current user was added to a private
+        /// thread.
+        const THREAD_CREATE = 1 << 53;
+        /// Thread has been deleted.
+        const THREAD_DELETE = 1 << 54;
+        /// Active threads in channels the current user gained access to.
+        const THREAD_LIST_SYNC = 1 << 55;
+        /// Thread has been updated.
+        const THREAD_UPDATE = 1 << 63;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -153,6 +168,9 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
             EventType::GuildIntegrationsUpdate => EventTypeFlags::GUILD_INTEGRATIONS_UPDATE,
+            EventType::GuildScheduledEventCreate => EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE,
+            EventType::GuildScheduledEventDelete => EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE,
+            EventType::GuildScheduledEventUpdate => EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE,
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
             EventType::IntegrationCreate => EventTypeFlags::INTEGRATION_CREATE,
             EventType::IntegrationDelete => EventTypeFlags::INTEGRATION_DELETE,
@@ -189,6 +207,10 @@ impl From<EventType> for EventTypeFlags {
             EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
             EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
             EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
@@ -2,7 +2,10 @@ use serde::{
     de::{Error as DeError, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -663,9 +666,29 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: ErrorCode,
+    /// Errors of individual fields of the request body, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<ApiFieldErrors>,
     pub message: String,
 }
 
+impl GeneralApiError {
+    /// Errors of individual fields of the request body, along with the path
+    /// of the field they're for.
+    ///
+    /// Paths are the field names joined by periods, such as `embed.title` or
+    /// `components.0.type`. The order of the fields is not guaranteed.
+    pub fn field_errors(&self) -> Vec<(String, &ApiFieldError)> {
+        let mut errors = Vec::new();
+
+        if let Some(fields) = &self.errors {
+            fields.collect("", &mut errors);
+        }
+
+        errors
+    }
+}
+
 impl Display for GeneralApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("Error code ")?;
@@ -676,6 +699,44 @@ impl Display for GeneralApiError {
     }
 }
 
+/// Errors of a field in a request body and of the fields nested within it.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApiFieldErrors {
+    /// Errors of the field itself.
+    #[serde(default, rename = "_errors", skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiFieldError>,
+    /// Errors of the fields nested within the field, by name or index.
+    #[serde(flatten)]
+    pub fields: HashMap<String, ApiFieldErrors>,
+}
+
+impl ApiFieldErrors {
+    fn collect<'a>(&'a self, path: &str, errors: &mut Vec<(String, &'a ApiFieldError)>) {
+        for error in &self.errors {
+            errors.push((path.to_owned(), error));
+        }
+
+        for (name, field) in &self.fields {
+            let path = if path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", path, name)
+            };
+
+            field.collect(&path, errors);
+        }
+    }
+}
+
+/// Error of a field in a request body.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ApiFieldError {
+    /// Machine-readable code of the error, such as `BASE_TYPE_REQUIRED`.
+    pub code: String,
+    /// Human readable message of the error.
+    pub message: String,
+}
+
 /// Sending a message failed because the provided fields contained invalid
 /// input.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -768,8 +829,8 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, ErrorCode, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
-        RatelimitedApiError,
+        ApiError, ApiFieldError, ErrorCode, GeneralApiError, MessageApiError,
+        MessageApiErrorEmbedField, RatelimitedApiError,
     };
     use serde_test::Token;
     use static_assertions::assert_impl_all;
@@ -798,6 +859,7 @@ mod tests {
     fn test_api_error_deser() {
         let expected = GeneralApiError {
             code: ErrorCode::UnknownAccount,
+            errors: None,
             message: "Unknown account".to_owned(),
         };
 
@@ -817,6 +879,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_field_errors() {
+        let input = br#"{
+            "code": 50035,
+            "errors": {
+                "embed": {
+                    "title": {
+                        "_errors": [
+                            {
+                                "code": "BASE_TYPE_MAX_LENGTH",
+                                "message": "Must be 256 or fewer in length."
+                            }
+                        ]
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_slice::<ApiError>(input).unwrap();
+        let general = match error {
+            ApiError::General(general) => general,
+            other => panic!("expected general error, got {:?}", other),
+        };
+
+        assert_eq!(ErrorCode::InvalidFormBodyOrContentType, general.code);
+        assert_eq!("Invalid Form Body", general.message);
+        assert_eq!(
+            vec![(
+                "embed.title".to_owned(),
+                &ApiFieldError {
+                    code: "BASE_TYPE_MAX_LENGTH".to_owned(),
+                    message: "Must be 256 or fewer in length.".to_owned(),
+                },
+            )],
+            general.field_errors(),
+        );
+    }
+
     #[test]
     fn test_api_error_message() {
         let expected = ApiError::Message(MessageApiError {
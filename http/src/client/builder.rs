@@ -1,26 +1,95 @@
-use super::{Client, State};
-use crate::ratelimiting::Ratelimiter;
-use hyper::header::HeaderMap;
+use super::{
+    interceptor::RequestInterceptor, response_cache::ResponseCache, retry::RetryPolicy, Client,
+    State,
+};
+use crate::{
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    request::Request,
+};
+use hyper::header::{HeaderMap, HeaderValue};
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
-        Arc,
+        Arc, RwLock,
     },
     time::Duration,
 };
 use twilight_model::{channel::message::allowed_mentions::AllowedMentions, id::ApplicationId};
 
+/// Configuring a [`ClientBuilder`] failed.
+#[derive(Debug)]
+pub struct ClientBuilderError {
+    kind: ClientBuilderErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClientBuilderError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClientBuilderErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ClientBuilderErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ClientBuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ClientBuilderErrorType::UserAgentInvalid { .. } => {
+                f.write_str("user agent is not a valid header value")
+            }
+        }
+    }
+}
+
+impl Error for ClientBuilderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ClientBuilderError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClientBuilderErrorType {
+    /// User agent contains characters not allowed in a header value.
+    UserAgentInvalid {
+        /// Provided user agent.
+        user_agent: String,
+    },
+}
+
 #[derive(Debug)]
 /// A builder for [`Client`].
 pub struct ClientBuilder {
     pub(crate) application_id: AtomicU64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Arc<dyn Ratelimiter>>,
+    pub(crate) request_interceptor: Option<RequestInterceptor>,
+    pub(crate) response_cache: Option<ResponseCache>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) send_token: bool,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
-    pub(crate) token: Option<Box<str>>,
+    pub(crate) token: Option<Arc<str>>,
     pub(crate) use_http: bool,
+    pub(crate) user_agent: Option<HeaderValue>,
 }
 
 impl ClientBuilder {
@@ -50,12 +119,17 @@ impl ClientBuilder {
                 default_headers: self.default_headers,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
+                request_interceptor: self.request_interceptor,
+                response_cache: self.response_cache,
+                retry_policy: self.retry_policy,
+                send_token: self.send_token,
                 timeout: self.timeout,
                 token_invalid: AtomicBool::new(false),
-                token: self.token,
+                token: RwLock::new(self.token),
                 application_id: self.application_id,
                 default_allowed_mentions: self.default_allowed_mentions,
                 use_http: self.use_http,
+                user_agent: self.user_agent,
             }),
         }
     }
@@ -105,13 +179,155 @@ impl ClientBuilder {
 
     /// Set a ratelimiter to use.
     ///
+    /// A custom [`Ratelimiter`] implementation can be provided, for example to
+    /// share ratelimits between multiple processes.
+    ///
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// If this method is not called at all then an [`InMemoryRatelimiter`]
+    /// will be created by [`ClientBuilder::build`].
+    pub fn ratelimiter(mut self, ratelimiter: Option<Arc<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter;
+
+        self
+    }
+
+    /// Set a callback to observe every request before it's sent.
+    ///
+    /// The callback is called once per request, right before the HTTP request
+    /// is built, and receives the request's route, method, and body. It can be
+    /// used for purposes like debugging and collecting metrics. The callback
+    /// can't modify the request and doesn't affect ratelimiting.
+    ///
+    /// # Examples
+    ///
+    /// Print the method and path of every request:
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .request_interceptor(|request| {
+    ///         println!("{:?} {}", request.method, request.path_str);
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn request_interceptor(
+        mut self,
+        interceptor: impl Fn(&Request) + Send + Sync + 'static,
+    ) -> Self {
+        self.request_interceptor
+            .replace(RequestInterceptor::new(interceptor));
+
+        self
+    }
+
+    /// Cache the responses to `GET` requests for a duration.
+    ///
+    /// Successful responses are cached by the path of the request. Repeated
+    /// requests to the same path within the duration are responded to from
+    /// the cache without sending a request or waiting for a ratelimit ticket.
+    /// Requests with other methods are never cached, and remove the cached
+    /// responses of their top-level resource, such as every cached response
+    /// under `channels/1` when a message in channel 1 is created. At most 1000
+    /// responses are cached, and the cache is cleared when the token is
+    /// replaced with [`Client::set_token`].
+    ///
+    /// The default is to not cache responses.
+    ///
+    /// # Examples
+    ///
+    /// Cache responses for 30 seconds:
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .response_cache(Duration::from_secs(30))
+    ///     .build();
+    /// ```
+    pub fn response_cache(mut self, ttl: Duration) -> Self {
+        self.response_cache.replace(ResponseCache::new(ttl));
+
+        self
+    }
+
+    /// Set the maximum number of times to retry a request when the API
+    /// responds with a server error.
+    ///
+    /// Requests resulting in a 500, 502, 503, or 504 response are retried with
+    /// an exponential backoff, starting at half a second. Every retry waits for
+    /// a new ratelimit ticket. Only requests with idempotent methods (`DELETE`,
+    /// `GET`, and `PUT`) are retried unless [`retry_non_idempotent`] is
+    /// enabled.
+    ///
+    /// The default is 0, which disables retries.
+    ///
+    /// # Examples
+    ///
+    /// Retry requests up to 3 times:
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder().retry_5xx(3).build();
+    /// ```
+    ///
+    /// [`retry_non_idempotent`]: Self::retry_non_idempotent
+    pub const fn retry_5xx(mut self, max_retries: u8) -> Self {
+        self.retry_policy.max_retries = max_retries;
+
+        self
+    }
+
+    /// Set whether to also retry requests with non-idempotent methods
+    /// (`PATCH` and `POST`) on server errors.
+    ///
+    /// Retrying these may cause an action to be performed multiple times, such
+    /// as a message being sent twice, if the API performed the action but
+    /// still responded with an error.
+    ///
+    /// The default is false. Has no effect unless [`retry_5xx`] is set.
+    ///
+    /// [`retry_5xx`]: Self::retry_5xx
+    pub const fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_policy.non_idempotent = retry_non_idempotent;
+
+        self
+    }
+
+    /// Set whether to retry a request once after it hit the global ratelimit.
+    ///
+    /// When the API responds with a 429 whose `X-RateLimit-Global` header is
+    /// set, the request is retried after waiting for the duration of its
+    /// `Retry-After` header, as long as that doesn't exceed the request's
+    /// timeout. Per-route 429s aren't retried since they're handled by the
+    /// ratelimiter. Has no effect if the [`ratelimiter`] is disabled.
+    ///
+    /// The default is true.
+    ///
+    /// [`ratelimiter`]: Self::ratelimiter
+    pub const fn retry_global_ratelimit(mut self, retry_global_ratelimit: bool) -> Self {
+        self.retry_policy.global_ratelimit = retry_global_ratelimit;
+
+        self
+    }
+
+    /// Set whether to send the token in the `Authorization` header of
+    /// requests.
+    ///
+    /// Disabling this is useful when requests are routed through a
+    /// [`proxy`] that adds the token itself, such as [twilight's HTTP proxy
+    /// server], so that the token doesn't need to be shared with the client.
+    ///
+    /// The default is true.
+    ///
+    /// [`proxy`]: Self::proxy
+    /// [twilight's HTTP proxy server]: https://github.com/twilight-rs/http-proxy
+    pub const fn send_token(mut self, send_token: bool) -> Self {
+        self.send_token = send_token;
 
         self
     }
@@ -134,20 +350,47 @@ impl ClientBuilder {
 
     /// Set the token to use for HTTP requests.
     pub fn token(mut self, token: impl Into<String>) -> Self {
-        let mut token = token.into();
+        self.token.replace(prefix_token(token.into()));
 
-        let is_bot = token.starts_with("Bot ");
-        let is_bearer = token.starts_with("Bearer ");
+        self
+    }
 
-        // Make sure it is either a bot or bearer token, and assume it's a bot
-        // token if no prefix is given
-        if !is_bot && !is_bearer {
-            token.insert_str(0, "Bot ");
-        }
+    /// Set the `User-Agent` header sent with every request.
+    ///
+    /// Discord recommends identifying your bot in the user agent. The default
+    /// identifies the request as sent by this library.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder()
+    ///     .user_agent("DiscordBot (https://example.com, 1.0.0) ExampleBot")?
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientBuilderErrorType::UserAgentInvalid`] error type if
+    /// the user agent isn't a valid header value, such as if it contains a
+    /// newline.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Result<Self, ClientBuilderError> {
+        let user_agent = user_agent.into();
 
-        self.token.replace(token.into_boxed_str());
+        match HeaderValue::from_str(&user_agent) {
+            Ok(value) => {
+                self.user_agent.replace(value);
 
-        self
+                Ok(self)
+            }
+            Err(source) => Err(ClientBuilderError {
+                kind: ClientBuilderErrorType::UserAgentInvalid { user_agent },
+                source: Some(Box::new(source)),
+            }),
+        }
     }
 }
 
@@ -158,10 +401,30 @@ impl Default for ClientBuilder {
             default_allowed_mentions: None,
             default_headers: None,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Arc::new(InMemoryRatelimiter::new())),
+            request_interceptor: None,
+            response_cache: None,
+            retry_policy: RetryPolicy::default(),
+            send_token: true,
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
+            user_agent: None,
         }
     }
 }
+
+/// Prefix a token with `Bot ` unless it's already prefixed as a bot or bearer
+/// token.
+pub(super) fn prefix_token(mut token: String) -> Arc<str> {
+    let is_bot = token.starts_with("Bot ");
+    let is_bearer = token.starts_with("Bearer ");
+
+    // Make sure it is either a bot or bearer token, and assume it's a bot
+    // token if no prefix is given
+    if !is_bot && !is_bearer {
+        token.insert_str(0, "Bot ");
+    }
+
+    token.into()
+}
@@ -0,0 +1,70 @@
+use crate::request::Request;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+/// Callback called with every request before it's sent.
+#[derive(Clone)]
+pub(crate) struct RequestInterceptor(Arc<dyn Fn(&Request) + Send + Sync>);
+
+impl RequestInterceptor {
+    pub(crate) fn new(interceptor: impl Fn(&Request) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(interceptor))
+    }
+
+    pub(crate) fn call(&self, request: &Request) {
+        (self.0)(request);
+    }
+}
+
+impl Debug for RequestInterceptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RequestInterceptor").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestInterceptor;
+    use crate::{
+        request::Method,
+        test_server::{TestServer, NO_CONTENT},
+        Client,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(RequestInterceptor: Clone, Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_called_once_per_request() {
+        let server = TestServer::respond(NO_CONTENT).await;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let client = {
+            let requests = Arc::clone(&requests);
+
+            Client::builder()
+                .proxy(server.addr().to_string(), true)
+                .ratelimiter(None)
+                .request_interceptor(move |request| {
+                    requests
+                        .lock()
+                        .unwrap()
+                        .push((request.method, request.path_str.to_string()));
+                })
+                .build()
+        };
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+
+        assert_eq!(
+            [(Method::Post, "channels/1/typing".to_owned())],
+            requests.lock().unwrap().as_slice(),
+        );
+    }
+}
@@ -1,28 +1,39 @@
 mod builder;
+mod interceptor;
+mod response_cache;
+mod retry;
 
-pub use self::builder::ClientBuilder;
+pub use self::builder::{ClientBuilder, ClientBuilderError, ClientBuilderErrorType};
 
+use self::{interceptor::RequestInterceptor, response_cache::ResponseCache, retry::RetryPolicy};
 use crate::{
     api_error::ApiError,
     error::{Error, ErrorType},
     ratelimiting::{RatelimitHeaders, Ratelimiter},
     request::{
         application::{
-            CreateFollowupMessage, CreateGlobalCommand, CreateGuildCommand, DeleteFollowupMessage,
-            DeleteGlobalCommand, DeleteGuildCommand, DeleteOriginalResponse, GetCommandPermissions,
-            GetGlobalCommands, GetGuildCommandPermissions, GetGuildCommands, GetOriginalResponse,
-            InteractionCallback, InteractionError, InteractionErrorType, SetCommandPermissions,
-            SetGlobalCommands, SetGuildCommands, UpdateCommandPermissions, UpdateFollowupMessage,
-            UpdateGlobalCommand, UpdateGuildCommand, UpdateOriginalResponse,
+            CreateFollowupMessage, CreateGlobalCommand, CreateGuildCommand, CreateTestEntitlement,
+            DeleteFollowupMessage, DeleteGlobalCommand, DeleteGuildCommand, DeleteOriginalResponse,
+            DeleteTestEntitlement, GetCommandPermissions, GetEntitlements, GetGlobalCommands,
+            GetGuildCommandPermissions, GetGuildCommands, GetOriginalResponse, InteractionCallback,
+            InteractionError, InteractionErrorType, SetCommandPermissions, SetGlobalCommands,
+            SetGuildCommands, TestEntitlementOwner, UpdateCommandPermissions,
+            UpdateFollowupMessage, UpdateGlobalCommand, UpdateGuildCommand, UpdateOriginalResponse,
+        },
+        channel::{
+            add_group_recipient::AddGroupRecipientError,
+            stage::create_stage_instance::CreateStageInstanceError,
         },
-        channel::stage::create_stage_instance::CreateStageInstanceError,
         guild::{
-            create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError,
-            update_guild_channel_positions::Position,
+            create_guild::CreateGuildError,
+            create_guild_channel::CreateGuildChannelError,
+            role::update_role_positions::{RolePosition, UpdateRolePositionsError},
+            update_guild_channel_positions::{Position, UpdateGuildChannelPositionsError},
         },
         prelude::*,
         GetUserApplicationInfo, Method, Request,
     },
+    routing::Path,
     API_VERSION,
 };
 use hyper::body::Bytes;
@@ -34,26 +45,29 @@ use hyper::{
 };
 use serde::de::DeserializeOwned;
 use std::{
+    borrow::Cow,
     convert::TryFrom,
     fmt::{Debug, Formatter, Result as FmtResult},
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
-        Arc,
+        Arc, PoisonError, RwLock,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::time;
 use twilight_model::{
     application::{
         callback::InteractionResponse,
         command::{permissions::CommandPermissions, Command},
+        role_connection::ApplicationRoleConnection,
     },
     channel::message::allowed_mentions::AllowedMentions,
     guild::Permissions,
     id::{
-        ApplicationId, ChannelId, CommandId, EmojiId, GuildId, IntegrationId, InteractionId,
-        MessageId, RoleId, UserId, WebhookId,
+        ApplicationId, ChannelId, CommandId, EmojiId, EntitlementId, GuildId, IntegrationId,
+        InteractionId, MessageId, RoleId, UserId, WebhookId,
     },
+    oauth::id::SkuId,
 };
 
 #[cfg(feature = "hyper-rustls")]
@@ -65,11 +79,16 @@ struct State {
     http: HyperClient<HttpsConnector<HttpConnector>, Body>,
     default_headers: Option<HeaderMap>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
+    request_interceptor: Option<RequestInterceptor>,
+    response_cache: Option<ResponseCache>,
+    retry_policy: RetryPolicy,
+    send_token: bool,
     timeout: Duration,
     token_invalid: AtomicBool,
-    token: Option<Box<str>>,
+    token: RwLock<Option<Arc<str>>>,
     use_http: bool,
+    user_agent: Option<HeaderValue>,
     pub(crate) application_id: AtomicU64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
 }
@@ -81,12 +100,29 @@ impl Debug for State {
             .field("default_headers", &self.default_headers)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("request_interceptor", &self.request_interceptor)
+            .field("response_cache", &self.response_cache)
+            .field("retry_policy", &self.retry_policy)
             .field("token", &self.token)
             .field("use_http", &self.use_http)
             .finish()
     }
 }
 
+/// Request whose headers and body have been built.
+///
+/// The body is reference counted, so the request can be cheaply cloned to be
+/// sent again when it's retried.
+#[derive(Clone, Debug)]
+struct PreparedRequest {
+    body: Option<Bytes>,
+    bucket: Path,
+    headers: HeaderMap,
+    method: Method,
+    path: Cow<'static, str>,
+    timeout: Option<Duration>,
+}
+
 /// Twilight's http client.
 ///
 /// Almost all of the client methods require authentication, and as such, the client must be
@@ -171,12 +207,57 @@ impl Client {
         ClientBuilder::new()
     }
 
-    /// Retrieve an immutable reference to the token used by the client.
+    /// Retrieve the token used by the client.
     ///
     /// If the initial token provided is not prefixed with `Bot `, it will be, and this method
     /// reflects that.
-    pub fn token(&self) -> Option<&str> {
-        self.state.token.as_deref()
+    ///
+    /// The token is reference counted, so this doesn't copy it.
+    pub fn token(&self) -> Option<Arc<str>> {
+        self.state
+            .token
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replace the token used by the client, such as after rotating it.
+    ///
+    /// This also clears the token's invalid status, so that requests are sent
+    /// again, and the cached responses, if any. The client's connection pool
+    /// and ratelimiter are kept.
+    ///
+    /// Like [`ClientBuilder::token`], the token is prefixed with `Bot ` if it
+    /// isn't prefixed as a bot or bearer token.
+    ///
+    /// Refer to [`is_token_invalid`] for more information on when a token is
+    /// marked as invalid.
+    ///
+    /// [`is_token_invalid`]: Self::is_token_invalid
+    pub fn set_token(&self, token: impl Into<String>) {
+        let token = builder::prefix_token(token.into());
+
+        self.state
+            .token
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(token);
+        self.state.token_invalid.store(false, Ordering::Relaxed);
+
+        if let Some(response_cache) = self.state.response_cache.as_ref() {
+            response_cache.clear();
+        }
+    }
+
+    /// Whether the configured token has been marked as invalid.
+    ///
+    /// The token is marked as invalid once a request receives a
+    /// 401 Unauthorized response, after which every request fails with an
+    /// [`ErrorType::Unauthorized`] error without being sent. This can be used
+    /// to detect that the token needs to be replaced before sending another
+    /// request.
+    pub fn is_token_invalid(&self) -> bool {
+        self.state.token_invalid.load(Ordering::Relaxed)
     }
 
     /// Retrieve the [`ApplicationId`] used by interaction methods.
@@ -215,7 +296,7 @@ impl Client {
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
+    pub fn ratelimiter(&self) -> Option<Arc<dyn Ratelimiter>> {
         self.state.ratelimiter.clone()
     }
 
@@ -266,7 +347,8 @@ impl Client {
 
     /// Get information about a ban of a guild.
     ///
-    /// Includes the user banned and the reason.
+    /// Includes the user banned and the reason. Resolves to `None` if the user
+    /// is not banned.
     pub fn ban(&self, guild_id: GuildId, user_id: UserId) -> GetBan<'_> {
         GetBan::new(self, guild_id, user_id)
     }
@@ -511,6 +593,27 @@ impl Client {
         GetCurrentUserConnections::new(self)
     }
 
+    /// Get the current user's role connection to an application.
+    ///
+    /// Requires an OAuth2 bearer token with the `role_connections.write` scope.
+    pub fn user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+    ) -> GetUserApplicationRoleConnection<'_> {
+        GetUserApplicationRoleConnection::new(self, application_id)
+    }
+
+    /// Update the current user's role connection to an application.
+    ///
+    /// Requires an OAuth2 bearer token with the `role_connections.write` scope.
+    pub fn update_user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+        connection: ApplicationRoleConnection,
+    ) -> UpdateUserApplicationRoleConnection<'_> {
+        UpdateUserApplicationRoleConnection::new(self, application_id, connection)
+    }
+
     /// Returns a list of guilds for the current user.
     ///
     /// # Examples
@@ -738,13 +841,21 @@ impl Client {
     ///
     /// The minimum amount of channels to modify, is a swap between two channels.
     ///
-    /// This function accepts an `Iterator` of `(ChannelId, u64)`. It also
-    /// accepts an `Iterator` of `Position`, which has extra fields.
+    /// This function accepts an iterator of `(ChannelId, u64)`. It also
+    /// accepts an iterator of [`Position`], which has extra fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateGuildChannelPositionsErrorType::DuplicateChannel`]
+    /// error type if a channel is positioned more than once.
+    ///
+    /// [`Position`]: crate::request::guild::update_guild_channel_positions::Position
+    /// [`UpdateGuildChannelPositionsErrorType::DuplicateChannel`]: crate::request::guild::update_guild_channel_positions::UpdateGuildChannelPositionsErrorType::DuplicateChannel
     pub fn update_guild_channel_positions(
         &self,
         guild_id: GuildId,
-        channel_positions: impl Iterator<Item = impl Into<Position>>,
-    ) -> UpdateGuildChannelPositions<'_> {
+        channel_positions: impl IntoIterator<Item = impl Into<Position>>,
+    ) -> Result<UpdateGuildChannelPositions<'_>, UpdateGuildChannelPositionsError> {
         UpdateGuildChannelPositions::new(self, guild_id, channel_positions)
     }
 
@@ -1086,6 +1197,42 @@ impl Client {
         GetMessage::new(self, channel_id, message_id)
     }
 
+    /// Get multiple messages by [`ChannelId`] and their [`MessageId`]s.
+    ///
+    /// Discord doesn't have an endpoint to get multiple specific messages, so
+    /// a request is made for each message concurrently, respecting
+    /// ratelimits. This is useful for resolving the chain of messages a reply
+    /// is in.
+    ///
+    /// The results are returned in the same order as the provided message
+    /// IDs. Messages that don't exist resolve to `Ok(None)`, like with
+    /// [`message`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::id::{ChannelId, MessageId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token");
+    /// let channel_id = ChannelId(1);
+    /// let message_ids = [MessageId(2), MessageId(3)];
+    ///
+    /// for result in client.messages(channel_id, &message_ids).await {
+    ///     if let Some(message) = result? {
+    ///         println!("{}: {}", message.id, message.content);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`message`]: Self::message
+    pub fn messages(&self, channel_id: ChannelId, message_ids: &[MessageId]) -> GetMessages<'_> {
+        GetMessages::new(self, channel_id, message_ids)
+    }
+
     /// Send a message to a channel.
     ///
     /// # Example
@@ -1151,6 +1298,27 @@ impl Client {
         DeleteMessages::new(self, channel_id, message_ids)
     }
 
+    /// Delete messages by [`ChannelId`] and their [`MessageId`]s, regardless
+    /// of their age or count.
+    ///
+    /// Unlike [`delete_messages`], any number of messages may be provided.
+    /// Messages younger than two weeks are deleted with bulk deletes of up to
+    /// 100 messages, while older messages, which can't be bulk deleted, are
+    /// deleted individually. The requests are made concurrently, respecting
+    /// ratelimits.
+    ///
+    /// Resolves to the first error that any of the requests returned, after
+    /// all of them have completed.
+    ///
+    /// [`delete_messages`]: Self::delete_messages
+    pub fn delete_messages_auto(
+        &self,
+        channel_id: ChannelId,
+        message_ids: &[MessageId],
+    ) -> DeleteMessagesAuto<'_> {
+        DeleteMessagesAuto::new(self, channel_id, message_ids)
+    }
+
     /// Update a message by [`ChannelId`] and [`MessageId`].
     ///
     /// You can pass `None` to any of the methods to remove the associated field.
@@ -1222,6 +1390,39 @@ impl Client {
         DeletePin::new(self, channel_id, message_id)
     }
 
+    /// Add a user to a group DM.
+    ///
+    /// An access token for the user with the `gdm.join` scope is required,
+    /// along with the nickname the user will have in the group. Refer to
+    /// [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AddGroupRecipientErrorType::AccessTokenEmpty`] error type
+    /// if the access token is empty.
+    ///
+    /// [`AddGroupRecipientErrorType::AccessTokenEmpty`]: crate::request::channel::add_group_recipient::AddGroupRecipientErrorType::AccessTokenEmpty
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/resources/channel#group-dm-add-recipient
+    pub fn add_group_recipient(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+        access_token: impl Into<String>,
+        nick: impl Into<String>,
+    ) -> Result<AddGroupRecipient<'_>, AddGroupRecipientError> {
+        AddGroupRecipient::new(self, channel_id, user_id, access_token, nick)
+    }
+
+    /// Remove a user from a group DM.
+    pub fn remove_group_recipient(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveGroupRecipient<'_> {
+        RemoveGroupRecipient::new(self, channel_id, user_id)
+    }
+
     /// Get a list of users that reacted to a message with an `emoji`.
     ///
     /// This endpoint is limited to 100 users maximum, so if a message has more than 100 reactions,
@@ -1361,11 +1562,22 @@ impl Client {
     /// Modify the position of the roles.
     ///
     /// The minimum amount of roles to modify, is a swap between two roles.
+    ///
+    /// This function accepts an iterator of `(RoleId, u64)`. It also accepts
+    /// an iterator of [`RolePosition`], whose position is optional.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateRolePositionsErrorType::DuplicateRole`] error type
+    /// if a role is positioned more than once.
+    ///
+    /// [`RolePosition`]: crate::request::guild::role::update_role_positions::RolePosition
+    /// [`UpdateRolePositionsErrorType::DuplicateRole`]: crate::request::guild::role::update_role_positions::UpdateRolePositionsErrorType::DuplicateRole
     pub fn update_role_positions(
         &self,
         guild_id: GuildId,
-        roles: impl Iterator<Item = (RoleId, u64)>,
-    ) -> UpdateRolePositions<'_> {
+        roles: impl IntoIterator<Item = impl Into<RolePosition>>,
+    ) -> Result<UpdateRolePositions<'_>, UpdateRolePositionsError> {
         UpdateRolePositions::new(self, guild_id, roles)
     }
 
@@ -1485,6 +1697,38 @@ impl Client {
         GetUser::new(self, user_id.to_string())
     }
 
+    /// Get multiple users' information by their [`UserId`]s.
+    ///
+    /// Discord doesn't have an endpoint to get multiple users, so a request is
+    /// made for each user concurrently, respecting ratelimits.
+    ///
+    /// The results are returned in the same order as the provided user IDs.
+    /// Users that don't exist resolve to `Ok(None)`, like with [`user`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::id::UserId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token");
+    /// let user_ids = [UserId(1), UserId(2)];
+    ///
+    /// for result in client.users(&user_ids).await {
+    ///     if let Some(user) = result? {
+    ///         println!("{}#{}", user.name, user.discriminator);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`user`]: Self::user
+    pub fn users(&self, user_ids: &[UserId]) -> GetUsers<'_> {
+        GetUsers::new(self, user_ids)
+    }
+
     /// Update another user's voice state.
     ///
     /// # Caveats
@@ -2133,14 +2377,83 @@ impl Client {
         SetCommandPermissions::new(self, application_id, guild_id, permissions)
     }
 
+    /// Get the entitlements of an application.
+    ///
+    /// The entitlements can be filtered by guild, SKUs, and user, and whether
+    /// they have ended. Refer to [`GetEntitlements`] for more information.
+    pub fn entitlements(&self, application_id: ApplicationId) -> GetEntitlements<'_> {
+        GetEntitlements::new(self, application_id)
+    }
+
+    /// Create a test entitlement to a SKU for a guild or user.
+    ///
+    /// This is useful for testing premium features without making a purchase.
+    pub fn create_test_entitlement(
+        &self,
+        application_id: ApplicationId,
+        sku_id: SkuId,
+        owner: TestEntitlementOwner,
+    ) -> CreateTestEntitlement<'_> {
+        CreateTestEntitlement::new(self, application_id, sku_id, owner)
+    }
+
+    /// Delete a test entitlement, by ID.
+    pub fn delete_test_entitlement(
+        &self,
+        application_id: ApplicationId,
+        entitlement_id: EntitlementId,
+    ) -> DeleteTestEntitlement<'_> {
+        DeleteTestEntitlement::new(self, application_id, entitlement_id)
+    }
+
     /// Execute a request, returning the response.
     ///
+    /// Unlike [`request`], this gives access to the status and headers of the
+    /// response, such as to tell a `200 OK` apart from a `201 Created`
+    /// response. The response isn't checked to be successful.
+    ///
+    /// # Examples
+    ///
+    /// Read the status of a response before deserializing its body:
+    ///
+    /// ```rust,no_run
+    /// use twilight_http::{request::Request, routing::Route, Client};
+    /// use twilight_model::user::User;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let request = Request::from_route(Route::GetUser {
+    ///     target_user: "1".to_owned(),
+    /// });
+    /// let response = client.raw(request).await?;
+    /// println!("status: {}", response.status());
+    ///
+    /// let bytes = hyper::body::to_bytes(response.into_body()).await?;
+    /// let user = serde_json::from_slice::<User>(&bytes)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`request`]: Self::request
+    ///
     /// # Errors
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revokation, etc.
-    #[allow(clippy::too_many_lines)]
     pub async fn raw(&self, request: Request) -> Result<Response<Body>, Error> {
+        if let Some(resp) = self.start_request(&request)? {
+            return Ok(resp);
+        }
+
+        let request = self.prepare(request)?;
+
+        self.send(request).await
+    }
+
+    /// Check that a request can be sent, returning the cached response to it
+    /// if there is one, and pass it to the request interceptor.
+    fn start_request(&self, request: &Request) -> Result<Option<Response<Body>>, Error> {
         if self.state.token_invalid.load(Ordering::Relaxed) {
             return Err(Error {
                 kind: ErrorType::Unauthorized,
@@ -2148,6 +2461,25 @@ impl Client {
             });
         }
 
+        if let Some(response_cache) = self.state.response_cache.as_ref() {
+            if request.method == Method::Get {
+                if let Some(resp) = response_cache.get(&request.path_str) {
+                    return Ok(Some(resp));
+                }
+            } else {
+                response_cache.remove(&request.path_str);
+            }
+        }
+
+        if let Some(request_interceptor) = self.state.request_interceptor.as_ref() {
+            request_interceptor.call(request);
+        }
+
+        Ok(None)
+    }
+
+    /// Build the headers and body of a request.
+    fn prepare(&self, request: Request) -> Result<PreparedRequest, Error> {
         let Request {
             body,
             form,
@@ -2155,22 +2487,21 @@ impl Client {
             method,
             path: bucket,
             path_str: path,
+            timeout,
             use_authorization_token,
         } = request;
 
-        let protocol = if self.state.use_http { "http" } else { "https" };
-        let host = self.state.proxy.as_deref().unwrap_or("discord.com");
+        let mut headers = HeaderMap::new();
 
-        let url = format!("{}://{}/api/v{}/{}", protocol, host, API_VERSION, path);
-        #[cfg(feature = "tracing")]
-        tracing::debug!("URL: {:?}", url);
-
-        let mut builder = hyper::Request::builder()
-            .method(method.into_hyper())
-            .uri(&url);
+        if use_authorization_token && self.state.send_token {
+            let token = self
+                .state
+                .token
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone();
 
-        if use_authorization_token {
-            if let Some(ref token) = self.state.token {
+            if let Some(token) = token {
                 let value = HeaderValue::from_str(&token).map_err(|source| {
                     #[allow(clippy::borrow_interior_mutable_const)]
                     let name = AUTHORIZATION.to_string();
@@ -2181,89 +2512,136 @@ impl Client {
                     }
                 })?;
 
-                if let Some(headers) = builder.headers_mut() {
-                    headers.insert(AUTHORIZATION, value);
-                }
+                headers.insert(AUTHORIZATION, value);
             }
         }
 
-        let user_agent = HeaderValue::from_static(concat!(
-            "DiscordBot (",
-            env!("CARGO_PKG_HOMEPAGE"),
-            ", ",
-            env!("CARGO_PKG_VERSION"),
-            ") Twilight-rs",
-        ));
+        let user_agent = self.state.user_agent.clone().unwrap_or_else(|| {
+            HeaderValue::from_static(concat!(
+                "DiscordBot (",
+                env!("CARGO_PKG_HOMEPAGE"),
+                ", ",
+                env!("CARGO_PKG_VERSION"),
+                ") Twilight-rs",
+            ))
+        });
+
+        let body = if let Some(form) = form {
+            if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
+                headers.insert(CONTENT_TYPE, content_type);
+            }
 
-        if let Some(headers) = builder.headers_mut() {
-            if let Some(form) = &form {
-                if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
-                    headers.insert(CONTENT_TYPE, content_type);
-                }
-            } else if let Some(bytes) = &body {
-                let len = bytes.len();
-                headers.insert(CONTENT_LENGTH, len.into());
+            let form_bytes = form.build();
+            headers.insert(CONTENT_LENGTH, form_bytes.len().into());
 
-                let content_type = HeaderValue::from_static("application/json");
-                headers.insert(CONTENT_TYPE, content_type);
+            Some(Bytes::from(form_bytes))
+        } else if let Some(bytes) = body {
+            headers.insert(CONTENT_LENGTH, bytes.len().into());
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+            Some(Bytes::from(bytes))
+        } else {
+            if method == Method::Put || method == Method::Post || method == Method::Patch {
+                headers.insert(CONTENT_LENGTH, 0.into());
             }
 
-            headers.insert(USER_AGENT, user_agent);
+            None
+        };
 
-            if let Some(req_headers) = req_headers {
-                for (maybe_name, value) in req_headers {
-                    if let Some(name) = maybe_name {
-                        headers.insert(name, value);
-                    }
+        headers.insert(USER_AGENT, user_agent);
+
+        if let Some(req_headers) = req_headers {
+            for (maybe_name, value) in req_headers {
+                if let Some(name) = maybe_name {
+                    headers.insert(name, value);
                 }
             }
+        }
 
-            if let Some(default_headers) = &self.state.default_headers {
-                for (name, value) in default_headers {
-                    headers.insert(name, HeaderValue::from(value));
-                }
+        if let Some(default_headers) = &self.state.default_headers {
+            for (name, value) in default_headers {
+                headers.insert(name, HeaderValue::from(value));
             }
         }
 
-        let req = if let Some(form) = form {
-            let form_bytes = form.build();
-            if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, form_bytes.len().into());
-            };
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
-        } else if let Some(bytes) = body {
-            builder.body(Body::from(bytes)).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else if method == Method::Put || method == Method::Post || method == Method::Patch {
-            if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, 0.into());
+        Ok(PreparedRequest {
+            body,
+            bucket,
+            headers,
+            method,
+            path,
+            timeout,
+        })
+    }
+
+    /// Send a prepared request, returning the response.
+    async fn send(&self, request: PreparedRequest) -> Result<Response<Body>, Error> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::{field, Instrument};
+
+            let span = tracing::debug_span!(
+                "request",
+                method = ?request.method,
+                route = %request.path,
+                status = field::Empty,
+                elapsed = field::Empty,
+            );
+            let started = Instant::now();
+            let result = self.dispatch(request).instrument(span.clone()).await;
+
+            if let Ok(resp) = &result {
+                span.record("status", &resp.status().as_u16());
             }
 
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else {
-            builder.body(Body::empty()).map_err(|source| Error {
+            span.record("elapsed", &field::debug(started.elapsed()));
+
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.dispatch(request).await
+    }
+
+    async fn dispatch(&self, request: PreparedRequest) -> Result<Response<Body>, Error> {
+        let PreparedRequest {
+            body,
+            bucket,
+            headers,
+            method,
+            path,
+            timeout,
+        } = request;
+
+        let protocol = if self.state.use_http { "http" } else { "https" };
+        let host = self.state.proxy.as_deref().unwrap_or("discord.com");
+
+        let url = format!("{}://{}/api/v{}/{}", protocol, host, API_VERSION, path);
+        #[cfg(feature = "tracing")]
+        tracing::debug!("URL: {:?}", url);
+
+        let mut builder = hyper::Request::builder()
+            .method(method.into_hyper())
+            .uri(&url);
+
+        if let Some(builder_headers) = builder.headers_mut() {
+            *builder_headers = headers;
+        }
+
+        let req = builder
+            .body(body.map_or_else(Body::empty, Body::from))
+            .map_err(|source| Error {
                 kind: ErrorType::BuildingRequest,
                 source: Some(Box::new(source)),
-            })?
-        };
+            })?;
 
         let inner = self.state.http.request(req);
-        let fut = time::timeout(self.state.timeout, inner);
+        let fut = time::timeout(timeout.unwrap_or(self.state.timeout), inner);
 
         let ratelimiter = match self.state.ratelimiter.as_ref() {
             Some(ratelimiter) => ratelimiter,
             None => {
-                return fut
+                let resp = fut
                     .await
                     .map_err(|source| Error {
                         kind: ErrorType::RequestTimedOut,
@@ -2272,14 +2650,16 @@ impl Client {
                     .map_err(|source| Error {
                         kind: ErrorType::RequestError,
                         source: Some(Box::new(source)),
-                    });
+                    })?;
+                self.check_unauthorized(&resp);
+
+                return self.cache_response(method, path, resp).await;
             }
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx.await.map_err(|source| Error {
+        let tx = ratelimiter.ticket(bucket).await.map_err(|source| Error {
             kind: ErrorType::RequestCanceled,
-            source: Some(Box::new(source)),
+            source: Some(source),
         })?;
 
         let resp = fut
@@ -2293,12 +2673,7 @@ impl Client {
                 source: Some(Box::new(source)),
             })?;
 
-        // If the API sent back an Unauthorized response, then the client's
-        // configured token is permanently invalid and future requests must be
-        // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
-            self.state.token_invalid.store(true, Ordering::Relaxed);
-        }
+        self.check_unauthorized(&resp);
 
         match RatelimitHeaders::try_from(resp.headers()) {
             Ok(v) => {
@@ -2313,11 +2688,69 @@ impl Client {
             }
         }
 
-        Ok(resp)
+        self.cache_response(method, path, resp).await
+    }
+
+    /// Cache the response to a `GET` request if the response cache is
+    /// enabled.
+    async fn cache_response(
+        &self,
+        method: Method,
+        path: Cow<'static, str>,
+        resp: Response<Body>,
+    ) -> Result<Response<Body>, Error> {
+        match self.state.response_cache.as_ref() {
+            Some(response_cache) if method == Method::Get => {
+                response_cache.insert(path.into_owned(), resp).await
+            }
+            _ => Ok(resp),
+        }
+    }
+
+    /// Mark the token as invalid if the API sent back an Unauthorized
+    /// response.
+    ///
+    /// The client's configured token is then permanently invalid and future
+    /// requests must be ignored to avoid API bans.
+    fn check_unauthorized(&self, resp: &Response<Body>) {
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.state.token_invalid.store(true, Ordering::Relaxed);
+        }
     }
 
     /// Execute a request, chunking and deserializing the response.
     ///
+    /// The response can be deserialized into any type, which is useful when a
+    /// model of this library doesn't include some field that Discord returns.
+    ///
+    /// # Examples
+    ///
+    /// Get a user, including a field not present on [`User`]:
+    ///
+    /// ```rust,no_run
+    /// use serde::Deserialize;
+    /// use twilight_http::{request::Request, routing::Route, Client};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct UserWithBanner {
+    ///     banner: Option<String>,
+    ///     username: String,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let request = Request::from_route(Route::GetUser {
+    ///     target_user: "1".to_owned(),
+    /// });
+    /// let user = client.request::<UserWithBanner>(request).await?;
+    /// println!("{}: {:?}", user.username, user.banner);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`User`]: twilight_model::user::User
+    ///
     /// # Errors
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
@@ -2360,8 +2793,71 @@ impl Client {
         Ok(())
     }
 
+    /// Execute a request, retrying it on server errors as configured by the
+    /// client's retry policy.
+    ///
+    /// The request is prepared once, and each attempt sends a cheap copy of
+    /// it, acquiring a new ratelimit ticket each time.
+    async fn raw_with_retries(&self, request: Request) -> Result<Response<Body>, Error> {
+        let policy = self.state.retry_policy;
+        let retry_global = policy.global_ratelimit && self.state.ratelimiter.is_some();
+
+        if !retry_global && !policy.allows(request.method) {
+            return self.raw(request).await;
+        }
+
+        if let Some(resp) = self.start_request(&request)? {
+            return Ok(resp);
+        }
+
+        let timeout = request.timeout.unwrap_or(self.state.timeout);
+        let request = self.prepare(request)?;
+        let started = Instant::now();
+        let mut retried_global = false;
+        let mut retries = 0;
+
+        loop {
+            let resp = self.send(request.clone()).await?;
+
+            // Requests that hit the global ratelimit weren't processed, so they
+            // can be retried regardless of their method, as long as the wait
+            // fits in what's left of the request's timeout.
+            if retry_global && !retried_global {
+                let remaining = timeout.checked_sub(started.elapsed()).unwrap_or_default();
+
+                if let Some(delay) = policy.global_ratelimit_delay(&resp, remaining) {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        "retrying request after global ratelimit in {:?}: {:?}",
+                        delay,
+                        request.path,
+                    );
+
+                    time::sleep(delay).await;
+                    retried_global = true;
+
+                    continue;
+                }
+            }
+
+            if !policy.allows(request.method) || !policy.should_retry(retries, resp.status()) {
+                return Ok(resp);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                "retrying request after {} response: {:?}",
+                resp.status(),
+                request.path,
+            );
+
+            time::sleep(RetryPolicy::delay(retries)).await;
+            retries += 1;
+        }
+    }
+
     async fn make_request(&self, request: Request) -> Result<Response<Body>, Error> {
-        let resp = self.raw(request).await?;
+        let resp = self.raw_with_retries(request).await?;
         let status = resp.status();
 
         if status.is_success() {
@@ -2417,3 +2913,248 @@ impl Client {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, ClientBuilderErrorType};
+    use crate::{
+        error::ErrorType,
+        request::Request,
+        routing::Route,
+        test_server::{json_response, TestServer, NO_CONTENT},
+    };
+    use hyper::StatusCode;
+    use serde::Deserialize;
+    #[cfg(feature = "tracing")]
+    use std::sync::{Arc, Mutex};
+
+    const UNAUTHORIZED: &str =
+        "HTTP/1.1 401 Unauthorized\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct UserWithBanner {
+        banner: Option<String>,
+        id: String,
+        username: String,
+    }
+
+    #[tokio::test]
+    async fn test_request_custom_type() {
+        const BODY: &str =
+            r#"{"avatar":null,"banner":"abc","discriminator":"0001","id":"1","username":"test"}"#;
+
+        let server = TestServer::respond(json_response("200 OK", BODY)).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+        let request = Request::from_route(Route::GetUser {
+            target_user: "1".to_owned(),
+        });
+        let user = client.request::<UserWithBanner>(request).await.unwrap();
+
+        assert_eq!(
+            UserWithBanner {
+                banner: Some("abc".to_owned()),
+                id: "1".to_owned(),
+                username: "test".to_owned(),
+            },
+            user,
+        );
+    }
+
+    fn delete_message() -> Request {
+        Request::from_route(Route::DeleteMessage {
+            channel_id: 1,
+            message_id: 2,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_send_token_disabled() {
+        let server = TestServer::respond(NO_CONTENT).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .send_token(false)
+            .token("token")
+            .build();
+        client.verify(delete_message()).await.unwrap();
+
+        let request = server.requests().remove(0).to_lowercase();
+        assert!(request.starts_with("delete /api/v8/channels/1/messages/2"));
+        assert!(!request.contains("authorization:"));
+    }
+
+    #[tokio::test]
+    async fn test_send_token_default() {
+        let server = TestServer::respond(NO_CONTENT).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .token("token")
+            .build();
+        client.verify(delete_message()).await.unwrap();
+
+        let request = server.requests().remove(0).to_lowercase();
+        assert!(request.contains("authorization: bot token"));
+    }
+
+    #[tokio::test]
+    async fn test_user_agent() {
+        let server = TestServer::respond(NO_CONTENT).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .user_agent("DiscordBot (https://example.com, 1.0.0) ExampleBot")
+            .unwrap()
+            .build();
+        client.verify(delete_message()).await.unwrap();
+
+        let request = server.requests().remove(0).to_lowercase();
+        assert!(request.contains("user-agent: discordbot (https://example.com, 1.0.0) examplebot"));
+    }
+
+    #[test]
+    fn test_user_agent_invalid() {
+        let error = Client::builder()
+            .user_agent("Bot\r\nInjected: true")
+            .err()
+            .expect("newlines aren't allowed in header values");
+
+        assert!(matches!(
+            error.kind(),
+            ClientBuilderErrorType::UserAgentInvalid { user_agent } if user_agent == "Bot\r\nInjected: true"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_raw_status() {
+        const BODY: &str = r#"{"id":"1","username":"test","banner":null}"#;
+
+        let server = TestServer::respond(json_response("201 Created", BODY)).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+        let response = client
+            .raw(Request::from_route(Route::GetUser {
+                target_user: "1".to_owned(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let user = serde_json::from_slice::<UserWithBanner>(&bytes).unwrap();
+        assert_eq!("test", user.username);
+    }
+
+    #[tokio::test]
+    async fn test_is_token_invalid() {
+        let server = TestServer::respond(UNAUTHORIZED).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .token("token")
+            .build();
+        assert!(!client.is_token_invalid());
+
+        assert!(client.verify(delete_message()).await.is_err());
+        assert!(client.is_token_invalid());
+
+        let error = client.verify(delete_message()).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Unauthorized));
+    }
+
+    #[tokio::test]
+    async fn test_set_token() {
+        let server = TestServer::start(|index, _| {
+            Some(if index == 0 { UNAUTHORIZED } else { NO_CONTENT }.to_owned())
+        })
+        .await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .token("old")
+            .build();
+        assert!(client.verify(delete_message()).await.is_err());
+        assert!(client.is_token_invalid());
+
+        client.set_token("new");
+        assert!(!client.is_token_invalid());
+        assert_eq!(Some("Bot new"), client.token().as_deref());
+
+        client.verify(delete_message()).await.unwrap();
+        let request = server.requests().remove(1).to_lowercase();
+        assert!(request.contains("authorization: bot new"));
+    }
+
+    /// Subscriber recording the name and fields of every created span.
+    #[cfg(feature = "tracing")]
+    #[derive(Default)]
+    struct SpanRecorder {
+        spans: Mutex<Vec<(&'static str, Vec<(&'static str, String)>)>>,
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            struct Visitor(Vec<(&'static str, String)>);
+
+            impl tracing::field::Visit for Visitor {
+                fn record_debug(
+                    &mut self,
+                    field: &tracing::field::Field,
+                    value: &dyn std::fmt::Debug,
+                ) {
+                    self.0.push((field.name(), format!("{:?}", value)));
+                }
+            }
+
+            let mut visitor = Visitor(Vec::new());
+            span.record(&mut visitor);
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span.metadata().name(), visitor.0));
+
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, _: &tracing::Event<'_>) {}
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_request_span() {
+        let recorder = Arc::new(SpanRecorder::default());
+        let _guard = tracing::subscriber::set_default(Arc::clone(&recorder));
+
+        let server = TestServer::respond(NO_CONTENT).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+        client.verify(delete_message()).await.unwrap();
+
+        let spans = recorder.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| *name == "request")
+            .expect("no request span");
+        assert!(fields.contains(&("method", "Delete".to_owned())));
+        assert!(fields.contains(&("route", "channels/1/messages/2".to_owned())));
+    }
+}
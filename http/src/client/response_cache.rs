@@ -0,0 +1,235 @@
+use crate::error::{Error, ErrorType};
+use hyper::{body::Bytes, header::HeaderMap, Body, Response, StatusCode};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Maximum number of responses cached at once.
+///
+/// Once reached, the oldest response is evicted when inserting another one.
+pub(crate) const MAX_RESPONSES: usize = 1000;
+
+/// Status, headers, and body of a successful response.
+#[derive(Debug)]
+struct CachedResponse {
+    body: Bytes,
+    headers: HeaderMap,
+    inserted_at: Instant,
+    status: StatusCode,
+}
+
+/// Cache of responses to `GET` requests, keyed by the request path.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    responses: Mutex<HashMap<String, CachedResponse>>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            responses: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Get a copy of the response cached for a path, if it hasn't expired.
+    pub(crate) fn get(&self, path: &str) -> Option<Response<Body>> {
+        let mut responses = self.responses.lock().expect("responses poisoned");
+        let cached = responses.get(path)?;
+
+        if cached.inserted_at.elapsed() >= self.ttl {
+            responses.remove(path);
+
+            return None;
+        }
+
+        let mut response = Response::new(Body::from(cached.body.clone()));
+        *response.status_mut() = cached.status;
+        *response.headers_mut() = cached.headers.clone();
+
+        Some(response)
+    }
+
+    /// Remove the responses cached for the resource of a path, such as after a
+    /// request modifying it.
+    ///
+    /// Every response under the path's top-level resource, such as
+    /// `channels/1` for `channels/1/messages/2`, is removed, since modifying a
+    /// resource can change the responses of its parent and sibling routes.
+    pub(crate) fn remove(&self, path: &str) {
+        let prefix = resource_prefix(path);
+
+        self.responses
+            .lock()
+            .expect("responses poisoned")
+            .retain(|cached_path, _| !is_under(cached_path, prefix));
+    }
+
+    /// Remove every cached response, such as after the token was replaced.
+    pub(crate) fn clear(&self) {
+        self.responses.lock().expect("responses poisoned").clear();
+    }
+
+    /// Cache a response for a path if it was successful.
+    ///
+    /// The body of the response is read to be cached, so an equivalent
+    /// response is returned. Expired responses are evicted, as is the oldest
+    /// response if [`MAX_RESPONSES`] are cached.
+    pub(crate) async fn insert(
+        &self,
+        path: String,
+        response: Response<Body>,
+    ) -> Result<Response<Body>, Error> {
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let (parts, body) = response.into_parts();
+        let bytes = hyper::body::to_bytes(body).await.map_err(|source| Error {
+            kind: ErrorType::ChunkingResponse,
+            source: Some(Box::new(source)),
+        })?;
+
+        let mut responses = self.responses.lock().expect("responses poisoned");
+        let ttl = self.ttl;
+        responses.retain(|_, cached| cached.inserted_at.elapsed() < ttl);
+
+        if responses.len() >= MAX_RESPONSES && !responses.contains_key(&path) {
+            let oldest = responses
+                .iter()
+                .min_by_key(|(_, cached)| cached.inserted_at)
+                .map(|(path, _)| path.clone());
+
+            if let Some(oldest) = oldest {
+                responses.remove(&oldest);
+            }
+        }
+
+        responses.insert(
+            path,
+            CachedResponse {
+                body: bytes.clone(),
+                headers: parts.headers.clone(),
+                inserted_at: Instant::now(),
+                status: parts.status,
+            },
+        );
+
+        Ok(Response::from_parts(parts, Body::from(bytes)))
+    }
+}
+
+/// Top-level resource of a path, made of its first two segments without the
+/// query string, such as `guilds/1` for `guilds/1/members?limit=5`.
+fn resource_prefix(path: &str) -> &str {
+    let path = path.split('?').next().unwrap_or(path);
+
+    match path.match_indices('/').nth(1) {
+        Some((idx, _)) => &path[..idx],
+        None => path,
+    }
+}
+
+/// Whether a path is the given prefix or a route under it.
+fn is_under(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix).map_or(false, |rest| {
+        rest.is_empty() || rest.starts_with('/') || rest.starts_with('?')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_under, resource_prefix};
+    use crate::{
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use std::{net::SocketAddr, time::Duration};
+    use twilight_model::id::{ChannelId, GuildId};
+
+    /// Respond to every request with an empty JSON array.
+    async fn serve() -> TestServer {
+        TestServer::respond(json_response("200 OK", "[]")).await
+    }
+
+    fn client(addr: SocketAddr, ttl: Duration) -> Client {
+        Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .response_cache(ttl)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_get_cached() {
+        let server = serve().await;
+        let client = client(server.addr(), Duration::from_secs(60));
+
+        assert!(client.guild_members(GuildId(1)).await.unwrap().is_empty());
+        assert!(client.guild_members(GuildId(1)).await.unwrap().is_empty());
+        assert_eq!(1, server.received());
+
+        assert!(client.guild_members(GuildId(2)).await.unwrap().is_empty());
+        assert_eq!(2, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_expired_not_cached() {
+        let server = serve().await;
+        let client = client(server.addr(), Duration::from_secs(0));
+
+        client.guild_members(GuildId(1)).await.unwrap();
+        client.guild_members(GuildId(1)).await.unwrap();
+        assert_eq!(2, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_non_get_not_cached() {
+        let server = serve().await;
+        let client = client(server.addr(), Duration::from_secs(60));
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+        assert_eq!(2, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_modification_invalidates_resource() {
+        let server = serve().await;
+        let client = client(server.addr(), Duration::from_secs(60));
+
+        client.channel_messages(ChannelId(1)).await.unwrap();
+        client.guild_members(GuildId(1)).await.unwrap();
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+        assert_eq!(3, server.received());
+
+        client.channel_messages(ChannelId(1)).await.unwrap();
+        client.guild_members(GuildId(1)).await.unwrap();
+        assert_eq!(4, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_set_token_clears() {
+        let server = serve().await;
+        let client = client(server.addr(), Duration::from_secs(60));
+
+        client.guild_members(GuildId(1)).await.unwrap();
+        client.set_token("other token");
+        client.guild_members(GuildId(1)).await.unwrap();
+        assert_eq!(2, server.received());
+    }
+
+    #[test]
+    fn test_resource_prefix() {
+        assert_eq!("guilds/1", resource_prefix("guilds/1/members?limit=5"));
+        assert_eq!("channels/1", resource_prefix("channels/1"));
+        assert_eq!("gateway", resource_prefix("gateway?v=9"));
+
+        assert!(is_under("channels/1/messages?limit=5", "channels/1"));
+        assert!(is_under("channels/1", "channels/1"));
+        assert!(!is_under("channels/10", "channels/1"));
+    }
+}
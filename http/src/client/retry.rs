@@ -0,0 +1,291 @@
+use crate::request::Method;
+use hyper::{Body, Response, StatusCode};
+use std::{str, time::Duration};
+
+/// Delay before the first retry, doubled after every following attempt.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Policy for retrying requests that failed due to a server error or the
+/// global ratelimit.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetryPolicy {
+    /// Whether to retry a request once after hitting the global ratelimit.
+    pub(crate) global_ratelimit: bool,
+    /// Maximum number of times to retry a request.
+    pub(crate) max_retries: u8,
+    /// Whether to retry requests with non-idempotent methods.
+    pub(crate) non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            global_ratelimit: true,
+            max_retries: 0,
+            non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether requests with the given method may ever be retried.
+    pub(crate) const fn allows(self, method: Method) -> bool {
+        self.max_retries > 0 && (self.non_idempotent || method.is_idempotent())
+    }
+
+    /// Whether a request should be retried after the given number of prior
+    /// retries resulted in a response with the given status.
+    pub(crate) fn should_retry(self, retries: u8, status: StatusCode) -> bool {
+        retries < self.max_retries
+            && matches!(
+                status,
+                StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            )
+    }
+
+    /// Delay to wait before sending the retry after the given number of prior
+    /// retries.
+    pub(crate) fn delay(retries: u8) -> Duration {
+        BASE_DELAY * 2_u32.saturating_pow(retries.into())
+    }
+
+    /// Delay to wait before retrying a request that hit the global ratelimit.
+    ///
+    /// Returns `None` if the response isn't a global 429, its `Retry-After`
+    /// header is missing or invalid, or the delay exceeds what's left of the
+    /// request's timeout.
+    /// Per-route 429s aren't retried since they're handled by the
+    /// ratelimiter's buckets.
+    pub(crate) fn global_ratelimit_delay(
+        self,
+        response: &Response<Body>,
+        remaining: Duration,
+    ) -> Option<Duration> {
+        if !self.global_ratelimit || response.status() != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        let headers = response.headers();
+        let global = headers.get("x-ratelimit-global")?.as_bytes();
+
+        if !global.eq_ignore_ascii_case(b"true") {
+            return None;
+        }
+
+        let retry_after = str::from_utf8(headers.get("retry-after")?.as_bytes())
+            .ok()?
+            .parse::<f64>()
+            .ok()?;
+
+        if !retry_after.is_finite() || retry_after < 0.0 {
+            return None;
+        }
+
+        let delay = Duration::from_secs_f64(retry_after);
+
+        if delay > remaining {
+            return None;
+        }
+
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::{
+        error::ErrorType,
+        request::Method,
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use hyper::StatusCode;
+    use std::time::Duration;
+    use twilight_model::id::{ChannelId, GuildId};
+
+    const SERVICE_UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    const GLOBAL_RATELIMITED: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0\r\nx-ratelimit-global: true\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    const GLOBAL_RATELIMITED_1S: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 1\r\nx-ratelimit-global: true\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    const ROUTE_RATELIMITED: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0\r\nx-ratelimit-scope: user\r\ncontent-type: application/json\r\ncontent-length: 54\r\nconnection: close\r\n\r\n{\"global\":false,\"message\":\"limited\",\"retry_after\":0.0}";
+
+    /// Respond with `failure` to the first `failures` received requests and
+    /// with an empty JSON array afterwards.
+    async fn serve(failure: &'static str, failures: usize) -> TestServer {
+        TestServer::start(move |index, _| {
+            Some(if index < failures {
+                failure.to_owned()
+            } else {
+                json_response("200 OK", "[]")
+            })
+        })
+        .await
+    }
+
+    #[test]
+    fn test_allows() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            non_idempotent: false,
+            ..RetryPolicy::default()
+        };
+        assert!(policy.allows(Method::Get));
+        assert!(policy.allows(Method::Delete));
+        assert!(policy.allows(Method::Put));
+        assert!(!policy.allows(Method::Patch));
+        assert!(!policy.allows(Method::Post));
+
+        let policy = RetryPolicy {
+            non_idempotent: true,
+            ..policy
+        };
+        assert!(policy.allows(Method::Post));
+
+        assert!(!RetryPolicy::default().allows(Method::Get));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            non_idempotent: false,
+            ..RetryPolicy::default()
+        };
+        assert!(policy.should_retry(0, StatusCode::BAD_GATEWAY));
+        assert!(policy.should_retry(1, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.should_retry(2, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.should_retry(0, StatusCode::NOT_FOUND));
+        assert!(!policy.should_retry(0, StatusCode::OK));
+    }
+
+    #[test]
+    fn test_delay() {
+        assert_eq!(Duration::from_millis(500), RetryPolicy::delay(0));
+        assert_eq!(Duration::from_secs(1), RetryPolicy::delay(1));
+        assert_eq!(Duration::from_secs(2), RetryPolicy::delay(2));
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let server = serve(SERVICE_UNAVAILABLE, 2).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .retry_5xx(2)
+            .build();
+
+        let members = client.guild_members(GuildId(1)).await.unwrap();
+
+        assert!(members.is_empty());
+        assert_eq!(3, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_not_retried() {
+        let server = serve(SERVICE_UNAVAILABLE, 1).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .retry_5xx(2)
+            .build();
+
+        let error = client
+            .create_typing_trigger(ChannelId(1))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::ServiceUnavailable { .. }));
+        assert_eq!(1, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_retried() {
+        let server = serve(GLOBAL_RATELIMITED, 1).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .build();
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+
+        assert_eq!(2, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_retried_once() {
+        let server = serve(GLOBAL_RATELIMITED, 2).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .build();
+
+        assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+        assert_eq!(2, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_retried_with_body() {
+        let server = TestServer::start(|index, _| {
+            Some(if index == 0 {
+                GLOBAL_RATELIMITED.to_owned()
+            } else {
+                json_response("200 OK", "{}")
+            })
+        })
+        .await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .build();
+
+        let _res = client
+            .create_message(ChannelId(1))
+            .content("retried")
+            .unwrap()
+            .await;
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert!(requests
+            .iter()
+            .all(|request| request.ends_with("{\"content\":\"retried\"}")));
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_exceeding_remaining_timeout() {
+        let server = serve(GLOBAL_RATELIMITED_1S, 1).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .timeout(Duration::from_secs(1))
+            .build();
+
+        assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+        assert_eq!(1, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_route_ratelimit_not_retried() {
+        let server = serve(ROUTE_RATELIMITED, 1).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .build();
+
+        assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+        assert_eq!(1, server.received());
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_retry_disabled() {
+        let server = serve(GLOBAL_RATELIMITED, 1).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .retry_global_ratelimit(false)
+            .build();
+
+        assert!(client.create_typing_trigger(ChannelId(1)).await.is_err());
+        assert_eq!(1, server.received());
+    }
+}
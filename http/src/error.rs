@@ -124,3 +124,49 @@ pub enum ErrorType {
     /// or is revoked. Recreate the client to configure a new token.
     Unauthorized,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorType;
+    use crate::{
+        api_error::{ApiError, ErrorCode},
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use hyper::StatusCode;
+    use twilight_model::id::ChannelId;
+
+    #[tokio::test]
+    async fn test_response_error_body() {
+        let body = r#"{"code":50035,"errors":{"content":{"_errors":[{"code":"BASE_TYPE_MAX_LENGTH","message":"Must be 2000 or fewer in length."}]}},"message":"Invalid Form Body"}"#;
+        let server = TestServer::respond(json_response("400 Bad Request", body)).await;
+
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let error = client
+            .create_typing_trigger(ChannelId(1))
+            .await
+            .unwrap_err();
+
+        let (error, status) = match error.kind() {
+            ErrorType::Response { error, status, .. } => (error, status),
+            other => panic!("expected response error, got {:?}", other),
+        };
+        let general = match error {
+            ApiError::General(general) => general,
+            other => panic!("expected general error, got {:?}", other),
+        };
+
+        assert_eq!(StatusCode::BAD_REQUEST, *status);
+        assert_eq!(ErrorCode::InvalidFormBodyOrContentType, general.code);
+        assert_eq!("Invalid Form Body", general.message);
+
+        let field_errors = general.field_errors();
+        assert_eq!(1, field_errors.len());
+        assert_eq!("content", field_errors[0].0);
+        assert_eq!("BASE_TYPE_MAX_LENGTH", field_errors[0].1.code);
+    }
+}
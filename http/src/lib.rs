@@ -109,6 +109,9 @@ pub mod routing;
 
 mod json;
 
+#[cfg(test)]
+mod test_server;
+
 /// Discord API version used by this crate.
 pub const API_VERSION: u8 = 8;
 
@@ -12,14 +12,18 @@ use crate::routing::Path;
 use bucket::{Bucket, BucketQueueTask, TimeRemaining};
 use std::{
     collections::hash_map::{Entry, HashMap},
+    error::Error,
+    fmt::Debug,
+    future::{self, Future},
+    pin::Pin,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::sync::{
-    oneshot::{self, Receiver, Sender},
+    oneshot::{self, Sender},
     Mutex,
 };
 
@@ -44,13 +48,127 @@ impl GlobalLockPair {
     }
 }
 
+/// Sender used to report the ratelimit headers of a response back to the
+/// ratelimiter once a request has been made.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Future returned by [`Ratelimiter::ticket`].
+pub type GetTicketFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TicketSender, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Future returned by [`Ratelimiter::time_until_available`].
+pub type GetTimeUntilAvailableFuture<'a> =
+    Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>>;
+
+/// Future returned by [`Ratelimiter::bucket_state`].
+pub type GetBucketStateFuture<'a> =
+    Pin<Box<dyn Future<Output = Option<BucketSnapshot>> + Send + 'a>>;
+
+/// Snapshot of the state of a ratelimit bucket at a point in time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BucketSnapshot {
+    limit: u64,
+    remaining: u64,
+    reset_at: Option<Instant>,
+}
+
+impl BucketSnapshot {
+    /// Create a new snapshot of a bucket's state.
+    ///
+    /// This is useful for implementing [`Ratelimiter::bucket_state`].
+    pub const fn new(limit: u64, remaining: u64, reset_at: Option<Instant>) -> Self {
+        Self {
+            limit,
+            remaining,
+            reset_at,
+        }
+    }
+
+    /// Total number of requests that can be made in a period.
+    ///
+    /// This is [`u64::MAX`] if no response of the bucket has been received yet.
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Number of requests remaining in the current period.
+    pub const fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// When the current period ends and the remaining requests are reset.
+    ///
+    /// `None` if the period hasn't started yet.
+    pub const fn reset_at(&self) -> Option<Instant> {
+        self.reset_at
+    }
+}
+
+/// Ratelimiter used by the [`Client`] to queue requests.
+///
+/// Implement this to share ratelimits between multiple processes using the
+/// same token, for example by storing buckets in a database. The built-in
+/// implementation is [`InMemoryRatelimiter`].
+///
+/// # Examples
+///
+/// Use a shared in-memory ratelimiter with a client:
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use twilight_http::{ratelimiting::InMemoryRatelimiter, Client};
+///
+/// let ratelimiter = Arc::new(InMemoryRatelimiter::new());
+/// let client = Client::builder().ratelimiter(Some(ratelimiter)).build();
+/// ```
+///
+/// [`Client`]: crate::Client
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Take a ticket for a request to the given path.
+    ///
+    /// The returned future resolves once the request can be made. The client
+    /// then sends the ratelimit headers of the response through the resolved
+    /// [`TicketSender`], or `None` if they couldn't be parsed.
+    ///
+    /// Resolve to an error if the ticket can't be granted, such as if the
+    /// ratelimiter is shutting down; the client then cancels the request.
+    fn ticket(&self, path: Path) -> GetTicketFuture<'_>;
+
+    /// Get a snapshot of the state of the bucket of the given path.
+    ///
+    /// This is useful for diagnosing why requests are stalled. Resolves to
+    /// `None` if the bucket isn't known, such as if no request to the path has
+    /// been made recently.
+    ///
+    /// The default implementation always resolves to `None`.
+    fn bucket_state(&self, path: &Path) -> GetBucketStateFuture<'_> {
+        let _ = path;
+
+        Box::pin(future::ready(None))
+    }
+
+    /// Provide an estimate for the time left until the given path can be used
+    /// without being ratelimited.
+    ///
+    /// Resolves to `None` if no ratelimit is known or if requests are
+    /// remaining.
+    ///
+    /// The default implementation always resolves to `None`.
+    fn time_until_available(&self, path: &Path) -> GetTimeUntilAvailableFuture<'_> {
+        let _ = path;
+
+        Box::pin(future::ready(None))
+    }
+}
+
+/// Default ratelimiter, keeping track of buckets in memory.
 #[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
+pub struct InMemoryRatelimiter {
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
     global: Arc<GlobalLockPair>,
 }
 
-impl Ratelimiter {
+impl InMemoryRatelimiter {
     /// Create a new ratelimiter.
     ///
     /// Most users won't need to use this directly. If you're creating your own
@@ -59,28 +177,6 @@ impl Ratelimiter {
         Self::default()
     }
 
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        #[cfg(feature = "tracing")]
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx).await;
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
-        }
-
-        rx
-    }
-
     /// Provide an estimate for the time left until a path can be used
     /// without being ratelimited.
     ///
@@ -94,11 +190,7 @@ impl Ratelimiter {
         }
     }
 
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
+    async fn entry(&self, path: Path, tx: Sender<TicketSender>) -> (Arc<Bucket>, bool) {
         // nb: not realisically point of contention
         let mut buckets = self.buckets.lock().await;
 
@@ -128,3 +220,186 @@ impl Ratelimiter {
         }
     }
 }
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket(&self, path: Path) -> GetTicketFuture<'_> {
+        Box::pin(async move {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("getting bucket for path: {:?}", path);
+
+            let (tx, rx) = oneshot::channel();
+            let (bucket, fresh) = self.entry(path.clone(), tx).await;
+
+            if fresh {
+                tokio::spawn(
+                    BucketQueueTask::new(
+                        bucket,
+                        Arc::clone(&self.buckets),
+                        Arc::clone(&self.global),
+                        path,
+                    )
+                    .run(),
+                );
+            }
+
+            rx.await
+                .map_err(|source| Box::new(source) as Box<dyn Error + Send + Sync>)
+        })
+    }
+
+    fn bucket_state(&self, path: &Path) -> GetBucketStateFuture<'_> {
+        let path = path.clone();
+
+        Box::pin(async move {
+            let bucket = Arc::clone(self.buckets.lock().await.get(&path)?);
+            let reset_at = bucket
+                .started_at
+                .lock()
+                .await
+                .map(|started_at| started_at + Duration::from_millis(bucket.reset_after()));
+
+            Some(BucketSnapshot::new(
+                bucket.limit(),
+                bucket.remaining(),
+                reset_at,
+            ))
+        })
+    }
+
+    fn time_until_available(&self, path: &Path) -> GetTimeUntilAvailableFuture<'_> {
+        let path = path.clone();
+
+        Box::pin(async move { InMemoryRatelimiter::time_until_available(self, &path).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketSnapshot, GetTicketFuture, InMemoryRatelimiter, Ratelimiter};
+    use crate::{
+        routing::Path,
+        test_server::{TestServer, NO_CONTENT},
+        Client,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use tokio::{
+        sync::oneshot,
+        time::{self, Duration},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(BucketSnapshot: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Ratelimiter, Send, Sync);
+    assert_obj_safe!(Ratelimiter);
+
+    /// Ratelimiter handing out tickets immediately, counting them.
+    #[derive(Debug, Default)]
+    struct CountingRatelimiter(AtomicUsize);
+
+    impl Ratelimiter for CountingRatelimiter {
+        fn ticket(&self, _: Path) -> GetTicketFuture<'_> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async {
+                let (tx, _) = oneshot::channel();
+
+                Ok(tx)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_ticket() {
+        let ratelimiter: Arc<dyn Ratelimiter> = Arc::new(InMemoryRatelimiter::new());
+
+        let tx = ratelimiter.ticket(Path::ChannelsId(1)).await.unwrap();
+
+        assert!(tx.send(None).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_custom_ratelimiter() {
+        let server = TestServer::respond(NO_CONTENT).await;
+
+        let ratelimiter = Arc::new(CountingRatelimiter::default());
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(Some(Arc::clone(&ratelimiter) as Arc<dyn Ratelimiter>))
+            .build();
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+
+        assert_eq!(1, ratelimiter.0.load(Ordering::SeqCst));
+        assert!(client.ratelimiter().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_state() {
+        let server = TestServer::respond(
+            "HTTP/1.1 204 No Content\r\nx-ratelimit-bucket: abc\r\nx-ratelimit-limit: 5\r\nx-ratelimit-remaining: 4\r\nx-ratelimit-reset: 1600000000.000\r\nx-ratelimit-reset-after: 2.000\r\nconnection: close\r\n\r\n",
+        )
+        .await;
+
+        let ratelimiter = Arc::new(InMemoryRatelimiter::new());
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(Some(Arc::clone(&ratelimiter) as Arc<dyn Ratelimiter>))
+            .build();
+        let path = Path::ChannelsIdTyping(1);
+
+        assert!(ratelimiter.bucket_state(&path).await.is_none());
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+
+        // The bucket is updated in the background once the response headers
+        // are received.
+        let mut snapshot = None;
+
+        for _ in 0..100 {
+            snapshot = ratelimiter.bucket_state(&path).await;
+
+            if snapshot.map_or(false, |snapshot| snapshot.remaining() == 4) {
+                break;
+            }
+
+            time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let snapshot = snapshot.unwrap();
+        assert_eq!(5, snapshot.limit());
+        assert_eq!(4, snapshot.remaining());
+        assert!(snapshot.reset_at().is_some());
+        assert!(ratelimiter
+            .bucket_state(&Path::ChannelsId(1))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_time_until_available_through_client() {
+        let client = Client::new("token".to_owned());
+        let ratelimiter = client.ratelimiter().unwrap();
+
+        assert!(ratelimiter
+            .time_until_available(&Path::ChannelsId(1))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_state_default() {
+        let ratelimiter = CountingRatelimiter::default();
+
+        assert!(ratelimiter
+            .bucket_state(&Path::ChannelsId(1))
+            .await
+            .is_none());
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Form, Pending, Request},
+    error::Error as HttpError,
+    request::{validate, Form, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageFlags},
@@ -14,11 +19,76 @@ use twilight_model::{
     id::ApplicationId,
 };
 
+/// A followup message can not be created as configured.
+#[derive(Debug)]
+pub struct CreateFollowupMessageError {
+    kind: CreateFollowupMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateFollowupMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateFollowupMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateFollowupMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateFollowupMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateFollowupMessageErrorType::ComponentsInvalid => {
+                f.write_str("message components are invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateFollowupMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateFollowupMessageError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateFollowupMessageErrorType {
+    /// Components are invalid.
+    ///
+    /// The source of the error is a [`ComponentValidationError`] describing
+    /// the problem.
+    ///
+    /// [`ComponentValidationError`]: crate::request::channel::message::ComponentValidationError
+    ComponentsInvalid,
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct CreateFollowupMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -74,7 +144,10 @@ impl<'a> CreateFollowupMessage<'a> {
         token: impl Into<String>,
     ) -> Self {
         Self {
-            fields: CreateFollowupMessageFields::default(),
+            fields: CreateFollowupMessageFields {
+                allowed_mentions: http.default_allowed_mentions(),
+                ..CreateFollowupMessageFields::default()
+            },
             files: Vec::new(),
             fut: None,
             http,
@@ -84,8 +157,15 @@ impl<'a> CreateFollowupMessage<'a> {
     }
 
     /// Specify the [`AllowedMentions`] for the webhook message.
-    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
-        self.fields.allowed_mentions.replace(allowed_mentions);
+    ///
+    /// Overrides the client's default allowed mentions. Accepts either
+    /// [`AllowedMentions`] or an [`AllowedMentionsBuilder`].
+    ///
+    /// [`AllowedMentionsBuilder`]: twilight_model::channel::message::allowed_mentions::AllowedMentionsBuilder
+    pub fn allowed_mentions(mut self, allowed_mentions: impl Into<AllowedMentions>) -> Self {
+        self.fields
+            .allowed_mentions
+            .replace(allowed_mentions.into());
 
         self
     }
@@ -97,6 +177,29 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Set the list of [`Component`]s of the followup message.
+    ///
+    /// Top-level components must be action rows, which may only contain
+    /// buttons and select menus. A message may have at most 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateFollowupMessageErrorType::ComponentsInvalid`] error
+    /// type if the components are invalid.
+    pub fn components(
+        mut self,
+        components: &[Component],
+    ) -> Result<Self, CreateFollowupMessageError> {
+        validate::components(components).map_err(|source| CreateFollowupMessageError {
+            kind: CreateFollowupMessageErrorType::ComponentsInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        self.fields.components.replace(components.to_vec());
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints.
@@ -114,14 +217,8 @@ impl<'a> CreateFollowupMessage<'a> {
     }
 
     /// Set if the followup should be ephemeral.
-    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
-        if ephemeral {
-            self.fields.flags.replace(MessageFlags::EPHEMERAL);
-        } else {
-            self.fields.flags = None;
-        }
-
-        self
+    pub fn ephemeral(self, ephemeral: bool) -> Self {
+        self.flag(MessageFlags::EPHEMERAL, ephemeral)
     }
 
     /// Attach a file to the webhook.
@@ -205,6 +302,11 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Set if the embeds of the followup should be suppressed.
+    pub fn suppress_embeds(self, suppress_embeds: bool) -> Self {
+        self.flag(MessageFlags::SUPPRESS_EMBEDS, suppress_embeds)
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
@@ -219,7 +321,16 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    /// Enable or disable a flag, keeping any other set flags.
+    fn flag(mut self, flag: MessageFlags, enabled: bool) -> Self {
+        let mut flags = self.fields.flags.unwrap_or_else(MessageFlags::empty);
+        flags.set(flag, enabled);
+        self.fields.flags = if flags.is_empty() { None } else { Some(flags) };
+
+        self
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
         let mut request = Request::builder(Route::ExecuteWebhook {
             token: self.token.clone(),
             wait: None,
@@ -236,7 +347,7 @@ impl<'a> CreateFollowupMessage<'a> {
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -253,3 +364,83 @@ impl<'a> CreateFollowupMessage<'a> {
 }
 
 poll_req!(CreateFollowupMessage<'_>, Option<Message>);
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateFollowupMessage, CreateFollowupMessageErrorType};
+    use crate::{
+        client::Client,
+        request::validate::tests::{button, row},
+    };
+    use serde_json::json;
+    use twilight_model::{
+        channel::message::{allowed_mentions::AllowedMentionsBuilder, MessageFlags},
+        id::{ApplicationId, RoleId, UserId},
+    };
+
+    #[test]
+    fn test_allowed_mentions() {
+        let client = Client::builder()
+            .default_allowed_mentions(AllowedMentionsBuilder::new().build())
+            .build();
+
+        let default = CreateFollowupMessage::new(&client, ApplicationId(1), "token");
+        assert_eq!(
+            json!({ "parse": [] }),
+            serde_json::to_value(&default.fields).unwrap()["allowed_mentions"],
+        );
+
+        let custom = CreateFollowupMessage::new(&client, ApplicationId(1), "token")
+            .allowed_mentions(
+                AllowedMentionsBuilder::new()
+                    .user_ids(vec![UserId(3)])
+                    .role_ids(vec![RoleId(4)]),
+            );
+        assert_eq!(
+            json!({ "parse": [], "users": ["3"], "roles": ["4"] }),
+            serde_json::to_value(&custom.fields).unwrap()["allowed_mentions"],
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        let client = Client::new("token");
+
+        let followup = CreateFollowupMessage::new(&client, ApplicationId(1), "token")
+            .ephemeral(true)
+            .suppress_embeds(true);
+        assert_eq!(
+            Some(MessageFlags::EPHEMERAL | MessageFlags::SUPPRESS_EMBEDS),
+            followup.fields.flags,
+        );
+
+        let followup = followup.ephemeral(false);
+        assert_eq!(Some(MessageFlags::SUPPRESS_EMBEDS), followup.fields.flags);
+
+        let followup = followup.suppress_embeds(false);
+        assert!(followup.fields.flags.is_none());
+    }
+
+    #[test]
+    fn test_components() {
+        let client = Client::new("token");
+        let components = [row(vec![button("yes"), button("no")])];
+
+        let builder = CreateFollowupMessage::new(&client, ApplicationId(1), "token")
+            .components(&components)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&components).unwrap(),
+            serde_json::to_value(&builder.fields).unwrap()["components"],
+        );
+
+        let error = CreateFollowupMessage::new(&client, ApplicationId(1), "token")
+            .components(&[row(vec![row(vec![button("yes")])])])
+            .err()
+            .expect("components are invalid");
+        assert!(matches!(
+            error.kind(),
+            CreateFollowupMessageErrorType::ComponentsInvalid
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    application::entitlement::Entitlement,
+    id::{ApplicationId, GenericId, GuildId, UserId},
+    oauth::id::SkuId,
+};
+
+/// Owner of a test entitlement.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TestEntitlementOwner {
+    /// Guild that is granted access to the SKU.
+    Guild(GuildId),
+    /// User that is granted access to the SKU.
+    User(UserId),
+}
+
+#[derive(Serialize)]
+struct CreateTestEntitlementFields {
+    owner_id: GenericId,
+    owner_type: u8,
+    sku_id: SkuId,
+}
+
+/// Create a test entitlement to a SKU for a guild or user.
+///
+/// Test entitlements don't have a start or end date and can be removed with
+/// [`DeleteTestEntitlement`].
+///
+/// [`DeleteTestEntitlement`]: super::DeleteTestEntitlement
+pub struct CreateTestEntitlement<'a> {
+    application_id: ApplicationId,
+    fields: CreateTestEntitlementFields,
+    fut: Option<Pending<'a, Entitlement>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateTestEntitlement<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        sku_id: SkuId,
+        owner: TestEntitlementOwner,
+    ) -> Self {
+        let (owner_id, owner_type) = match owner {
+            TestEntitlementOwner::Guild(guild_id) => (guild_id.0, 1),
+            TestEntitlementOwner::User(user_id) => (user_id.0, 2),
+        };
+
+        Self {
+            application_id,
+            fields: CreateTestEntitlementFields {
+                owner_id: GenericId(owner_id),
+                owner_type,
+                sku_id,
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        let request = Request::builder(Route::CreateTestEntitlement {
+            application_id: self.application_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        Ok(request)
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateTestEntitlement<'_>, Entitlement);
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateTestEntitlement, TestEntitlementOwner};
+    use crate::{client::Client, request::Method, routing::Path};
+    use twilight_model::{
+        id::{ApplicationId, UserId},
+        oauth::id::SkuId,
+    };
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+        let actual = CreateTestEntitlement::new(
+            &client,
+            ApplicationId(1),
+            SkuId(2),
+            TestEntitlementOwner::User(UserId(3)),
+        )
+        .request()
+        .expect("failed to create request");
+
+        assert_eq!(Method::Post, actual.method);
+        assert_eq!(Path::ApplicationEntitlement(1), actual.path);
+        assert_eq!(
+            br#"{"owner_id":"3","owner_type":2,"sku_id":"2"}"#.to_vec(),
+            actual.body.expect("body is present")
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::id::{ApplicationId, EntitlementId};
+
+/// Delete a test entitlement, by ID.
+pub struct DeleteTestEntitlement<'a> {
+    application_id: ApplicationId,
+    entitlement_id: EntitlementId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+}
+
+impl<'a> DeleteTestEntitlement<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        entitlement_id: EntitlementId,
+    ) -> Self {
+        Self {
+            application_id,
+            entitlement_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::DeleteTestEntitlement {
+            application_id: self.application_id.0,
+            entitlement_id: self.entitlement_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(DeleteTestEntitlement<'_>, ());
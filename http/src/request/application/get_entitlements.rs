@@ -0,0 +1,140 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{
+    application::entitlement::Entitlement,
+    id::{ApplicationId, GuildId, UserId},
+    oauth::id::SkuId,
+};
+
+#[derive(Default)]
+struct GetEntitlementsFields {
+    exclude_ended: Option<bool>,
+    guild_id: Option<GuildId>,
+    sku_ids: Vec<u64>,
+    user_id: Option<UserId>,
+}
+
+/// Get the entitlements of an application.
+///
+/// # Examples
+///
+/// Get the active entitlements of user `200` to application `100`:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{ApplicationId, UserId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+///
+/// let entitlements = client
+///     .entitlements(ApplicationId(100))
+///     .user_id(UserId(200))
+///     .exclude_ended(true)
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct GetEntitlements<'a> {
+    application_id: ApplicationId,
+    fields: GetEntitlementsFields,
+    fut: Option<Pending<'a, Vec<Entitlement>>>,
+    http: &'a Client,
+}
+
+impl<'a> GetEntitlements<'a> {
+    pub(crate) fn new(http: &'a Client, application_id: ApplicationId) -> Self {
+        Self {
+            application_id,
+            fields: GetEntitlementsFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Set whether to exclude entitlements that have ended.
+    pub fn exclude_ended(mut self, exclude_ended: bool) -> Self {
+        self.fields.exclude_ended.replace(exclude_ended);
+
+        self
+    }
+
+    /// Set the guild to get entitlements for.
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.fields.guild_id.replace(guild_id);
+
+        self
+    }
+
+    /// Set the SKUs to get entitlements for.
+    pub fn sku_ids(mut self, sku_ids: impl Iterator<Item = SkuId>) -> Self {
+        self.fields.sku_ids = sku_ids.map(|sku_id| sku_id.0).collect();
+
+        self
+    }
+
+    /// Set the user to get entitlements for.
+    pub fn user_id(mut self, user_id: UserId) -> Self {
+        self.fields.user_id.replace(user_id);
+
+        self
+    }
+
+    fn request(&self) -> Request {
+        Request::from_route(Route::GetEntitlements {
+            application_id: self.application_id.0,
+            exclude_ended: self.fields.exclude_ended,
+            guild_id: self.fields.guild_id.map(|id| id.0),
+            sku_ids: self.fields.sku_ids.clone(),
+            user_id: self.fields.user_id.map(|id| id.0),
+        })
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request();
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetEntitlements<'_>, Vec<Entitlement>);
+
+#[cfg(test)]
+mod tests {
+    use super::GetEntitlements;
+    use crate::{
+        client::Client,
+        request::Method,
+        routing::{Path, Route},
+    };
+    use twilight_model::{
+        id::{ApplicationId, GuildId},
+        oauth::id::SkuId,
+    };
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+        let actual = GetEntitlements::new(&client, ApplicationId(1))
+            .guild_id(GuildId(2))
+            .sku_ids(vec![SkuId(3), SkuId(4)].into_iter())
+            .request();
+
+        let route = Route::GetEntitlements {
+            application_id: 1,
+            exclude_ended: None,
+            guild_id: Some(2),
+            sku_ids: vec![3, 4],
+            user_id: None,
+        };
+
+        assert_eq!(Method::Get, actual.method);
+        assert_eq!(Path::ApplicationEntitlement(1), actual.path);
+        assert_eq!(route.display().to_string(), actual.path_str);
+    }
+}
@@ -1,11 +1,14 @@
 mod create_followup_message;
 mod create_global_command;
 mod create_guild_command;
+mod create_test_entitlement;
 mod delete_followup_message;
 mod delete_global_command;
 mod delete_guild_command;
 mod delete_original_response;
+mod delete_test_entitlement;
 mod get_command_permissions;
+mod get_entitlements;
 mod get_global_commands;
 mod get_guild_command_permissions;
 mod get_guild_commands;
@@ -21,14 +24,19 @@ mod update_guild_command;
 mod update_original_response;
 
 pub use self::{
-    create_followup_message::CreateFollowupMessage,
+    create_followup_message::{
+        CreateFollowupMessage, CreateFollowupMessageError, CreateFollowupMessageErrorType,
+    },
     create_global_command::CreateGlobalCommand,
     create_guild_command::CreateGuildCommand,
+    create_test_entitlement::{CreateTestEntitlement, TestEntitlementOwner},
     delete_followup_message::DeleteFollowupMessage,
     delete_global_command::DeleteGlobalCommand,
     delete_guild_command::DeleteGuildCommand,
     delete_original_response::DeleteOriginalResponse,
+    delete_test_entitlement::DeleteTestEntitlement,
     get_command_permissions::GetCommandPermissions,
+    get_entitlements::GetEntitlements,
     get_global_commands::GetGlobalCommands,
     get_guild_command_permissions::GetGuildCommandPermissions,
     get_guild_commands::GetGuildCommands,
@@ -14,6 +14,7 @@ mod private {
         channel::{
             invite::{CreateInvite, DeleteInvite},
             message::{DeleteMessage, DeleteMessages},
+            stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
             webhook::{
                 CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook,
                 UpdateWebhookMessage,
@@ -26,8 +27,9 @@ mod private {
             emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
-            role::{CreateRole, DeleteRole, UpdateRole},
-            CreateGuildChannel, CreateGuildPrune, UpdateGuild,
+            role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
+            CreateGuildChannel, CreateGuildPrune, UpdateCurrentUserNick, UpdateGuild,
+            UpdateGuildWelcomeScreen, UpdateGuildWidget,
         },
     };
 
@@ -64,6 +66,13 @@ mod private {
     impl<'a> Sealed for UpdateRole<'a> {}
     impl<'a> Sealed for UpdateGuild<'a> {}
     impl Sealed for UpdateWebhookMessage<'_> {}
+    impl Sealed for CreateStageInstance<'_> {}
+    impl Sealed for DeleteStageInstance<'_> {}
+    impl Sealed for UpdateStageInstance<'_> {}
+    impl Sealed for UpdateRolePositions<'_> {}
+    impl Sealed for UpdateCurrentUserNick<'_> {}
+    impl Sealed for UpdateGuildWelcomeScreen<'_> {}
+    impl Sealed for UpdateGuildWidget<'_> {}
 }
 
 impl AuditLogReasonError {
@@ -140,24 +149,30 @@ pub enum AuditLogReasonErrorType {
 #[cfg(test)]
 mod test {
     use super::AuditLogReason;
-    use crate::request::{
-        channel::{
-            invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
-            webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
-            CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
-            UpdateChannelPermissionConfigured,
-        },
-        guild::{
-            ban::{CreateBan, DeleteBan},
-            emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
-            integration::DeleteGuildIntegration,
-            member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
-            role::{CreateRole, DeleteRole, UpdateRole},
-            CreateGuildChannel, CreateGuildPrune, UpdateGuild,
+    use crate::{
+        request::{
+            channel::{
+                invite::{CreateInvite, DeleteInvite},
+                message::{DeleteMessage, DeleteMessages},
+                stage::{CreateStageInstance, DeleteStageInstance, UpdateStageInstance},
+                webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
+                CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin,
+                UpdateChannel, UpdateChannelPermissionConfigured,
+            },
+            guild::{
+                ban::{CreateBan, DeleteBan},
+                emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
+                integration::DeleteGuildIntegration,
+                member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
+                role::{CreateRole, DeleteRole, UpdateRole, UpdateRolePositions},
+                CreateGuildChannel, CreateGuildPrune, UpdateCurrentUserNick, UpdateGuild,
+                UpdateGuildWelcomeScreen, UpdateGuildWidget,
+            },
         },
+        Client,
     };
     use static_assertions::{assert_impl_all, assert_obj_safe};
+    use twilight_model::id::{ChannelId, GuildId, RoleId};
 
     assert_obj_safe!(AuditLogReason);
 
@@ -190,4 +205,54 @@ mod test {
     assert_impl_all!(DeleteRole<'_>: AuditLogReason);
     assert_impl_all!(UpdateRole<'_>: AuditLogReason);
     assert_impl_all!(UpdateGuild<'_>: AuditLogReason);
+    assert_impl_all!(CreateStageInstance<'_>: AuditLogReason);
+    assert_impl_all!(DeleteStageInstance<'_>: AuditLogReason);
+    assert_impl_all!(UpdateStageInstance<'_>: AuditLogReason);
+    assert_impl_all!(UpdateRolePositions<'_>: AuditLogReason);
+    assert_impl_all!(UpdateCurrentUserNick<'_>: AuditLogReason);
+    assert_impl_all!(UpdateGuildWelcomeScreen<'_>: AuditLogReason);
+    assert_impl_all!(UpdateGuildWidget<'_>: AuditLogReason);
+
+    #[test]
+    fn test_reason_header() {
+        let client = Client::new("foo");
+        let reason = "spring cleaning";
+        let requests = vec![
+            CreateStageInstance::new(&client, ChannelId(1), "topic")
+                .unwrap()
+                .reason(reason)
+                .unwrap()
+                .request(),
+            DeleteStageInstance::new(&client, ChannelId(1))
+                .reason(reason)
+                .unwrap()
+                .request(),
+            UpdateStageInstance::new(&client, ChannelId(1))
+                .reason(reason)
+                .unwrap()
+                .request(),
+            UpdateRolePositions::new(&client, GuildId(1), vec![(RoleId(2), 3)].into_iter())
+                .unwrap()
+                .reason(reason)
+                .unwrap()
+                .request(),
+            UpdateCurrentUserNick::new(&client, GuildId(1), "nick")
+                .reason(reason)
+                .unwrap()
+                .request(),
+            UpdateGuildWelcomeScreen::new(&client, GuildId(1))
+                .reason(reason)
+                .unwrap()
+                .request(),
+            UpdateGuildWidget::new(&client, GuildId(1))
+                .reason(reason)
+                .unwrap()
+                .request(),
+        ];
+
+        for request in requests {
+            let headers = request.unwrap().headers.expect("audit log reason is set");
+            assert_eq!("spring%20cleaning", headers["x-audit-log-reason"]);
+        }
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
 };
 use hyper::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 /// Builder to create a customized request.
 ///
@@ -73,6 +73,7 @@ impl RequestBuilder {
             method,
             path,
             path_str: Cow::Owned(path_and_query),
+            timeout: None,
             use_authorization_token: true,
         })
     }
@@ -123,6 +124,14 @@ impl RequestBuilder {
         Ok(self.body(bytes))
     }
 
+    /// Set the timeout for the request, overriding the client's timeout.
+    #[must_use = "request has not been fully built"]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.0.timeout = Some(timeout);
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -134,7 +143,7 @@ impl RequestBuilder {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request {
     /// The body of the request, if any.
     pub body: Option<Vec<u8>>,
@@ -148,6 +157,8 @@ pub struct Request {
     pub path: Path,
     /// The URI path to request.
     pub path_str: Cow<'static, str>,
+    /// Timeout of the request, overriding the client's timeout if set.
+    pub(crate) timeout: Option<Duration>,
     /// Whether to use the client's authorization token in the request.
     pub(crate) use_authorization_token: bool,
 }
@@ -173,6 +184,7 @@ impl Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -230,10 +242,16 @@ impl Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
 
+    /// Timeout of the request, overriding the client's timeout if set.
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
@@ -249,6 +267,7 @@ impl From<Route> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -263,6 +282,7 @@ impl From<(Vec<u8>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -277,6 +297,7 @@ impl From<(Form, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -291,6 +312,7 @@ impl From<(Vec<u8>, Form, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -305,6 +327,7 @@ impl From<(HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -319,6 +342,7 @@ impl From<(Vec<u8>, HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -333,6 +357,7 @@ impl From<(Form, HeaderMap<HeaderValue>, Route)> for Request {
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            timeout: None,
             use_authorization_token: true,
         }
     }
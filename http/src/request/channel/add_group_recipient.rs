@@ -0,0 +1,182 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{Pending, Request},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{ChannelId, UserId};
+
+/// Recipient cannot be added to the group as configured.
+#[derive(Debug)]
+pub struct AddGroupRecipientError {
+    kind: AddGroupRecipientErrorType,
+}
+
+impl AddGroupRecipientError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AddGroupRecipientErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AddGroupRecipientErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for AddGroupRecipientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            AddGroupRecipientErrorType::AccessTokenEmpty => {
+                f.write_str("the access token is empty")
+            }
+        }
+    }
+}
+
+impl Error for AddGroupRecipientError {}
+
+/// Type of [`AddGroupRecipientError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AddGroupRecipientErrorType {
+    /// The provided access token is empty.
+    AccessTokenEmpty,
+}
+
+#[derive(Serialize)]
+struct AddGroupRecipientFields {
+    access_token: String,
+    nick: String,
+}
+
+/// Add a user to a group DM.
+///
+/// An access token for the user with the `gdm.join` scope is required. Refer
+/// to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/resources/channel#group-dm-add-recipient
+pub struct AddGroupRecipient<'a> {
+    channel_id: ChannelId,
+    fields: AddGroupRecipientFields,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> AddGroupRecipient<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        user_id: UserId,
+        access_token: impl Into<String>,
+        nick: impl Into<String>,
+    ) -> Result<Self, AddGroupRecipientError> {
+        Self::_new(http, channel_id, user_id, access_token.into(), nick.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        user_id: UserId,
+        access_token: String,
+        nick: String,
+    ) -> Result<Self, AddGroupRecipientError> {
+        if access_token.is_empty() {
+            return Err(AddGroupRecipientError {
+                kind: AddGroupRecipientErrorType::AccessTokenEmpty,
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: AddGroupRecipientFields { access_token, nick },
+            fut: None,
+            http,
+            user_id,
+        })
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        let request = Request::builder(Route::AddGroupRecipient {
+            channel_id: self.channel_id.0,
+            user_id: self.user_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        Ok(request)
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(AddGroupRecipient<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::{AddGroupRecipient, AddGroupRecipientErrorType, AddGroupRecipientFields};
+    use crate::{client::Client, request::Request, routing::Route};
+    use twilight_model::id::{ChannelId, UserId};
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+        let builder = AddGroupRecipient::new(&client, ChannelId(1), UserId(2), "access", "nick")
+            .expect("access token is present");
+        let actual = builder.request().expect("failed to create request");
+
+        let body = AddGroupRecipientFields {
+            access_token: "access".to_owned(),
+            nick: "nick".to_owned(),
+        };
+        let route = Route::AddGroupRecipient {
+            channel_id: 1,
+            user_id: 2,
+        };
+        let expected = Request::builder(route)
+            .json(&body)
+            .expect("failed to serialize body")
+            .build();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!(expected.path, actual.path);
+    }
+
+    #[test]
+    fn test_access_token_empty() {
+        let client = Client::new("token");
+        let error = AddGroupRecipient::new(&client, ChannelId(1), UserId(2), "", "nick")
+            .err()
+            .expect("access token is empty");
+
+        assert!(matches!(
+            error.kind(),
+            AddGroupRecipientErrorType::AccessTokenEmpty
+        ));
+    }
+}
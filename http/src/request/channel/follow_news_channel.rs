@@ -48,3 +48,42 @@ impl<'a> FollowNewsChannel<'a> {
 }
 
 poll_req!(FollowNewsChannel<'_>, FollowedChannel);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use twilight_model::{
+        channel::FollowedChannel,
+        id::{ChannelId, WebhookId},
+    };
+
+    #[tokio::test]
+    async fn test_followed_channel_body() {
+        let server = TestServer::respond(json_response(
+            "200 OK",
+            r#"{"channel_id":"1","webhook_id":"3"}"#,
+        ))
+        .await;
+
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let followed = client
+            .follow_news_channel(ChannelId(1), ChannelId(2))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            FollowedChannel {
+                channel_id: ChannelId(1),
+                webhook_id: WebhookId(3),
+            },
+            followed
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     client::Client,
-    error::Error as HttpError,
+    error::{Error as HttpError, ErrorType},
     request::{
         multipart::Form,
-        validate::{self, EmbedValidationError},
+        validate::{self, ComponentValidationError, EmbedValidationError},
         Pending, Request,
     },
     routing::Route,
@@ -12,11 +12,13 @@ use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
 };
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
-        message::{AllowedMentions, MessageReference},
+        message::{sticker::StickerId, AllowedMentions, MessageReference},
         Message,
     },
     id::{ChannelId, MessageId},
@@ -48,6 +50,13 @@ impl CreateMessageError {
         (self.kind, self.source)
     }
 
+    fn components(source: ComponentValidationError) -> Self {
+        Self {
+            kind: CreateMessageErrorType::ComponentsInvalid,
+            source: Some(Box::new(source)),
+        }
+    }
+
     fn embed(source: EmbedValidationError, embed: Embed, idx: Option<usize>) -> Self {
         Self {
             kind: CreateMessageErrorType::EmbedTooLarge {
@@ -62,6 +71,12 @@ impl CreateMessageError {
 impl Display for CreateMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            CreateMessageErrorType::AttachmentMissing { .. } => {
+                f.write_str("an embed references a file that isn't attached")
+            }
+            CreateMessageErrorType::ComponentsInvalid => {
+                f.write_str("the message components are invalid")
+            }
             CreateMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("the message content is invalid")
             }
@@ -75,6 +90,9 @@ impl Display for CreateMessageError {
                     f.write_str("the embed's contents are too long")
                 }
             }
+            CreateMessageErrorType::StickersInvalid { .. } => {
+                f.write_str("the amount of stickers is invalid")
+            }
         }
     }
 }
@@ -91,6 +109,20 @@ impl Error for CreateMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum CreateMessageErrorType {
+    /// Returned when an embed references a file via an `attachment://` URL
+    /// that isn't attached.
+    ///
+    /// This is checked when the request is sent, which then fails with an
+    /// [`ErrorType::BuildingRequest`] error whose source is this error.
+    AttachmentMissing {
+        /// Name of the referenced file.
+        filename: String,
+    },
+    /// Returned when the components are invalid.
+    ///
+    /// The source of the error is a [`ComponentValidationError`] describing
+    /// the problem.
+    ComponentsInvalid,
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -103,10 +135,17 @@ pub enum CreateMessageErrorType {
         /// Index of the embed, if there is any.
         idx: Option<usize>,
     },
+    /// Returned when there are less than 1 or more than 3 stickers.
+    StickersInvalid {
+        /// Provided sticker IDs.
+        sticker_ids: Vec<StickerId>,
+    },
 }
 
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -120,6 +159,8 @@ pub(crate) struct CreateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    sticker_ids: Option<Vec<StickerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
 }
 
@@ -149,6 +190,7 @@ pub struct CreateMessage<'a> {
     files: Vec<(String, Vec<u8>)>,
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> CreateMessage<'a> {
@@ -162,16 +204,41 @@ impl<'a> CreateMessage<'a> {
             files: Vec::new(),
             fut: None,
             http,
+            timeout: None,
         }
     }
 
     /// Specify the [`AllowedMentions`] for the message.
-    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
-        self.fields.allowed_mentions.replace(allowed_mentions);
+    ///
+    /// Overrides the client's default allowed mentions. Accepts either
+    /// [`AllowedMentions`] or an [`AllowedMentionsBuilder`].
+    ///
+    /// [`AllowedMentionsBuilder`]: twilight_model::channel::message::allowed_mentions::AllowedMentionsBuilder
+    pub fn allowed_mentions(mut self, allowed_mentions: impl Into<AllowedMentions>) -> Self {
+        self.fields
+            .allowed_mentions
+            .replace(allowed_mentions.into());
 
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Top-level components must be action rows, which may only contain
+    /// buttons and select menus. A message may have at most 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateMessageErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    pub fn components(mut self, components: &[Component]) -> Result<Self, CreateMessageError> {
+        validate::components(components).map_err(CreateMessageError::components)?;
+
+        self.fields.components.replace(components.to_vec());
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// The maximum length is 2000 UTF-16 characters.
@@ -309,6 +376,12 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Specify the ID of another message to create a reply to.
+    ///
+    /// Whether the author of the other message is mentioned is controlled by
+    /// the allowed mentions of the message, such as via
+    /// [`AllowedMentionsBuilder::replied_user`].
+    ///
+    /// [`AllowedMentionsBuilder::replied_user`]: twilight_model::channel::message::allowed_mentions::AllowedMentionsBuilder::replied_user
     pub fn reply(mut self, other: MessageId) -> Self {
         let channel_id = self.channel_id;
 
@@ -329,6 +402,27 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the IDs of up to 3 stickers to send in the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateMessageErrorType::StickersInvalid`] error type if
+    /// there are less than 1 or more than 3 stickers.
+    pub fn sticker_ids(mut self, sticker_ids: &[StickerId]) -> Result<Self, CreateMessageError> {
+        if !validate::sticker_ids(sticker_ids.len()) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::StickersInvalid {
+                    sticker_ids: sticker_ids.to_vec(),
+                },
+                source: None,
+            });
+        }
+
+        self.fields.sticker_ids.replace(sticker_ids.to_vec());
+
+        Ok(self)
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
@@ -336,7 +430,35 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Check that every file referenced by an embed via an `attachment://` URL,
+    /// such as an image or thumbnail, is attached.
+    ///
+    /// Embed images referencing a file that isn't attached silently don't
+    /// render, so this is checked when the request is sent.
+    fn validate_attachments(&self) -> Result<(), CreateMessageError> {
+        if let Some(filename) = validate::embed_attachments(&self.fields.embeds, &self.files) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::AttachmentMissing { filename },
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Set the timeout for this request, overriding the client's timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout.replace(timeout);
+
+        self
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
+        self.validate_attachments().map_err(|source| HttpError {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
         let mut request = Request::builder(Route::CreateMessage {
             channel_id: self.channel_id.0,
         });
@@ -360,6 +482,10 @@ impl<'a> CreateMessage<'a> {
             request = request.json(&self.fields)?;
         }
 
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
         self.fut
             .replace(Box::pin(self.http.request(request.build())));
 
@@ -368,3 +494,221 @@ impl<'a> CreateMessage<'a> {
 }
 
 poll_req!(CreateMessage<'_>, Message);
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateMessage, CreateMessageError, CreateMessageErrorType};
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::validate::tests::{base_embed, button, row},
+        test_server::{TestServer, NO_CONTENT},
+    };
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use twilight_model::{
+        channel::{
+            embed::EmbedImage,
+            message::{allowed_mentions::AllowedMentionsBuilder, sticker::StickerId},
+        },
+        id::{ChannelId, MessageId, RoleId, UserId},
+    };
+
+    #[test]
+    fn test_allowed_mentions() {
+        let client = Client::builder()
+            .default_allowed_mentions(AllowedMentionsBuilder::new().build())
+            .build();
+
+        let default = CreateMessage::new(&client, ChannelId(1));
+        assert_eq!(
+            json!({ "parse": [] }),
+            serde_json::to_value(&default.fields).unwrap()["allowed_mentions"],
+        );
+
+        let custom = CreateMessage::new(&client, ChannelId(1)).allowed_mentions(
+            AllowedMentionsBuilder::new()
+                .user_ids(vec![UserId(3)])
+                .role_ids(vec![RoleId(4)]),
+        );
+        assert_eq!(
+            json!({ "parse": [], "users": ["3"], "roles": ["4"] }),
+            serde_json::to_value(&custom.fields).unwrap()["allowed_mentions"],
+        );
+    }
+
+    #[test]
+    fn test_reply() {
+        let client = Client::new("token");
+
+        let reply = CreateMessage::new(&client, ChannelId(1)).reply(MessageId(2));
+        assert_eq!(
+            json!({ "channel_id": "1", "message_id": "2" }),
+            serde_json::to_value(&reply.fields).unwrap()["message_reference"],
+        );
+
+        let reply = CreateMessage::new(&client, ChannelId(1))
+            .fail_if_not_exists()
+            .reply(MessageId(2));
+        assert_eq!(
+            json!({ "channel_id": "1", "message_id": "2", "fail_if_not_exists": true }),
+            serde_json::to_value(&reply.fields).unwrap()["message_reference"],
+        );
+    }
+
+    #[test]
+    fn test_sticker_ids() {
+        let client = Client::new("token");
+
+        let builder = CreateMessage::new(&client, ChannelId(1))
+            .sticker_ids(&[StickerId(2), StickerId(3)])
+            .unwrap();
+        assert_eq!(
+            json!(["2", "3"]),
+            serde_json::to_value(&builder.fields).unwrap()["sticker_ids"],
+        );
+
+        let sticker_ids = [StickerId(2), StickerId(3), StickerId(4), StickerId(5)];
+        let error = CreateMessage::new(&client, ChannelId(1))
+            .sticker_ids(&sticker_ids)
+            .err()
+            .expect("too many stickers");
+        assert!(matches!(
+            error.kind(),
+            CreateMessageErrorType::StickersInvalid { sticker_ids: provided } if provided.len() == 4
+        ));
+
+        assert!(CreateMessage::new(&client, ChannelId(1))
+            .sticker_ids(&[])
+            .is_err());
+    }
+
+    #[test]
+    fn test_components() {
+        let client = Client::new("token");
+        let components = [row(vec![button("yes"), button("no")])];
+
+        let builder = CreateMessage::new(&client, ChannelId(1))
+            .components(&components)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&components).unwrap(),
+            serde_json::to_value(&builder.fields).unwrap()["components"],
+        );
+
+        let error = CreateMessage::new(&client, ChannelId(1))
+            .components(&vec![row(vec![button("yes")]); 6])
+            .err()
+            .expect("too many action rows");
+        assert!(matches!(
+            error.kind(),
+            CreateMessageErrorType::ComponentsInvalid
+        ));
+    }
+
+    /// Send the multipart body of a message with files to a server that
+    /// responds with an empty response, returning the built body.
+    async fn multipart_body(
+        configure: impl FnOnce(CreateMessage<'_>) -> CreateMessage<'_>,
+    ) -> String {
+        let server = TestServer::respond(NO_CONTENT).await;
+
+        let form = Arc::new(Mutex::new(None));
+        let client = {
+            let form = Arc::clone(&form);
+
+            Client::builder()
+                .proxy(server.addr().to_string(), true)
+                .ratelimiter(None)
+                .request_interceptor(move |request| {
+                    form.lock().unwrap().replace(request.form.clone());
+                })
+                .build()
+        };
+
+        // The empty response can't be deserialized into a message.
+        assert!(configure(CreateMessage::new(&client, ChannelId(1)))
+            .await
+            .is_err());
+
+        let form = form
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap()
+            .expect("request is multipart");
+
+        String::from_utf8(form.build()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_files() {
+        let body = multipart_body(|builder| {
+            builder
+                .content("hello")
+                .unwrap()
+                .files(vec![("a.png", vec![1, 2, 3]), ("b.txt", b"text".to_vec())])
+        })
+        .await;
+
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"0\"; filename=\"a.png\"\r\n\r\n\u{1}\u{2}\u{3}"
+        ));
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"1\"; filename=\"b.txt\"\r\n\r\ntext"
+        ));
+        assert!(body.contains(r#""content":"hello""#));
+    }
+
+    #[tokio::test]
+    async fn test_files_payload_json() {
+        let body = multipart_body(|builder| {
+            builder
+                .content("ignored")
+                .unwrap()
+                .file("a.png", vec![1])
+                .payload_json(r#"{"content":"payload"}"#)
+        })
+        .await;
+
+        assert!(body.contains(r#"name="0"; filename="a.png""#));
+        assert!(body.contains(r#"{"content":"payload"}"#));
+        assert!(!body.contains("ignored"));
+    }
+
+    #[tokio::test]
+    async fn test_attachment_missing() {
+        let client = Client::new("token");
+        let mut embed = base_embed();
+        embed.image = Some(EmbedImage {
+            height: None,
+            proxy_url: None,
+            url: Some("attachment://image.png".to_owned()),
+            width: None,
+        });
+
+        let error = CreateMessage::new(&client, ChannelId(1))
+            .embed(embed.clone())
+            .unwrap()
+            .file("other.png", vec![1, 2, 3])
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::BuildingRequest));
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<CreateMessageError>()
+            .unwrap();
+        assert!(matches!(
+            source.kind(),
+            CreateMessageErrorType::AttachmentMissing { filename } if filename == "image.png"
+        ));
+
+        assert!(CreateMessage::new(&client, ChannelId(1))
+            .embed(embed)
+            .unwrap()
+            .file("image.png", vec![1, 2, 3])
+            .validate_attachments()
+            .is_ok());
+    }
+}
@@ -0,0 +1,185 @@
+use super::{DeleteMessage, DeleteMessages};
+use crate::{client::Client, error::Error};
+use futures_util::future::{self, JoinAll};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+use twilight_model::id::{self, ChannelId, MessageId};
+
+/// Maximum age of messages that can be bulk deleted.
+const BULK_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Maximum number of messages that can be deleted in one bulk delete.
+const BULK_MAX_COUNT: usize = 100;
+
+/// A request made to delete some of the messages.
+enum Delete<'a> {
+    Bulk(DeleteMessages<'a>),
+    Single(DeleteMessage<'a>),
+}
+
+impl Future for Delete<'_> {
+    type Output = Result<(), Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.get_mut() {
+            Self::Bulk(request) => Pin::new(request).poll(cx),
+            Self::Single(request) => Pin::new(request).poll(cx),
+        }
+    }
+}
+
+/// Split message IDs into batches that can be bulk deleted and IDs of messages
+/// that need to be deleted individually.
+///
+/// Messages older than two weeks can't be bulk deleted, and a bulk delete
+/// needs between 2 and 100 messages.
+fn partition(message_ids: &[MessageId], now: SystemTime) -> (Vec<Vec<MessageId>>, Vec<MessageId>) {
+    let (recent, mut single): (Vec<MessageId>, Vec<MessageId>) =
+        message_ids.iter().partition(|message_id| {
+            now.duration_since(id::created_at(message_id.0))
+                .map_or(true, |age| age < BULK_MAX_AGE)
+        });
+
+    let mut bulk = Vec::new();
+
+    for chunk in recent.chunks(BULK_MAX_COUNT) {
+        if chunk.len() == 1 {
+            single.extend_from_slice(chunk);
+        } else {
+            bulk.push(chunk.to_vec());
+        }
+    }
+
+    (bulk, single)
+}
+
+/// Delete messages by [`ChannelId`] and their [`MessageId`]s, regardless of
+/// their age or count.
+///
+/// Messages younger than two weeks are deleted via bulk deletes of up to 100
+/// messages. Older messages, as well as a single leftover message, are deleted
+/// individually. All requests are made concurrently and still respect the
+/// client's ratelimiter.
+///
+/// Resolves to the first error of any of the requests, if there is one, after
+/// all requests have completed.
+pub struct DeleteMessagesAuto<'a> {
+    fut: JoinAll<Delete<'a>>,
+}
+
+impl<'a> DeleteMessagesAuto<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, message_ids: &[MessageId]) -> Self {
+        let (bulk, single) = partition(message_ids, SystemTime::now());
+
+        let requests = bulk
+            .into_iter()
+            .map(|ids| Delete::Bulk(DeleteMessages::new(http, channel_id, ids)))
+            .chain(single.into_iter().map(|message_id| {
+                Delete::Single(DeleteMessage::new(http, channel_id, message_id))
+            }));
+
+        Self {
+            fut: future::join_all(requests),
+        }
+    }
+}
+
+impl Future for DeleteMessagesAuto<'_> {
+    type Output = Result<(), Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.fut)
+            .poll(cx)
+            .map(|results| results.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::partition;
+    use crate::{
+        test_server::{TestServer, NO_CONTENT},
+        Client,
+    };
+    use std::{
+        convert::TryFrom,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use twilight_model::id::{ChannelId, MessageId, DISCORD_EPOCH};
+
+    /// Create a message ID from the time it was created at.
+    fn message_id(created_at: SystemTime, increment: u64) -> MessageId {
+        let millis = created_at.duration_since(UNIX_EPOCH).unwrap().as_millis();
+        let millis = u64::try_from(millis).unwrap();
+
+        MessageId(((millis - DISCORD_EPOCH) << 22) | increment)
+    }
+
+    #[test]
+    fn test_partition() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let recent = (0..3)
+            .map(|idx| message_id(now - day, idx))
+            .collect::<Vec<_>>();
+        let old = (0..2)
+            .map(|idx| message_id(now - day * 15, idx))
+            .collect::<Vec<_>>();
+        let ids = [old[0], recent[0], recent[1], old[1], recent[2]];
+
+        let (bulk, single) = partition(&ids, now);
+        assert_eq!(vec![recent.clone()], bulk);
+        assert_eq!(old, single);
+
+        // A single recent message can't be bulk deleted.
+        let (bulk, single) = partition(&[old[0], recent[0]], now);
+        assert!(bulk.is_empty());
+        assert_eq!(vec![old[0], recent[0]], single);
+
+        let many = (0..201)
+            .map(|idx| message_id(now - day, idx))
+            .collect::<Vec<_>>();
+        let (bulk, single) = partition(&many, now);
+        assert_eq!(
+            vec![100, 100],
+            bulk.iter().map(Vec::len).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![many[200]], single);
+    }
+
+    #[tokio::test]
+    async fn test_delete_messages_auto() {
+        let server = TestServer::respond(NO_CONTENT).await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let now = SystemTime::now();
+        let recent = message_id(now - Duration::from_secs(60), 0);
+        let ids = [
+            recent,
+            MessageId(recent.0 + 1),
+            MessageId(5),
+            message_id(now - Duration::from_secs(15 * 24 * 60 * 60), 0),
+        ];
+        client
+            .delete_messages_auto(ChannelId(1), &ids)
+            .await
+            .unwrap();
+
+        let mut requests = server.routes();
+        requests.sort();
+        let mut expected = vec![
+            format!("DELETE /api/v8/channels/1/messages/{}", ids[2]),
+            format!("DELETE /api/v8/channels/1/messages/{}", ids[3]),
+            "POST /api/v8/channels/1/messages/bulk-delete".to_owned(),
+        ];
+        expected.sort();
+        assert_eq!(expected, requests);
+    }
+}
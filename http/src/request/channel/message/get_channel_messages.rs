@@ -5,6 +5,7 @@ use crate::{
     request::{validate, Pending, Request},
     routing::Route,
 };
+use futures_util::stream::{self, Stream, TryStreamExt};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -175,6 +176,68 @@ impl<'a> GetChannelMessages<'a> {
         Ok(self)
     }
 
+    /// Stream all messages in the channel, from newest to oldest.
+    ///
+    /// Pages of [`limit`] messages, or 100 if unset, are requested one after
+    /// another, each starting before the oldest message of the previous page.
+    /// The stream ends after a page with fewer messages than the limit.
+    ///
+    /// # Examples
+    ///
+    /// Print the content of every message in channel `123`:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::ChannelId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let mut messages = Box::pin(client.channel_messages(ChannelId(123)).into_stream());
+    ///
+    /// while let Some(message) = messages.try_next().await? {
+    ///     println!("{}", message.content);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`limit`]: Self::limit
+    pub fn into_stream(self) -> impl Stream<Item = Result<Message, HttpError>> + 'a {
+        let channel_id = self.channel_id;
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(100);
+
+        // The state is the message to get messages before, or `None` once
+        // the last page has been received.
+        stream::try_unfold(Some(None), move |state| async move {
+            let before = match state {
+                Some(before) => before,
+                None => return Ok(None),
+            };
+
+            let page = GetChannelMessagesConfigured::new(
+                http,
+                channel_id,
+                None,
+                None,
+                before,
+                Some(limit),
+            )
+            .await?;
+
+            let next = match page.last() {
+                Some(last) if page.len() as u64 >= limit => Some(Some(last.id)),
+                _ => None,
+            };
+
+            Ok(Some((page, next)))
+        })
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetMessages {
             after: None,
@@ -191,3 +254,61 @@ impl<'a> GetChannelMessages<'a> {
 }
 
 poll_req!(GetChannelMessages<'_>, Vec<Message>);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use futures_util::stream::TryStreamExt;
+    use twilight_model::id::{ChannelId, MessageId};
+
+    fn message_json(id: u64) -> String {
+        format!(
+            r#"{{"attachments":[],"author":{{"avatar":null,"discriminator":"0001","id":"3","username":"test"}},"channel_id":"1","content":"ping","edited_timestamp":null,"embeds":[],"id":"{}","mention_everyone":false,"mention_roles":[],"mentions":[],"pinned":false,"timestamp":"2021-01-01T00:00:00.000000+00:00","tts":false,"type":0}}"#,
+            id
+        )
+    }
+
+    #[tokio::test]
+    async fn test_into_stream() {
+        // Respond with a full page of two messages and then with a page of one
+        // message, newest first.
+        let server = TestServer::start(|index, _| {
+            let page = if index == 0 {
+                format!("[{},{}]", message_json(5), message_json(4))
+            } else {
+                format!("[{}]", message_json(3))
+            };
+
+            Some(json_response("200 OK", &page))
+        })
+        .await;
+
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let messages = client
+            .channel_messages(ChannelId(1))
+            .limit(2)
+            .unwrap()
+            .into_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let ids = messages
+            .iter()
+            .map(|message| message.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![MessageId(5), MessageId(4), MessageId(3)], ids);
+
+        let requests = server.routes();
+        assert_eq!(2, requests.len());
+        assert!(!requests[0].contains("before="));
+        assert!(requests[1].contains("before=4"));
+    }
+}
@@ -0,0 +1,117 @@
+use super::GetMessage;
+use crate::{client::Client, error::Error};
+use futures_util::future::{self, JoinAll};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use twilight_model::{
+    channel::Message,
+    id::{ChannelId, MessageId},
+};
+
+/// Get multiple messages by [`ChannelId`] and their [`MessageId`]s.
+///
+/// Discord doesn't provide an endpoint to get multiple specific messages at
+/// once, so this issues a [`GetMessage`] request for every message ID
+/// concurrently. Each request still respects the client's ratelimiter.
+///
+/// Resolves to the result of every request, in the same order as the provided
+/// message IDs.
+pub struct GetMessages<'a> {
+    fut: JoinAll<GetMessage<'a>>,
+}
+
+impl<'a> GetMessages<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, message_ids: &[MessageId]) -> Self {
+        Self {
+            fut: future::join_all(
+                message_ids
+                    .iter()
+                    .map(|message_id| GetMessage::new(http, channel_id, *message_id)),
+            ),
+        }
+    }
+}
+
+impl Future for GetMessages<'_> {
+    type Output = Vec<Result<Option<Message>, Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.fut).poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetMessages;
+    use crate::{
+        test_server::{json_response, request_path, TestServer},
+        Client,
+    };
+    use twilight_model::id::{ChannelId, MessageId};
+
+    const MESSAGE: &str = r#"{
+        "attachments": [],
+        "author": {
+            "avatar": null,
+            "bot": false,
+            "discriminator": "0001",
+            "id": "3",
+            "username": "test"
+        },
+        "channel_id": "1",
+        "content": "ping",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": "MESSAGE_ID",
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00.000000+00:00",
+        "tts": false,
+        "type": 0
+    }"#;
+
+    /// Serve a message for every received request, using the last segment of
+    /// the requested path as its ID.
+    async fn serve() -> TestServer {
+        TestServer::start(|_, request| {
+            let message_id = request_path(request).rsplit('/').next().unwrap();
+            let body = MESSAGE.replace("MESSAGE_ID", message_id);
+
+            Some(json_response("200 OK", &body))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_get_messages() {
+        let server = serve().await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let results = client
+            .messages(ChannelId(1), &[MessageId(5), MessageId(6)])
+            .await;
+
+        assert_eq!(2, results.len());
+        let ids = results
+            .into_iter()
+            .map(|result| result.unwrap().unwrap().id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![MessageId(5), MessageId(6)], ids);
+    }
+
+    #[tokio::test]
+    async fn test_get_messages_empty() {
+        let client = Client::new("token");
+        let results = GetMessages::new(&client, ChannelId(1), &[]).await;
+
+        assert!(results.is_empty());
+    }
+}
@@ -6,13 +6,17 @@ pub mod update_message;
 
 mod delete_message;
 mod delete_messages;
+mod delete_messages_auto;
 mod get_message;
+mod get_messages;
 
 pub use self::{
     create_message::CreateMessage, crosspost_message::CrosspostMessage,
     delete_message::DeleteMessage, delete_messages::DeleteMessages,
-    get_channel_messages::GetChannelMessages,
+    delete_messages_auto::DeleteMessagesAuto, get_channel_messages::GetChannelMessages,
     get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
-    update_message::UpdateMessage,
+    get_messages::GetMessages, update_message::UpdateMessage,
+};
+pub use super::super::validate::{
+    ComponentValidationError, ComponentValidationErrorType, EmbedValidationError,
 };
-pub use super::super::validate::EmbedValidationError;
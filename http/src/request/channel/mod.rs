@@ -1,3 +1,4 @@
+pub mod add_group_recipient;
 pub mod invite;
 pub mod message;
 pub mod reaction;
@@ -14,14 +15,17 @@ mod delete_pin;
 mod follow_news_channel;
 mod get_channel;
 mod get_pins;
+mod remove_group_recipient;
 mod update_channel_permission;
 mod update_channel_permission_configured;
 
 pub use self::{
-    create_pin::CreatePin, create_typing_trigger::CreateTypingTrigger,
-    delete_channel::DeleteChannel, delete_channel_permission::DeleteChannelPermission,
+    add_group_recipient::AddGroupRecipient, create_pin::CreatePin,
+    create_typing_trigger::CreateTypingTrigger, delete_channel::DeleteChannel,
+    delete_channel_permission::DeleteChannelPermission,
     delete_channel_permission_configured::DeleteChannelPermissionConfigured, delete_pin::DeletePin,
     follow_news_channel::FollowNewsChannel, get_channel::GetChannel, get_pins::GetPins,
-    update_channel::UpdateChannel, update_channel_permission::UpdateChannelPermission,
+    remove_group_recipient::RemoveGroupRecipient, update_channel::UpdateChannel,
+    update_channel_permission::UpdateChannelPermission,
     update_channel_permission_configured::UpdateChannelPermissionConfigured,
 };
@@ -126,14 +126,18 @@ impl<'a> GetReactions<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetReactionUsers {
+    fn request(&self) -> Request {
+        Request::from_route(Route::GetReactionUsers {
             after: self.fields.after.map(|x| x.0),
             channel_id: self.channel_id.0,
             emoji: self.emoji.display().to_string(),
             limit: self.fields.limit,
             message_id: self.message_id.0,
-        });
+        })
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request();
 
         self.fut.replace(Box::pin(self.http.request(request)));
 
@@ -142,3 +146,49 @@ impl<'a> GetReactions<'a> {
 }
 
 poll_req!(GetReactions<'_>, Vec<User>);
+
+#[cfg(test)]
+mod tests {
+    use super::{GetReactions, GetReactionsErrorType};
+    use crate::{request::channel::reaction::RequestReactionType, Client};
+    use twilight_model::id::{ChannelId, MessageId, UserId};
+
+    fn builder(client: &Client) -> GetReactions<'_> {
+        let emoji = RequestReactionType::Unicode {
+            name: "a".to_owned(),
+        };
+
+        GetReactions::new(client, ChannelId(1), MessageId(2), emoji)
+    }
+
+    #[test]
+    fn test_pagination() {
+        let client = Client::new("foo");
+        let request = builder(&client)
+            .after(UserId(3))
+            .limit(100)
+            .unwrap()
+            .request();
+
+        assert_eq!(
+            "channels/1/messages/2/reactions/a?after=3&limit=100",
+            request.path_str
+        );
+    }
+
+    #[test]
+    fn test_limit_invalid() {
+        let client = Client::new("foo");
+
+        for limit in [0, 101].iter().copied() {
+            let error = builder(&client)
+                .limit(limit)
+                .err()
+                .expect("limit must be between 1 and 100");
+            assert!(matches!(
+                error.kind(),
+                GetReactionsErrorType::LimitInvalid { limit: l } if *l == limit
+            ));
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::id::{ChannelId, UserId};
+
+/// Remove a user from a group DM.
+pub struct RemoveGroupRecipient<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> RemoveGroupRecipient<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, user_id: UserId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            user_id,
+        }
+    }
+
+    fn request(&self) -> Request {
+        Request::from_route(Route::RemoveGroupRecipient {
+            channel_id: self.channel_id.0,
+            user_id: self.user_id.0,
+        })
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request();
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RemoveGroupRecipient<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::RemoveGroupRecipient;
+    use crate::{
+        client::Client,
+        request::Method,
+        routing::{Path, Route},
+    };
+    use twilight_model::id::{ChannelId, UserId};
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+        let actual = RemoveGroupRecipient::new(&client, ChannelId(1), UserId(2)).request();
+
+        let route = Route::RemoveGroupRecipient {
+            channel_id: 1,
+            user_id: 2,
+        };
+
+        assert_eq!(Method::Delete, actual.method);
+        assert_eq!(Path::ChannelsIdRecipients(1), actual.path);
+        assert_eq!(route.display().to_string(), actual.path_str);
+        assert!(actual.body.is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{self, validate, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
@@ -83,6 +83,7 @@ pub struct CreateStageInstance<'a> {
     fields: CreateStageInstanceFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> CreateStageInstance<'a> {
@@ -114,6 +115,7 @@ impl<'a> CreateStageInstance<'a> {
             },
             fut: None,
             http,
+            reason: None,
         })
     }
 
@@ -124,10 +126,18 @@ impl<'a> CreateStageInstance<'a> {
         self
     }
 
+    pub(crate) fn request(&self) -> Result<Request, HttpError> {
+        let mut request = Request::builder(Route::CreateStageInstance).json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::CreateStageInstance)
-            .json(&self.fields)?
-            .build();
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.verify(request)));
 
@@ -135,4 +145,13 @@ impl<'a> CreateStageInstance<'a> {
     }
 }
 
+impl<'a> AuditLogReason for CreateStageInstance<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(CreateStageInstance<'_>, ());
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
 use twilight_model::id::ChannelId;
@@ -13,6 +13,7 @@ pub struct DeleteStageInstance<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> DeleteStageInstance<'a> {
@@ -21,18 +22,38 @@ impl<'a> DeleteStageInstance<'a> {
             channel_id,
             fut: None,
             http,
+            reason: None,
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteStageInstance {
+    pub(crate) fn request(&self) -> Result<Request, Error> {
+        let mut request = Request::builder(Route::DeleteStageInstance {
             channel_id: self.channel_id.0,
         });
 
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
+impl<'a> AuditLogReason for DeleteStageInstance<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(DeleteStageInstance<'_>, ());
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{self, validate, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
@@ -84,6 +84,7 @@ pub struct UpdateStageInstance<'a> {
     fields: UpdateStageInstanceFields,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> UpdateStageInstance<'a> {
@@ -93,6 +94,7 @@ impl<'a> UpdateStageInstance<'a> {
             fields: UpdateStageInstanceFields::default(),
             fut: None,
             http,
+            reason: None,
         }
     }
 
@@ -121,12 +123,21 @@ impl<'a> UpdateStageInstance<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::UpdateStageInstance {
+    pub(crate) fn request(&self) -> Result<Request, HttpError> {
+        let mut request = Request::builder(Route::UpdateStageInstance {
             channel_id: self.channel_id.0,
         })
-        .json(&self.fields)?
-        .build();
+        .json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.verify(request)));
 
@@ -134,4 +145,13 @@ impl<'a> UpdateStageInstance<'a> {
     }
 }
 
+impl<'a> AuditLogReason for UpdateStageInstance<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(UpdateStageInstance<'_>, ());
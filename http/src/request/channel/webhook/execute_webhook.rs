@@ -1,26 +1,123 @@
 use crate::{
     client::Client,
-    error::{Error, ErrorType},
-    request::{Form, PendingOption, Request},
+    error::{Error as HttpError, ErrorType},
+    request::{
+        validate::{self, ComponentValidationError},
+        Form, PendingOption, Request,
+    },
     routing::Route,
 };
 use hyper::StatusCode;
 use serde::Serialize;
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Message},
     id::WebhookId,
 };
 
+/// The error created when the webhook can not be executed as configured.
+#[derive(Debug)]
+pub struct ExecuteWebhookError {
+    kind: ExecuteWebhookErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ExecuteWebhookError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ExecuteWebhookErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ExecuteWebhookErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    fn components(source: ComponentValidationError) -> Self {
+        Self {
+            kind: ExecuteWebhookErrorType::ComponentsInvalid,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ExecuteWebhookErrorType::AttachmentMissing { .. } => {
+                f.write_str("an embed references a file that isn't attached")
+            }
+            ExecuteWebhookErrorType::ComponentsInvalid => {
+                f.write_str("the message components are invalid")
+            }
+            ExecuteWebhookErrorType::UsernameInvalid { .. } => {
+                f.write_str("the webhook username is invalid")
+            }
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ExecuteWebhookError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExecuteWebhookErrorType {
+    /// Returned when an embed references a file via an `attachment://` URL
+    /// that isn't attached.
+    ///
+    /// This is checked when the request is sent, which then fails with an
+    /// [`ErrorType::BuildingRequest`] error whose source is this error.
+    AttachmentMissing {
+        /// Name of the referenced file.
+        filename: String,
+    },
+    /// Returned when the components are invalid.
+    ///
+    /// The source of the error is a [`ComponentValidationError`] describing
+    /// the problem.
+    ComponentsInvalid,
+    /// The username is either empty, more than 80 characters, or contains
+    /// "discord".
+    UsernameInvalid {
+        /// Provided username.
+        username: String,
+    },
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -73,7 +170,10 @@ pub struct ExecuteWebhook<'a> {
 impl<'a> ExecuteWebhook<'a> {
     pub(crate) fn new(http: &'a Client, webhook_id: WebhookId, token: impl Into<String>) -> Self {
         Self {
-            fields: ExecuteWebhookFields::default(),
+            fields: ExecuteWebhookFields {
+                allowed_mentions: http.default_allowed_mentions(),
+                ..ExecuteWebhookFields::default()
+            },
             files: Vec::new(),
             fut: None,
             http,
@@ -83,8 +183,15 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// Specify the [`AllowedMentions`] for the webhook message.
-    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
-        self.fields.allowed_mentions.replace(allowed_mentions);
+    ///
+    /// Overrides the client's default allowed mentions. Accepts either
+    /// [`AllowedMentions`] or an [`AllowedMentionsBuilder`].
+    ///
+    /// [`AllowedMentionsBuilder`]: twilight_model::channel::message::allowed_mentions::AllowedMentionsBuilder
+    pub fn allowed_mentions(mut self, allowed_mentions: impl Into<AllowedMentions>) -> Self {
+        self.fields
+            .allowed_mentions
+            .replace(allowed_mentions.into());
 
         self
     }
@@ -96,6 +203,23 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Set the list of [`Component`]s of the webhook's message.
+    ///
+    /// Top-level components must be action rows, which may only contain
+    /// buttons and select menus. A message may have at most 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExecuteWebhookErrorType::ComponentsInvalid`] error type if
+    /// the components are invalid.
+    pub fn components(mut self, components: &[Component]) -> Result<Self, ExecuteWebhookError> {
+        validate::components(components).map_err(ExecuteWebhookError::components)?;
+
+        self.fields.components.replace(components.to_vec());
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints, same as a message.
@@ -197,10 +321,48 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// Specify the username of the webhook's message.
-    pub fn username(mut self, username: impl Into<String>) -> Self {
-        self.fields.username.replace(username.into());
+    ///
+    /// The minimum length is 1 character and the maximum is 80 characters.
+    /// The username may not contain "discord".
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExecuteWebhookErrorType::UsernameInvalid`] error type if
+    /// the username length is too short or too long, or if it contains
+    /// "discord".
+    pub fn username(self, username: impl Into<String>) -> Result<Self, ExecuteWebhookError> {
+        self._username(username.into())
+    }
 
-        self
+    fn _username(mut self, username: String) -> Result<Self, ExecuteWebhookError> {
+        if !validate::webhook_username(&username) {
+            return Err(ExecuteWebhookError {
+                kind: ExecuteWebhookErrorType::UsernameInvalid { username },
+                source: None,
+            });
+        }
+
+        self.fields.username.replace(username);
+
+        Ok(self)
+    }
+
+    /// Check that every file referenced by an embed via an `attachment://` URL,
+    /// such as an image or thumbnail, is attached.
+    ///
+    /// Embed images referencing a file that isn't attached silently don't
+    /// render, so this is checked when the request is sent.
+    fn validate_attachments(&self) -> Result<(), ExecuteWebhookError> {
+        let embeds = self.fields.embeds.iter().flatten();
+
+        if let Some(filename) = validate::embed_attachments(embeds, &self.files) {
+            return Err(ExecuteWebhookError {
+                kind: ExecuteWebhookErrorType::AttachmentMissing { filename },
+                source: None,
+            });
+        }
+
+        Ok(())
     }
 
     /// If true, wait for the message to send before sending a response. See [Discord Docs/Execute
@@ -213,7 +375,12 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn start(&mut self) -> Result<(), HttpError> {
+        self.validate_attachments().map_err(|source| HttpError {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
         let mut request = Request::builder(Route::ExecuteWebhook {
             token: self.token.clone(),
             wait: self.fields.wait,
@@ -234,7 +401,7 @@ impl<'a> ExecuteWebhook<'a> {
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -251,14 +418,14 @@ impl<'a> ExecuteWebhook<'a> {
 }
 
 impl Future for ExecuteWebhook<'_> {
-    type Output = Result<Option<Message>, Error>;
+    type Output = Result<Option<Message>, HttpError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         loop {
             if let Some(fut) = self.as_mut().fut.as_mut() {
                 let bytes = match fut.as_mut().poll(cx) {
                     Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(Error {
+                    Poll::Ready(Err(HttpError {
                         kind: ErrorType::Response { status, .. },
                         source: None,
                     })) if status == StatusCode::NOT_FOUND => {
@@ -283,3 +450,125 @@ impl Future for ExecuteWebhook<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecuteWebhook, ExecuteWebhookError, ExecuteWebhookErrorType};
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::validate::tests::{base_embed, button, row},
+    };
+    use serde_json::json;
+    use twilight_model::{
+        channel::{embed::EmbedImage, message::allowed_mentions::AllowedMentionsBuilder},
+        id::{RoleId, UserId, WebhookId},
+    };
+
+    #[test]
+    fn test_username_too_long() {
+        let client = Client::new("token");
+        let username = "a".repeat(81);
+        let error = ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .username(username.clone())
+            .err()
+            .expect("username is too long");
+
+        assert!(matches!(
+            error.kind(),
+            ExecuteWebhookErrorType::UsernameInvalid { username: provided } if *provided == username
+        ));
+    }
+
+    #[test]
+    fn test_username_contains_discord() {
+        let client = Client::new("token");
+
+        assert!(ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .username("my Discord bot")
+            .is_err());
+        assert!(ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .username("twilight")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_allowed_mentions() {
+        let client = Client::builder()
+            .default_allowed_mentions(AllowedMentionsBuilder::new().build())
+            .build();
+
+        let default = ExecuteWebhook::new(&client, WebhookId(1), "token");
+        assert_eq!(
+            json!({ "parse": [] }),
+            serde_json::to_value(&default.fields).unwrap()["allowed_mentions"],
+        );
+
+        let custom = ExecuteWebhook::new(&client, WebhookId(1), "token").allowed_mentions(
+            AllowedMentionsBuilder::new()
+                .user_ids(vec![UserId(3)])
+                .role_ids(vec![RoleId(4)]),
+        );
+        assert_eq!(
+            json!({ "parse": [], "users": ["3"], "roles": ["4"] }),
+            serde_json::to_value(&custom.fields).unwrap()["allowed_mentions"],
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let client = Client::new("token");
+        let components = [row(vec![button("yes"), button("no")])];
+
+        let builder = ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .components(&components)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&components).unwrap(),
+            serde_json::to_value(&builder.fields).unwrap()["components"],
+        );
+
+        let error = ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .components(&[button("yes")])
+            .err()
+            .expect("components are invalid");
+        assert!(matches!(
+            error.kind(),
+            ExecuteWebhookErrorType::ComponentsInvalid
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_attachment_missing() {
+        let client = Client::new("token");
+        let mut embed = base_embed();
+        embed.image = Some(EmbedImage {
+            height: None,
+            proxy_url: None,
+            url: Some("attachment://image.png".to_owned()),
+            width: None,
+        });
+
+        let error = ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .embeds(vec![embed.clone()])
+            .file("other.png", vec![1, 2, 3])
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::BuildingRequest));
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<ExecuteWebhookError>()
+            .unwrap();
+        assert!(matches!(
+            source.kind(),
+            ExecuteWebhookErrorType::AttachmentMissing { filename } if filename == "image.png"
+        ));
+
+        assert!(ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .embeds(vec![embed])
+            .file("image.png", vec![1, 2, 3])
+            .validate_attachments()
+            .is_ok());
+    }
+}
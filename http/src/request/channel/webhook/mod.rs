@@ -1,9 +1,9 @@
+pub mod execute_webhook;
 pub mod update_webhook_message;
 
 mod create_webhook;
 mod delete_webhook;
 mod delete_webhook_message;
-mod execute_webhook;
 mod get_channel_webhooks;
 mod get_webhook;
 mod get_webhook_message;
@@ -42,3 +42,55 @@ impl<'a> GetBan<'a> {
 }
 
 poll_req!(opt, GetBan<'_>, Ban);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use std::net::SocketAddr;
+    use twilight_model::id::{GuildId, UserId};
+
+    /// Respond to every request with the given status line and body.
+    async fn serve(status: &'static str, body: &'static str) -> TestServer {
+        TestServer::respond(json_response(status, body)).await
+    }
+
+    fn client(addr: SocketAddr) -> Client {
+        Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_banned() {
+        let server = serve(
+            "200 OK",
+            r#"{"reason":"spam","user":{"avatar":null,"discriminator":"0001","id":"2","username":"test"}}"#,
+        )
+        .await;
+
+        let ban = client(server.addr())
+            .ban(GuildId(1), UserId(2))
+            .await
+            .unwrap();
+
+        let ban = ban.expect("user is banned");
+        assert_eq!(Some("spam"), ban.reason.as_deref());
+        assert_eq!(UserId(2), ban.user.id);
+    }
+
+    #[tokio::test]
+    async fn test_not_banned() {
+        let server = serve("404 Not Found", r#"{"code":10026,"message":"Unknown Ban"}"#).await;
+
+        let ban = client(server.addr())
+            .ban(GuildId(1), UserId(2))
+            .await
+            .unwrap();
+
+        assert!(ban.is_none());
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     request::{validate, Pending, Request},
     routing::Route,
 };
+use futures_util::stream::{self, Stream, TryStreamExt};
 use hyper::body::Bytes;
 use serde::de::DeserializeSeed;
 use std::{
@@ -12,6 +13,7 @@ use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use twilight_model::{
     guild::member::{Member, MemberDeserializer},
@@ -110,6 +112,7 @@ pub struct GetGuildMembers<'a> {
     fut: Option<Pending<'a, Bytes>>,
     guild_id: GuildId,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> GetGuildMembers<'a> {
@@ -119,6 +122,7 @@ impl<'a> GetGuildMembers<'a> {
             fut: None,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -156,15 +160,94 @@ impl<'a> GetGuildMembers<'a> {
         self
     }
 
+    /// Set the timeout for this request, overriding the client's timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout.replace(timeout);
+
+        self
+    }
+
+    /// Stream all members of the guild, ordered by user ID.
+    ///
+    /// Pages of [`limit`] members, or 1000 if unset, are requested one after
+    /// another, each starting after the last member of the previous page. The
+    /// stream ends after a page with fewer members than the limit.
+    ///
+    /// # Examples
+    ///
+    /// Print the ID of every member of guild `100`:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::TryStreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::GuildId;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    ///
+    /// let mut members = Box::pin(client.guild_members(GuildId(100)).into_stream());
+    ///
+    /// while let Some(member) = members.try_next().await? {
+    ///     println!("{}", member.user.id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`limit`]: Self::limit
+    pub fn into_stream(self) -> impl Stream<Item = Result<Member, HttpError>> + 'a {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(1000);
+        let presences = self.fields.presences;
+        let timeout = self.timeout;
+
+        // The state is the user to get members after, or `None` once the last
+        // page has been received.
+        stream::try_unfold(Some(self.fields.after), move |state| async move {
+            let after = match state {
+                Some(after) => after,
+                None => return Ok(None),
+            };
+
+            let page = GetGuildMembers {
+                fields: GetGuildMembersFields {
+                    after,
+                    limit: Some(limit),
+                    presences,
+                },
+                fut: None,
+                guild_id,
+                http,
+                timeout,
+            }
+            .await?;
+
+            let next = match page.last() {
+                Some(last) if page.len() as u64 >= limit => Some(Some(last.user.id)),
+                _ => None,
+            };
+
+            Ok(Some((page, next)))
+        })
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetGuildMembers {
+        let mut request = Request::builder(Route::GetGuildMembers {
             after: self.fields.after.map(|x| x.0),
             guild_id: self.guild_id.0,
             limit: self.fields.limit,
             presences: self.fields.presences,
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        self.fut
+            .replace(Box::pin(self.http.request_bytes(request.build())));
 
         Ok(())
     }
@@ -203,3 +286,81 @@ impl Future for GetGuildMembers<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ErrorType,
+        test_server::{json_response, TestServer},
+        Client,
+    };
+    use futures_util::stream::TryStreamExt;
+    use std::time::Duration;
+    use twilight_model::id::{GuildId, UserId};
+
+    fn member_json(id: u64) -> String {
+        format!(
+            r#"{{"deaf":false,"hoisted_role":null,"joined_at":null,"mute":false,"nick":null,"premium_since":null,"roles":[],"user":{{"avatar":null,"discriminator":"0001","id":"{}","username":"test"}}}}"#,
+            id
+        )
+    }
+
+    #[tokio::test]
+    async fn test_into_stream() {
+        // Respond with a full page of two members and then with a page of one
+        // member.
+        let server = TestServer::start(|index, _| {
+            let page = if index == 0 {
+                format!("[{},{}]", member_json(1), member_json(2))
+            } else {
+                format!("[{}]", member_json(3))
+            };
+
+            Some(json_response("200 OK", &page))
+        })
+        .await;
+
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let members = client
+            .guild_members(GuildId(1))
+            .limit(2)
+            .unwrap()
+            .into_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let ids = members
+            .iter()
+            .map(|member| member.user.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![UserId(1), UserId(2), UserId(3)], ids);
+
+        let requests = server.routes();
+        assert_eq!(2, requests.len());
+        assert!(requests[1].contains("after=2"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_override() {
+        // Read requests but never respond.
+        let server = TestServer::start(|_, _| None).await;
+
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let error = client
+            .guild_members(GuildId(1))
+            .timeout(Duration::from_millis(10))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+    }
+}
@@ -12,6 +12,7 @@ use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use twilight_model::{
     guild::member::{Member, MemberDeserializer},
@@ -113,6 +114,7 @@ pub struct SearchGuildMembers<'a> {
     fut: Option<Pending<'a, Bytes>>,
     guild_id: GuildId,
     http: &'a Client,
+    timeout: Option<Duration>,
 }
 
 impl<'a> SearchGuildMembers<'a> {
@@ -126,6 +128,7 @@ impl<'a> SearchGuildMembers<'a> {
             fut: None,
             guild_id,
             http,
+            timeout: None,
         }
     }
 
@@ -151,14 +154,26 @@ impl<'a> SearchGuildMembers<'a> {
         Ok(self)
     }
 
+    /// Set the timeout for this request, overriding the client's timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout.replace(timeout);
+
+        self
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::SearchGuildMembers {
+        let mut request = Request::builder(Route::SearchGuildMembers {
             guild_id: self.guild_id.0,
             limit: self.fields.limit,
             query: self.fields.query.clone(),
         });
 
-        self.fut.replace(Box::pin(self.http.request_bytes(request)));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        self.fut
+            .replace(Box::pin(self.http.request_bytes(request.build())));
 
         Ok(())
     }
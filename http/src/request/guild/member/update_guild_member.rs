@@ -170,6 +170,10 @@ impl<'a> UpdateGuildMember<'a> {
     }
 
     /// Set the new list of roles for a member.
+    ///
+    /// This replaces all of the member's roles in a single request, unlike
+    /// [`Client::add_guild_member_role`] and
+    /// [`Client::remove_guild_member_role`], which modify one role at a time.
     pub fn roles(mut self, roles: Vec<RoleId>) -> Self {
         self.fields.roles.replace(roles);
 
@@ -245,7 +249,7 @@ mod tests {
         Client,
     };
     use std::error::Error;
-    use twilight_model::id::{GuildId, UserId};
+    use twilight_model::id::{GuildId, RoleId, UserId};
 
     const GUILD_ID: GuildId = GuildId(1);
     const USER_ID: UserId = UserId(1);
@@ -317,4 +321,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_roles() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo");
+        let builder = UpdateGuildMember::new(&client, GUILD_ID, USER_ID).roles(vec![
+            RoleId(2),
+            RoleId(3),
+            RoleId(4),
+        ]);
+        let actual = builder.request()?;
+
+        assert_eq!(Some(br#"{"roles":["2","3","4"]}"#.to_vec()), actual.body);
+
+        Ok(())
+    }
 }
@@ -1,8 +1,9 @@
+pub mod update_role_positions;
+
 mod create_role;
 mod delete_role;
 mod get_guild_roles;
 mod update_role;
-mod update_role_positions;
 
 pub use self::{
     create_role::CreateRole, delete_role::DeleteRole, get_guild_roles::GetGuildRoles,
@@ -1,44 +1,157 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Pending, Request},
+    error::Error as HttpError,
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
+use serde::Serialize;
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
     guild::Role,
     id::{GuildId, RoleId},
 };
 
+/// The error created when the role positions can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateRolePositionsError {
+    kind: UpdateRolePositionsErrorType,
+}
+
+impl UpdateRolePositionsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateRolePositionsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateRolePositionsErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for UpdateRolePositionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateRolePositionsErrorType::DuplicateRole { role_id } => {
+                f.write_str("role ")?;
+                Display::fmt(&role_id.0, f)?;
+
+                f.write_str(" is positioned more than once")
+            }
+        }
+    }
+}
+
+impl Error for UpdateRolePositionsError {}
+
+/// Type of [`UpdateRolePositionsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UpdateRolePositionsErrorType {
+    /// A role is positioned more than once.
+    DuplicateRole {
+        /// ID of the duplicated role.
+        role_id: RoleId,
+    },
+}
+
+/// New position of a role.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub struct RolePosition {
+    /// ID of the role.
+    pub id: RoleId,
+    /// Sorting position of the role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+}
+
+impl From<(RoleId, u64)> for RolePosition {
+    fn from((id, position): (RoleId, u64)) -> Self {
+        Self {
+            id,
+            position: Some(position),
+        }
+    }
+}
+
 /// Modify the position of the roles.
 ///
 /// The minimum amount of roles to modify, is a swap between two roles.
+///
+/// This function accepts an iterator of `(RoleId, u64)`. It also accepts an
+/// iterator of [`RolePosition`].
 pub struct UpdateRolePositions<'a> {
     fut: Option<Pending<'a, Vec<Role>>>,
     guild_id: GuildId,
     http: &'a Client,
-    roles: Vec<(RoleId, u64)>,
+    positions: Vec<RolePosition>,
+    reason: Option<String>,
 }
 
 impl<'a> UpdateRolePositions<'a> {
     pub(crate) fn new(
         http: &'a Client,
         guild_id: GuildId,
-        roles: impl Iterator<Item = (RoleId, u64)>,
-    ) -> Self {
-        Self {
+        roles: impl IntoIterator<Item = impl Into<RolePosition>>,
+    ) -> Result<Self, UpdateRolePositionsError> {
+        let positions = roles
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<RolePosition>>();
+        let mut role_ids = HashSet::with_capacity(positions.len());
+
+        for position in &positions {
+            if !role_ids.insert(position.id) {
+                return Err(UpdateRolePositionsError {
+                    kind: UpdateRolePositionsErrorType::DuplicateRole {
+                        role_id: position.id,
+                    },
+                });
+            }
+        }
+
+        Ok(Self {
             fut: None,
             guild_id,
             http,
-            roles: roles.collect(),
-        }
+            positions,
+            reason: None,
+        })
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateRolePositions {
+    pub(crate) fn request(&self) -> Result<Request, HttpError> {
+        let mut request = Request::builder(Route::UpdateRolePositions {
             guild_id: self.guild_id.0,
         })
-        .json(&self.roles)?
-        .build();
+        .json(&self.positions)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.request(request)));
 
@@ -46,4 +159,53 @@ impl<'a> UpdateRolePositions<'a> {
     }
 }
 
+impl<'a> AuditLogReason for UpdateRolePositions<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(UpdateRolePositions<'_>, Vec<Role>);
+
+#[cfg(test)]
+mod tests {
+    use super::{RolePosition, UpdateRolePositions, UpdateRolePositionsErrorType};
+    use crate::Client;
+    use twilight_model::id::{GuildId, RoleId};
+
+    #[test]
+    fn test_duplicate_role() {
+        let client = Client::new("foo");
+        let positions = vec![(RoleId(2), 3), (RoleId(4), 5), (RoleId(2), 6)];
+        let error = UpdateRolePositions::new(&client, GuildId(1), positions)
+            .err()
+            .expect("duplicate role is rejected");
+
+        assert!(matches!(
+            error.kind(),
+            UpdateRolePositionsErrorType::DuplicateRole { role_id } if *role_id == RoleId(2)
+        ));
+    }
+
+    #[test]
+    fn test_body() {
+        let client = Client::new("foo");
+        let positions = vec![
+            RolePosition::from((RoleId(2), 3)),
+            RolePosition {
+                id: RoleId(4),
+                position: None,
+            },
+        ];
+        let builder = UpdateRolePositions::new(&client, GuildId(1), positions).unwrap();
+        let request = builder.request().unwrap();
+
+        assert_eq!(
+            Some(br#"[{"id":"2","position":3},{"id":"4"}]"#.as_ref()),
+            request.body.as_deref()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
@@ -18,6 +18,7 @@ pub struct UpdateCurrentUserNick<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> UpdateCurrentUserNick<'a> {
@@ -27,15 +28,25 @@ impl<'a> UpdateCurrentUserNick<'a> {
             fut: None,
             guild_id,
             http,
+            reason: None,
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateNickname {
+    pub(crate) fn request(&self) -> Result<Request, Error> {
+        let mut request = Request::builder(Route::UpdateNickname {
             guild_id: self.guild_id.0,
         })
-        .json(&self.fields)?
-        .build();
+        .json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.verify(request)));
 
@@ -43,4 +54,13 @@ impl<'a> UpdateCurrentUserNick<'a> {
     }
 }
 
+impl<'a> AuditLogReason for UpdateCurrentUserNick<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(UpdateCurrentUserNick<'_>, ());
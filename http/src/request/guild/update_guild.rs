@@ -49,6 +49,9 @@ impl UpdateGuildError {
 impl Display for UpdateGuildError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateGuildErrorType::AfkTimeoutInvalid { .. } => {
+                f.write_str("the afk timeout is not one of the allowed values")
+            }
             UpdateGuildErrorType::NameInvalid { .. } => f.write_str("the name's length is invalid"),
         }
     }
@@ -60,6 +63,11 @@ impl Error for UpdateGuildError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateGuildErrorType {
+    /// The AFK timeout is not one of 60, 300, 900, 1800, or 3600 seconds.
+    AfkTimeoutInvalid {
+        /// Provided AFK timeout.
+        afk_timeout: u64,
+    },
     /// The name length is either fewer than 2 UTF-16 characters or more than 100 UTF-16
     /// characters.
     NameInvalid {
@@ -139,11 +147,25 @@ impl<'a> UpdateGuild<'a> {
         self
     }
 
-    /// Set how much time it takes for a voice user to be considered AFK.
-    pub fn afk_timeout(mut self, afk_timeout: u64) -> Self {
+    /// Set how much time it takes for a voice user to be considered AFK, in
+    /// seconds.
+    ///
+    /// The timeout must be one of 60, 300, 900, 1800, or 3600.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateGuildErrorType::AfkTimeoutInvalid`] error type if
+    /// the timeout is not one of the allowed values.
+    pub fn afk_timeout(mut self, afk_timeout: u64) -> Result<Self, UpdateGuildError> {
+        if !validate::guild_afk_timeout(afk_timeout) {
+            return Err(UpdateGuildError {
+                kind: UpdateGuildErrorType::AfkTimeoutInvalid { afk_timeout },
+            });
+        }
+
         self.fields.afk_timeout.replace(afk_timeout);
 
-        self
+        Ok(self)
     }
 
     /// Set the banner.
@@ -340,7 +362,7 @@ impl<'a> UpdateGuild<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
+    fn request(&self) -> Result<Request, HttpError> {
         let mut request = Request::builder(Route::UpdateGuild {
             guild_id: self.guild_id.0,
         })
@@ -350,8 +372,12 @@ impl<'a> UpdateGuild<'a> {
             request = request.headers(request::audit_header(reason)?)
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
@@ -367,3 +393,34 @@ impl<'a> AuditLogReason for UpdateGuild<'a> {
 }
 
 poll_req!(UpdateGuild<'_>, PartialGuild);
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateGuild, UpdateGuildErrorType};
+    use crate::client::Client;
+    use twilight_model::id::GuildId;
+
+    #[test]
+    fn test_afk_timeout() {
+        let client = Client::new("token");
+
+        let request = UpdateGuild::new(&client, GuildId(1))
+            .afk_timeout(300)
+            .expect("300 seconds is a valid timeout")
+            .request()
+            .expect("failed to create request");
+        assert_eq!(
+            br#"{"afk_timeout":300}"#.to_vec(),
+            request.body.expect("body is present")
+        );
+
+        let error = UpdateGuild::new(&client, GuildId(1))
+            .afk_timeout(120)
+            .err()
+            .expect("120 seconds is an invalid timeout");
+        assert!(matches!(
+            error.kind(),
+            UpdateGuildErrorType::AfkTimeoutInvalid { afk_timeout: 120 }
+        ));
+    }
+}
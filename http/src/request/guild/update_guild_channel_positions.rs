@@ -1,21 +1,91 @@
 use crate::{
     client::Client,
-    error::Error,
+    error::Error as HttpError,
     request::{Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::id::{ChannelId, GuildId};
 
-#[derive(Serialize)]
+/// The error created when the channel positions can not be updated as
+/// configured.
+#[derive(Debug)]
+pub struct UpdateGuildChannelPositionsError {
+    kind: UpdateGuildChannelPositionsErrorType,
+}
+
+impl UpdateGuildChannelPositionsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateGuildChannelPositionsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateGuildChannelPositionsErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for UpdateGuildChannelPositionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateGuildChannelPositionsErrorType::DuplicateChannel { channel_id } => {
+                f.write_str("channel ")?;
+                Display::fmt(&channel_id.0, f)?;
+
+                f.write_str(" is positioned more than once")
+            }
+        }
+    }
+}
+
+impl Error for UpdateGuildChannelPositionsError {}
+
+/// Type of [`UpdateGuildChannelPositionsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildChannelPositionsErrorType {
+    /// A channel is positioned more than once.
+    DuplicateChannel {
+        /// ID of the duplicated channel.
+        channel_id: ChannelId,
+    },
+}
+
+/// New position of a channel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct Position {
-    id: ChannelId,
+    /// ID of the channel.
+    pub id: ChannelId,
+    /// Whether to sync the permission overwrites of the channel with its new
+    /// parent category.
     #[serde(skip_serializing_if = "Option::is_none")]
-    lock_permissions: Option<bool>,
+    pub lock_permissions: Option<bool>,
+    /// ID of the new parent category of the channel.
     #[serde(skip_serializing_if = "Option::is_none")]
-    parent_id: Option<ChannelId>,
+    pub parent_id: Option<ChannelId>,
+    /// Sorting position of the channel.
     #[serde(skip_serializing_if = "Option::is_none")]
-    position: Option<u64>,
+    pub position: Option<u64>,
 }
 
 impl From<(ChannelId, u64)> for Position {
@@ -33,8 +103,8 @@ impl From<(ChannelId, u64)> for Position {
 ///
 /// The minimum amount of channels to modify, is a swap between two channels.
 ///
-/// This function accepts an `Iterator` of `(ChannelId, u64)`. It also accepts
-/// an `Iterator` of `Position`, which has extra fields.
+/// This function accepts an iterator of `(ChannelId, u64)`. It also accepts
+/// an iterator of [`Position`], which has extra fields.
 pub struct UpdateGuildChannelPositions<'a> {
     fut: Option<Pending<'a, ()>>,
     guild_id: GuildId,
@@ -46,25 +116,45 @@ impl<'a> UpdateGuildChannelPositions<'a> {
     pub(crate) fn new(
         http: &'a Client,
         guild_id: GuildId,
-        channel_positions: impl Iterator<Item = impl Into<Position>>,
-    ) -> Self {
-        let positions = channel_positions.map(Into::into).collect();
+        channel_positions: impl IntoIterator<Item = impl Into<Position>>,
+    ) -> Result<Self, UpdateGuildChannelPositionsError> {
+        let positions = channel_positions
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<Position>>();
+        let mut channel_ids = HashSet::with_capacity(positions.len());
 
-        Self {
+        for position in &positions {
+            if !channel_ids.insert(position.id) {
+                return Err(UpdateGuildChannelPositionsError {
+                    kind: UpdateGuildChannelPositionsErrorType::DuplicateChannel {
+                        channel_id: position.id,
+                    },
+                });
+            }
+        }
+
+        Ok(Self {
             fut: None,
             guild_id,
             http,
             positions,
-        }
+        })
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, HttpError> {
         let request = Request::builder(Route::UpdateGuildChannels {
             guild_id: self.guild_id.0,
         })
         .json(&self.positions)?
         .build();
 
+        Ok(request)
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -72,3 +162,49 @@ impl<'a> UpdateGuildChannelPositions<'a> {
 }
 
 poll_req!(UpdateGuildChannelPositions<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::{Position, UpdateGuildChannelPositions, UpdateGuildChannelPositionsErrorType};
+    use crate::Client;
+    use twilight_model::id::{ChannelId, GuildId};
+
+    #[test]
+    fn test_duplicate_channel() {
+        let client = Client::new("foo");
+        let positions = vec![(ChannelId(2), 3), (ChannelId(4), 5), (ChannelId(2), 6)];
+        let error = UpdateGuildChannelPositions::new(&client, GuildId(1), positions)
+            .err()
+            .expect("duplicate channel is rejected");
+
+        assert!(matches!(
+            error.kind(),
+            UpdateGuildChannelPositionsErrorType::DuplicateChannel { channel_id }
+                if *channel_id == ChannelId(2)
+        ));
+    }
+
+    #[test]
+    fn test_body() {
+        let client = Client::new("foo");
+        let positions = vec![
+            Position::from((ChannelId(2), 3)),
+            Position {
+                id: ChannelId(4),
+                lock_permissions: Some(true),
+                parent_id: Some(ChannelId(5)),
+                position: None,
+            },
+        ];
+        let builder = UpdateGuildChannelPositions::new(&client, GuildId(1), positions).unwrap();
+        let request = builder.request().unwrap();
+
+        assert_eq!(
+            Some(
+                br#"[{"id":"2","position":3},{"id":"4","lock_permissions":true,"parent_id":"5"}]"#
+                    .as_ref()
+            ),
+            request.body.as_deref()
+        );
+    }
+}
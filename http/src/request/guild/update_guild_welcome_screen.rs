@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
@@ -30,6 +30,7 @@ pub struct UpdateGuildWelcomeScreen<'a> {
     fut: Option<Pending<'a, WelcomeScreen>>,
     guild_id: GuildId,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> UpdateGuildWelcomeScreen<'a> {
@@ -39,6 +40,7 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
             fut: None,
             guild_id,
             http,
+            reason: None,
         }
     }
 
@@ -70,12 +72,21 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateGuildWelcomeScreen {
+    pub(crate) fn request(&self) -> Result<Request, Error> {
+        let mut request = Request::builder(Route::UpdateGuildWelcomeScreen {
             guild_id: self.guild_id.0,
         })
-        .json(&self.fields)?
-        .build();
+        .json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.request(request)));
 
@@ -83,4 +94,13 @@ impl<'a> UpdateGuildWelcomeScreen<'a> {
     }
 }
 
+impl<'a> AuditLogReason for UpdateGuildWelcomeScreen<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(UpdateGuildWelcomeScreen<'_>, WelcomeScreen);
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{NullableField, Pending, Request},
+    request::{self, AuditLogReason, AuditLogReasonError, NullableField, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
@@ -24,6 +24,7 @@ pub struct UpdateGuildWidget<'a> {
     fut: Option<Pending<'a, GuildWidget>>,
     guild_id: GuildId,
     http: &'a Client,
+    reason: Option<String>,
 }
 
 impl<'a> UpdateGuildWidget<'a> {
@@ -33,6 +34,7 @@ impl<'a> UpdateGuildWidget<'a> {
             fut: None,
             guild_id,
             http,
+            reason: None,
         }
     }
 
@@ -53,12 +55,21 @@ impl<'a> UpdateGuildWidget<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateGuildWidget {
+    pub(crate) fn request(&self) -> Result<Request, Error> {
+        let mut request = Request::builder(Route::UpdateGuildWidget {
             guild_id: self.guild_id.0,
         })
-        .json(&self.fields)?
-        .build();
+        .json(&self.fields)?;
+
+        if let Some(reason) = &self.reason {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.request(request)));
 
@@ -66,4 +77,13 @@ impl<'a> UpdateGuildWidget<'a> {
     }
 }
 
+impl<'a> AuditLogReason for UpdateGuildWidget<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
 poll_req!(UpdateGuildWidget<'_>, GuildWidget);
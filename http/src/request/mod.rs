@@ -111,6 +111,12 @@ pub enum Method {
 }
 
 impl Method {
+    /// Whether sending a request with the method multiple times has the same
+    /// effect as sending it once.
+    pub(crate) const fn is_idempotent(self) -> bool {
+        matches!(self, Self::Delete | Self::Get | Self::Put)
+    }
+
     pub(crate) const fn into_hyper(self) -> HyperMethod {
         match self {
             Self::Delete => HyperMethod::DELETE,
@@ -0,0 +1,41 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{application::role_connection::ApplicationRoleConnection, id::ApplicationId};
+
+/// Get the current user's role connection to an application.
+///
+/// Requires an OAuth2 bearer token with the `role_connections.write` scope.
+pub struct GetUserApplicationRoleConnection<'a> {
+    application_id: ApplicationId,
+    fut: Option<Pending<'a, ApplicationRoleConnection>>,
+    http: &'a Client,
+}
+
+impl<'a> GetUserApplicationRoleConnection<'a> {
+    pub(crate) fn new(http: &'a Client, application_id: ApplicationId) -> Self {
+        Self {
+            application_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = Request::from_route(Route::GetUserApplicationRoleConnection {
+            application_id: self.application_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    GetUserApplicationRoleConnection<'_>,
+    ApplicationRoleConnection
+);
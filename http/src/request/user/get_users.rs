@@ -0,0 +1,96 @@
+use super::GetUser;
+use crate::{client::Client, error::Error};
+use futures_util::future::{self, JoinAll};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use twilight_model::{id::UserId, user::User};
+
+/// Get multiple users' information by their [`UserId`]s.
+///
+/// Discord doesn't provide an endpoint to get multiple users at once, so this
+/// issues a [`GetUser`] request for every user ID concurrently. Each request
+/// still respects the client's ratelimiter.
+///
+/// Resolves to the result of every request, in the same order as the provided
+/// user IDs.
+pub struct GetUsers<'a> {
+    fut: JoinAll<GetUser<'a>>,
+}
+
+impl<'a> GetUsers<'a> {
+    pub(crate) fn new(http: &'a Client, user_ids: &[UserId]) -> Self {
+        Self {
+            fut: future::join_all(
+                user_ids
+                    .iter()
+                    .map(|user_id| GetUser::new(http, user_id.to_string())),
+            ),
+        }
+    }
+}
+
+impl Future for GetUsers<'_> {
+    type Output = Vec<Result<Option<User>, Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.fut).poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetUsers;
+    use crate::{
+        test_server::{json_response, request_path, TestServer},
+        Client,
+    };
+    use twilight_model::id::UserId;
+
+    /// Serve a user for every received request, using the last segment of the
+    /// requested path as its ID and name.
+    async fn serve() -> TestServer {
+        TestServer::start(|_, request| {
+            let user_id = request_path(request).rsplit('/').next().unwrap();
+            let body = format!(
+                r#"{{"avatar":null,"discriminator":"0001","id":"{0}","username":"user {0}"}}"#,
+                user_id,
+            );
+
+            Some(json_response("200 OK", &body))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_get_users() {
+        let server = serve().await;
+        let client = Client::builder()
+            .proxy(server.addr().to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let results = client.users(&[UserId(7), UserId(3), UserId(5)]).await;
+
+        assert_eq!(3, results.len());
+        let users = results
+            .into_iter()
+            .map(|result| result.unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![UserId(7), UserId(3), UserId(5)],
+            users.iter().map(|user| user.id).collect::<Vec<_>>(),
+        );
+        assert_eq!("user 3", users[1].name);
+    }
+
+    #[tokio::test]
+    async fn test_get_users_empty() {
+        let client = Client::new("token");
+        let results = GetUsers::new(&client, &[]).await;
+
+        assert!(results.is_empty());
+    }
+}
@@ -5,11 +5,16 @@ mod create_private_channel;
 mod get_current_user;
 mod get_current_user_connections;
 mod get_user;
+mod get_user_application_role_connection;
+mod get_users;
 mod leave_guild;
+mod update_user_application_role_connection;
 
 pub use self::{
     create_private_channel::CreatePrivateChannel, get_current_user::GetCurrentUser,
     get_current_user_connections::GetCurrentUserConnections,
-    get_current_user_guilds::GetCurrentUserGuilds, get_user::GetUser, leave_guild::LeaveGuild,
-    update_current_user::UpdateCurrentUser,
+    get_current_user_guilds::GetCurrentUserGuilds, get_user::GetUser,
+    get_user_application_role_connection::GetUserApplicationRoleConnection, get_users::GetUsers,
+    leave_guild::LeaveGuild, update_current_user::UpdateCurrentUser,
+    update_user_application_role_connection::UpdateUserApplicationRoleConnection,
 };
@@ -0,0 +1,94 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{application::role_connection::ApplicationRoleConnection, id::ApplicationId};
+
+/// Update the current user's role connection to an application.
+///
+/// Requires an OAuth2 bearer token with the `role_connections.write` scope.
+pub struct UpdateUserApplicationRoleConnection<'a> {
+    application_id: ApplicationId,
+    connection: ApplicationRoleConnection,
+    fut: Option<Pending<'a, ApplicationRoleConnection>>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateUserApplicationRoleConnection<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        connection: ApplicationRoleConnection,
+    ) -> Self {
+        Self {
+            application_id,
+            connection,
+            fut: None,
+            http,
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        let request = Request::builder(Route::UpdateUserApplicationRoleConnection {
+            application_id: self.application_id.0,
+        })
+        .json(&self.connection)?
+        .build();
+
+        Ok(request)
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    UpdateUserApplicationRoleConnection<'_>,
+    ApplicationRoleConnection
+);
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateUserApplicationRoleConnection;
+    use crate::{client::Client, request::Method, routing::Path};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use twilight_model::{
+        application::role_connection::ApplicationRoleConnection, id::ApplicationId,
+    };
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("Bearer token");
+        let mut metadata = HashMap::new();
+        metadata.insert("matches_won".to_owned(), "12".to_owned());
+        let connection = ApplicationRoleConnection {
+            metadata,
+            platform_name: Some("Twilight Arena".to_owned()),
+            platform_username: Some("twilight".to_owned()),
+        };
+
+        let actual =
+            UpdateUserApplicationRoleConnection::new(&client, ApplicationId(1), connection)
+                .request()
+                .expect("failed to create request");
+
+        assert_eq!(Method::Put, actual.method);
+        assert_eq!(Path::UsersIdApplicationsIdRoleConnection(1), actual.path);
+        assert_eq!(
+            json!({
+                "metadata": { "matches_won": "12" },
+                "platform_name": "Twilight Arena",
+                "platform_username": "twilight",
+            }),
+            serde_json::from_slice::<serde_json::Value>(&actual.body.expect("body is present"))
+                .unwrap(),
+        );
+    }
+}
@@ -7,7 +7,101 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::{
+    application::component::{Component, ComponentType},
+    channel::embed::Embed,
+};
+
+/// A list of message components is not valid.
+///
+/// Referenced values are used from [the Discord docs][docs].
+///
+/// [docs]: https://discord.com/developers/docs/interactions/message-components#action-rows
+#[derive(Debug)]
+pub struct ComponentValidationError {
+    kind: ComponentValidationErrorType,
+}
+
+impl ComponentValidationError {
+    /// The maximum number of action rows in a message.
+    pub const ACTION_ROW_COUNT: usize = 5;
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ComponentValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ComponentValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ComponentValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ComponentValidationErrorType::ActionRowCount { count } => {
+                f.write_str("there are ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" action rows, but the maximum amount is ")?;
+
+                Display::fmt(&Self::ACTION_ROW_COUNT, f)
+            }
+            ComponentValidationErrorType::InvalidChildComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.kind())?;
+
+                f.write_str("' component can not be placed in an action row")
+            }
+            ComponentValidationErrorType::InvalidRootComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.kind())?;
+
+                f.write_str("' component must be placed in an action row")
+            }
+        }
+    }
+}
+
+impl Error for ComponentValidationError {}
+
+/// Type of [`ComponentValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ComponentValidationErrorType {
+    /// There are more than [the maximum][`ACTION_ROW_COUNT`] number of action
+    /// rows.
+    ///
+    /// [`ACTION_ROW_COUNT`]: ComponentValidationError::ACTION_ROW_COUNT
+    ActionRowCount {
+        /// The number of action rows that were provided.
+        count: usize,
+    },
+    /// An action row contains a component other than a button or select menu.
+    InvalidChildComponent {
+        /// Type of the invalid component.
+        kind: ComponentType,
+    },
+    /// A top-level component is not an action row.
+    InvalidRootComponent {
+        /// Type of the invalid component.
+        kind: ComponentType,
+    },
+}
 
 /// An embed is not valid.
 ///
@@ -217,6 +311,40 @@ fn _channel_name(value: &str) -> bool {
     (1..=100).contains(&len)
 }
 
+pub fn components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    // <https://discord.com/developers/docs/interactions/message-components#action-rows>
+    if components.len() > ComponentValidationError::ACTION_ROW_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ActionRowCount {
+                count: components.len(),
+            },
+        });
+    }
+
+    for component in components {
+        let row = match component {
+            Component::ActionRow(row) => row,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                })
+            }
+        };
+
+        for child in &row.components {
+            if !matches!(child, Component::Button(_) | Component::SelectMenu(_)) {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: child.kind(),
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -318,6 +446,40 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
     Ok(())
 }
 
+/// Find the first file name referenced by an embed via an `attachment://`
+/// URL that isn't the name of one of the provided files.
+pub fn embed_attachments<'a>(
+    embeds: impl IntoIterator<Item = &'a Embed>,
+    files: &[(String, Vec<u8>)],
+) -> Option<String> {
+    embeds
+        .into_iter()
+        .flat_map(|embed| {
+            let image = embed.image.as_ref().and_then(|image| image.url.as_ref());
+            let thumbnail = embed
+                .thumbnail
+                .as_ref()
+                .and_then(|thumbnail| thumbnail.url.as_ref());
+            let author = embed
+                .author
+                .as_ref()
+                .and_then(|author| author.icon_url.as_ref());
+            let footer = embed
+                .footer
+                .as_ref()
+                .and_then(|footer| footer.icon_url.as_ref());
+
+            image
+                .into_iter()
+                .chain(thumbnail)
+                .chain(author)
+                .chain(footer)
+        })
+        .filter_map(|url| url.strip_prefix("attachment://"))
+        .find(|name| !files.iter().any(|(file, _)| file == name))
+        .map(ToOwned::to_owned)
+}
+
 pub const fn get_audit_log_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/audit-log#get-guild-audit-log-query-string-parameters>
     value >= 1 && value <= 100
@@ -347,6 +509,11 @@ pub const fn get_reactions_limit(value: u64) -> bool {
     value >= 1 && value <= 100
 }
 
+pub const fn guild_afk_timeout(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild#guild-object-guild-structure>
+    matches!(value, 60 | 300 | 900 | 1800 | 3600)
+}
+
 pub fn guild_name(value: impl AsRef<str>) -> bool {
     _guild_name(value.as_ref())
 }
@@ -396,6 +563,17 @@ fn _username(value: &str) -> bool {
     (2..=32).contains(&len)
 }
 
+pub fn webhook_username(value: impl AsRef<str>) -> bool {
+    _webhook_username(value.as_ref())
+}
+
+fn _webhook_username(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/webhook#create-webhook>
+    (1..=80).contains(&len) && !value.to_lowercase().contains("discord")
+}
+
 pub fn template_name(value: impl AsRef<str>) -> bool {
     _template_name(value.as_ref())
 }
@@ -456,12 +634,35 @@ pub fn command_permissions(len: usize) -> bool {
     (0..=10).contains(&len)
 }
 
+pub fn sticker_ids(len: usize) -> bool {
+    // https://discord.com/developers/docs/resources/channel#create-message-jsonform-params
+    (1..=3).contains(&len)
+}
+
 #[cfg(test)]
-mod tests {
+pub(super) mod tests {
     use super::*;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::{
+        application::component::{ActionRow, Button, ButtonStyle},
+        channel::embed::{EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail},
+    };
+
+    pub(crate) fn button(custom_id: &str) -> Component {
+        Component::Button(Button {
+            custom_id: Some(custom_id.to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("label".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        })
+    }
+
+    pub(crate) fn row(components: Vec<Component>) -> Component {
+        Component::ActionRow(ActionRow { components })
+    }
 
-    fn base_embed() -> Embed {
+    pub(crate) fn base_embed() -> Embed {
         Embed {
             author: None,
             color: None,
@@ -497,6 +698,34 @@ mod tests {
         assert!(!channel_name("a".repeat(101)));
     }
 
+    #[test]
+    fn test_components() {
+        assert!(components(&[]).is_ok());
+        assert!(components(&[row(vec![button("a"), button("b")])]).is_ok());
+        assert!(components(&vec![row(vec![button("a")]); 5]).is_ok());
+
+        assert!(matches!(
+            components(&vec![row(vec![button("a")]); 6])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ActionRowCount { count: 6 },
+        ));
+        assert!(matches!(
+            components(&[button("a")]).unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidRootComponent {
+                kind: ComponentType::Button,
+            },
+        ));
+        assert!(matches!(
+            components(&[row(vec![row(vec![button("a")])])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::ActionRow,
+            },
+        ));
+    }
+
     #[test]
     fn test_content_limit() {
         assert!(content_limit(""));
@@ -505,6 +734,41 @@ mod tests {
         assert!(!content_limit("a".repeat(2001)));
     }
 
+    #[test]
+    fn test_embed_attachments() {
+        let mut embed = base_embed();
+        embed.image = Some(EmbedImage {
+            height: None,
+            proxy_url: None,
+            url: Some("attachment://a.png".to_owned()),
+            width: None,
+        });
+        embed.thumbnail = Some(EmbedThumbnail {
+            height: None,
+            proxy_url: None,
+            url: Some("https://example.com/b.png".to_owned()),
+            width: None,
+        });
+        embed.footer = Some(EmbedFooter {
+            icon_url: Some("attachment://c.png".to_owned()),
+            proxy_icon_url: None,
+            text: "footer".to_owned(),
+        });
+
+        let files = vec![("a.png".to_owned(), Vec::new())];
+        assert_eq!(
+            Some("c.png".to_owned()),
+            embed_attachments(&[embed.clone()], &files)
+        );
+
+        let files = vec![
+            ("a.png".to_owned(), Vec::new()),
+            ("c.png".to_owned(), Vec::new()),
+        ];
+        assert!(embed_attachments(&[embed], &files).is_none());
+        assert!(embed_attachments(&[base_embed()], &[]).is_none());
+    }
+
     #[test]
     fn test_embed_base() {
         let embed = base_embed();
@@ -740,6 +1004,19 @@ mod tests {
         assert!(!get_reactions_limit(101));
     }
 
+    #[test]
+    fn test_guild_afk_timeout() {
+        assert!(guild_afk_timeout(60));
+        assert!(guild_afk_timeout(300));
+        assert!(guild_afk_timeout(900));
+        assert!(guild_afk_timeout(1800));
+        assert!(guild_afk_timeout(3600));
+
+        assert!(!guild_afk_timeout(0));
+        assert!(!guild_afk_timeout(61));
+        assert!(!guild_afk_timeout(7200));
+    }
+
     #[test]
     fn test_guild_name() {
         assert!(guild_name("aa"));
@@ -791,4 +1068,15 @@ mod tests {
         assert!(!username("a"));
         assert!(!username("a".repeat(33)));
     }
+
+    #[test]
+    fn test_webhook_username() {
+        assert!(webhook_username("a"));
+        assert!(webhook_username("a".repeat(80)));
+
+        assert!(!webhook_username(""));
+        assert!(!webhook_username("a".repeat(81)));
+        assert!(!webhook_username("discord"));
+        assert!(!webhook_username("Not Discord"));
+    }
 }
@@ -82,6 +82,10 @@ pub enum Path {
     ApplicationGuildCommand(u64),
     /// Operating on a specific command in a guild.
     ApplicationGuildCommandId(u64),
+    /// Operating on an application's entitlements.
+    ApplicationEntitlement(u64),
+    /// Operating on a specific entitlement.
+    ApplicationEntitlementId(u64),
     /// Operating on a channel.
     ChannelsId(u64),
     /// Operating on a channel's invites.
@@ -154,6 +158,8 @@ pub enum Path {
     InteractionCallback(u64),
     StageInstances,
     UsersId,
+    /// Operating on the current user's role connection to an application.
+    UsersIdApplicationsIdRoleConnection(u64),
     OauthApplicationsMe,
     UsersIdConnections,
     UsersIdChannels,
@@ -209,6 +215,8 @@ impl FromStr for Path {
         Ok(match parts.as_slice() {
             ["applications", id, "commands"] => ApplicationCommand(parse_id(id)?),
             ["applications", id, "commands", _] => ApplicationCommandId(parse_id(id)?),
+            ["applications", id, "entitlements"] => ApplicationEntitlement(parse_id(id)?),
+            ["applications", id, "entitlements", _] => ApplicationEntitlementId(parse_id(id)?),
             ["applications", id, "guilds", _, "commands"]
             | ["applications", id, "guilds", _, "commands", "permissions"] => {
                 ApplicationGuildCommand(parse_id(id)?)
@@ -291,6 +299,9 @@ impl FromStr for Path {
             ["stage-instances", _] => StageInstances,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
             ["users", _] => UsersId,
+            ["users", _, "applications", id, "role-connection"] => {
+                UsersIdApplicationsIdRoleConnection(parse_id(id)?)
+            }
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
             ["users", _, "guilds"] => UsersIdGuilds,
@@ -350,6 +361,14 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::ApplicationEntitlementId(123),
+            Path::from_str("/applications/123/entitlements/456")?
+        );
+        assert_eq!(
+            Path::UsersIdApplicationsIdRoleConnection(123),
+            Path::from_str("/users/@me/applications/123/role-connection")?
+        );
 
         Ok(())
     }
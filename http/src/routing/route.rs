@@ -5,6 +5,13 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route {
+    /// Route information to add a user to a group DM.
+    AddGroupRecipient {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to add a user to a guild.
     AddGuildMember { guild_id: u64, user_id: u64 },
     /// Route information to add a role to guild member.
@@ -110,6 +117,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a test entitlement.
+    CreateTestEntitlement {
+        /// The ID of the owner application.
+        application_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -253,6 +265,13 @@ pub enum Route {
         /// The target template code.
         template_code: String,
     },
+    /// Route information to delete a test entitlement.
+    DeleteTestEntitlement {
+        /// The ID of the owner application.
+        application_id: u64,
+        /// The ID of the entitlement.
+        entitlement_id: u64,
+    },
     /// Route information to delete a message created by a webhook.
     DeleteWebhookMessage {
         message_id: u64,
@@ -348,6 +367,19 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get an application's entitlements.
+    GetEntitlements {
+        /// The ID of the owner application.
+        application_id: u64,
+        /// Whether to exclude ended entitlements.
+        exclude_ended: Option<bool>,
+        /// The ID of the guild to get entitlements for.
+        guild_id: Option<u64>,
+        /// The IDs of the SKUs to get entitlements for.
+        sku_ids: Vec<u64>,
+        /// The ID of the user to get entitlements for.
+        user_id: Option<u64>,
+    },
     /// Route information to get basic gateway information.
     GetGateway,
     /// Route information to get gateway information tailored to the current
@@ -548,6 +580,12 @@ pub enum Route {
         /// user.
         target_user: String,
     },
+    /// Route information to get the current user's role connection to an
+    /// application.
+    GetUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to get the current user's connections.
     GetUserConnections,
     /// Route information to get the current user's private channels and groups.
@@ -590,6 +628,13 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to remove a user from a group DM.
+    RemoveGroupRecipient {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to remove a member from a guild.
     RemoveMember {
         /// The ID of the guild.
@@ -784,6 +829,12 @@ pub enum Route {
         /// The template code.
         template_code: String,
     },
+    /// Route information to update the current user's role connection to an
+    /// application.
+    UpdateUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to update a user's voice state.
     UpdateUserVoiceState {
         /// ID of the guild.
@@ -901,9 +952,11 @@ impl Route {
             | Self::DeleteRole { .. }
             | Self::DeleteStageInstance { .. }
             | Self::DeleteTemplate { .. }
+            | Self::DeleteTestEntitlement { .. }
             | Self::DeleteWebhookMessage { .. }
             | Self::DeleteWebhook { .. }
             | Self::LeaveGuild { .. }
+            | Self::RemoveGroupRecipient { .. }
             | Self::RemoveMember { .. }
             | Self::RemoveMemberRole { .. }
             | Self::UnpinMessage { .. } => Method::Delete,
@@ -919,6 +972,7 @@ impl Route {
             | Self::GetCurrentUserApplicationInfo
             | Self::GetEmoji { .. }
             | Self::GetEmojis { .. }
+            | Self::GetEntitlements { .. }
             | Self::GetGateway
             | Self::GetGlobalCommands { .. }
             | Self::GetGuild { .. }
@@ -947,6 +1001,7 @@ impl Route {
             | Self::GetStageInstance { .. }
             | Self::GetTemplate { .. }
             | Self::GetTemplates { .. }
+            | Self::GetUserApplicationRoleConnection { .. }
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
@@ -990,6 +1045,7 @@ impl Route {
             | Self::CreateRole { .. }
             | Self::CreateStageInstance { .. }
             | Self::CreateTemplate { .. }
+            | Self::CreateTestEntitlement { .. }
             | Self::CreateTypingTrigger { .. }
             | Self::CreateWebhook { .. }
             | Self::CrosspostMessage { .. }
@@ -998,7 +1054,8 @@ impl Route {
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
             | Self::SyncGuildIntegration { .. } => Method::Post,
-            Self::AddGuildMember { .. }
+            Self::AddGroupRecipient { .. }
+            | Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
             | Self::CreateBan { .. }
             | Self::CreateReaction { .. }
@@ -1008,7 +1065,8 @@ impl Route {
             | Self::SetGuildCommands { .. }
             | Self::SyncTemplate { .. }
             | Self::UpdateCommandPermissions { .. }
-            | Self::UpdatePermissionOverwrite { .. } => Method::Put,
+            | Self::UpdatePermissionOverwrite { .. }
+            | Self::UpdateUserApplicationRoleConnection { .. } => Method::Put,
         }
     }
 
@@ -1022,19 +1080,20 @@ impl Route {
     ///
     /// ```
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use twilight_http::{ratelimiting::Ratelimiter, routing::Route};
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use twilight_http::{
+    ///     ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    ///     routing::Route,
+    /// };
     ///
-    /// let ratelimiter = Ratelimiter::new();
+    /// let ratelimiter = InMemoryRatelimiter::new();
     /// let route = Route::CreateMessage {
     ///     channel_id: 123,
     ///  };
     ///
-    /// // Take a ticket from the ratelimiter.
-    /// let rx = ratelimiter.get(route.path()).await;
-    ///
-    /// // Wait to be told that a request can be made...
-    /// let _tx = rx.await;
+    /// // Take a ticket from the ratelimiter, waiting to be told that a
+    /// // request can be made...
+    /// let _tx = ratelimiter.ticket(route.path()).await?;
     ///
     /// // The request can now be made.
     /// # Ok(()) }
@@ -1044,6 +1103,10 @@ impl Route {
     #[allow(clippy::too_many_lines)]
     pub const fn path(&self) -> Path {
         match self {
+            Self::AddGroupRecipient { channel_id, .. }
+            | Self::RemoveGroupRecipient { channel_id, .. } => {
+                Path::ChannelsIdRecipients(*channel_id)
+            }
             Self::AddGuildMember { guild_id, .. }
             | Self::GetMember { guild_id, .. }
             | Self::RemoveMember { guild_id, .. }
@@ -1063,6 +1126,13 @@ impl Route {
             | Self::SetGlobalCommands { application_id } => {
                 Path::ApplicationCommand(*application_id)
             }
+            Self::CreateTestEntitlement { application_id }
+            | Self::GetEntitlements { application_id, .. } => {
+                Path::ApplicationEntitlement(*application_id)
+            }
+            Self::DeleteTestEntitlement { application_id, .. } => {
+                Path::ApplicationEntitlementId(*application_id)
+            }
             Self::CreateGuild | Self::CreateGuildFromTemplate { .. } | Self::GetTemplate { .. } => {
                 Path::Guilds
             }
@@ -1196,6 +1266,10 @@ impl Route {
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(*channel_id)
             }
+            Self::GetUserApplicationRoleConnection { application_id }
+            | Self::UpdateUserApplicationRoleConnection { application_id } => {
+                Path::UsersIdApplicationsIdRoleConnection(*application_id)
+            }
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
@@ -41,6 +41,20 @@ impl Display for RouteDisplay<'_> {
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self.0 {
+            Route::AddGroupRecipient {
+                channel_id,
+                user_id,
+            }
+            | Route::RemoveGroupRecipient {
+                channel_id,
+                user_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/recipients/")?;
+
+                Display::fmt(user_id, f)
+            }
             Route::AddGuildMember { guild_id, user_id }
             | Route::GetMember { guild_id, user_id }
             | Route::RemoveMember { guild_id, user_id }
@@ -234,6 +248,12 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/templates")
             }
+            Route::CreateTestEntitlement { application_id } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/entitlements")
+            }
             Route::CreateTypingTrigger { channel_id } => {
                 f.write_str("channels/")?;
                 Display::fmt(channel_id, f)?;
@@ -447,6 +467,16 @@ impl Display for RouteDisplay<'_> {
 
                 Display::fmt(channel_id, f)
             }
+            Route::DeleteTestEntitlement {
+                application_id,
+                entitlement_id,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/entitlements/")?;
+
+                Display::fmt(entitlement_id, f)
+            }
             Route::DeleteTemplate {
                 guild_id,
                 template_code,
@@ -585,6 +615,48 @@ impl Display for RouteDisplay<'_> {
             }
             Route::GetCurrentUserApplicationInfo => f.write_str("/oauth2/applications/@me"),
             Route::UpdateCurrentUser => f.write_str("users/@me"),
+            Route::GetEntitlements {
+                application_id,
+                exclude_ended,
+                guild_id,
+                sku_ids,
+                user_id,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/entitlements?")?;
+
+                if let Some(exclude_ended) = exclude_ended {
+                    f.write_str("exclude_ended=")?;
+                    Display::fmt(exclude_ended, f)?;
+                }
+
+                if let Some(guild_id) = guild_id {
+                    f.write_str("&guild_id=")?;
+                    Display::fmt(guild_id, f)?;
+                }
+
+                if !sku_ids.is_empty() {
+                    f.write_str("&sku_ids=")?;
+
+                    let sku_count = sku_ids.len() - 1;
+
+                    for (idx, sku_id) in sku_ids.iter().enumerate() {
+                        Display::fmt(sku_id, f)?;
+
+                        if idx < sku_count {
+                            f.write_str(",")?;
+                        }
+                    }
+                }
+
+                if let Some(user_id) = user_id {
+                    f.write_str("&user_id=")?;
+                    Display::fmt(user_id, f)?;
+                }
+
+                Ok(())
+            }
             Route::GetGateway => f.write_str("gateway"),
             Route::GetGuild {
                 guild_id,
@@ -833,6 +905,13 @@ impl Display for RouteDisplay<'_> {
 
                 Ok(())
             }
+            Route::GetUserApplicationRoleConnection { application_id }
+            | Route::UpdateUserApplicationRoleConnection { application_id } => {
+                f.write_str("users/@me/applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connection")
+            }
             Route::GetUserConnections => f.write_str("users/@me/connections"),
             Route::GetUser { target_user } => {
                 f.write_str("users/")?;
@@ -932,6 +1011,32 @@ mod tests {
 
     assert_impl_all!(RouteDisplay<'_>: Clone, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
 
+    #[test]
+    fn test_group_recipient() {
+        let route = Route::AddGroupRecipient {
+            channel_id: 1,
+            user_id: 2,
+        };
+
+        assert_eq!("channels/1/recipients/2", route.display().to_string());
+    }
+
+    #[test]
+    fn test_get_entitlements() {
+        let route = Route::GetEntitlements {
+            application_id: 1,
+            exclude_ended: Some(true),
+            guild_id: None,
+            sku_ids: vec![2, 3],
+            user_id: Some(4),
+        };
+
+        assert_eq!(
+            "applications/1/entitlements?exclude_ended=true&sku_ids=2,3&user_id=4",
+            route.display().to_string()
+        );
+    }
+
     #[test]
     fn test_set_guild_commands() {
         let route = Route::SetGuildCommands {
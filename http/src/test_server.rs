@@ -0,0 +1,173 @@
+//! Mock HTTP server for tests.
+//!
+//! Tests point a client at the server with `ClientBuilder::proxy`, and the
+//! server answers each request with the response built by a handler.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// Response without a body.
+pub const NO_CONTENT: &str = "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n";
+
+/// Build a response with a status line, such as `200 OK`, and a JSON body.
+pub fn json_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body,
+    )
+}
+
+/// Path of a raw request, such as `/api/v8/channels/1`.
+pub fn request_path(request: &str) -> &str {
+    request.split(' ').nth(1).unwrap_or_default()
+}
+
+/// Mock HTTP server recording the requests it receives.
+pub struct TestServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl TestServer {
+    /// Start a server answering each request with the response returned by
+    /// the handler.
+    ///
+    /// The handler is given the index of the request, in the order the
+    /// connections were accepted, and the raw request. Requests for which it
+    /// returns `None` are never answered.
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(usize, &str) -> Option<String> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler = Arc::new(handler);
+
+        {
+            let requests = Arc::clone(&requests);
+
+            tokio::spawn(async move {
+                let mut index = 0;
+
+                while let Ok((stream, _)) = listener.accept().await {
+                    let handler = Arc::clone(&handler);
+                    let requests = Arc::clone(&requests);
+
+                    tokio::spawn(handle(stream, index, handler, requests));
+                    index += 1;
+                }
+            });
+        }
+
+        Self { addr, requests }
+    }
+
+    /// Start a server answering every request with the same response.
+    pub async fn respond(response: impl Into<String>) -> Self {
+        let response = response.into();
+
+        Self::start(move |_, _| Some(response.clone())).await
+    }
+
+    /// Address to proxy requests to.
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Number of requests received.
+    pub fn received(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    /// Raw requests received, including their headers and bodies.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Method and path of the received requests, such as
+    /// `GET /api/v8/channels/1`.
+    pub fn routes(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.splitn(3, ' ').take(2).collect::<Vec<_>>().join(" "))
+            .collect()
+    }
+}
+
+async fn handle<F>(
+    mut stream: TcpStream,
+    index: usize,
+    handler: Arc<F>,
+    requests: Arc<Mutex<Vec<String>>>,
+) where
+    F: Fn(usize, &str) -> Option<String>,
+{
+    let request = read_request(&mut stream).await;
+    let response = handler(index, &request);
+    requests.lock().unwrap().push(request);
+
+    if let Some(response) = response {
+        let _res = stream.write_all(response.as_bytes()).await;
+
+        return;
+    }
+
+    // Hold the connection open until the client gives up on it.
+    let mut buf = [0; 64];
+
+    while let Ok(len) = stream.read(&mut buf).await {
+        if len == 0 {
+            break;
+        }
+    }
+}
+
+/// Read a request, including its body if it has a content length.
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    loop {
+        let len = stream.read(&mut buf).await.unwrap();
+
+        if len == 0 {
+            break;
+        }
+
+        request.extend_from_slice(&buf[..len]);
+
+        if let Some(headers_len) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            let headers = String::from_utf8_lossy(&request[..headers_len]);
+            let content_length = headers
+                .lines()
+                .find_map(|line| {
+                    let mut parts = line.splitn(2, ':');
+                    let name = parts.next()?;
+
+                    if !name.eq_ignore_ascii_case("content-length") {
+                        return None;
+                    }
+
+                    parts.next()?.trim().parse::<usize>().ok()
+                })
+                .unwrap_or(0);
+
+            if request.len() >= headers_len + 4 + content_length {
+                break;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&request).into_owned()
+}
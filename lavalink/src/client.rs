@@ -394,10 +394,10 @@ impl Lavalink {
 
         self.0
             .server_updates
-            .retain(|k, _| (k.0 >> 22) % shard_count != shard_id);
+            .retain(|k, _| k.shard_id(shard_count) != shard_id);
         self.0
             .sessions
-            .retain(|k, _| (k.0 >> 22) % shard_count != shard_id);
+            .retain(|k, _| k.shard_id(shard_count) != shard_id);
     }
 }
 
@@ -0,0 +1,116 @@
+//! Escape user provided content so that it renders literally in Discord.
+//!
+//! Markdown syntax is escaped with backslashes and `@everyone` and `@here`
+//! mentions are broken up by a zero-width space so that they don't ping.
+//!
+//! # Examples
+//!
+//! Echo user input back without formatting or pinging anyone:
+//!
+//! ```
+//! use twilight_mention::escape;
+//!
+//! assert_eq!("\\*\\*hi\\*\\* @\u{200b}everyone", escape("**hi** @everyone"));
+//! ```
+
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+/// Characters with a meaning in Discord's markdown.
+const MARKDOWN: &[char] = &['\\', '*', '_', '~', '`', '|', '>'];
+
+/// Mentions that notify users without referencing an ID.
+const MASS_MENTIONS: &[&str] = &["everyone", "here"];
+
+/// Zero-width space, inserted after the `@` of mass mentions.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Formatter escaping markdown and mass mentions in content.
+///
+/// Created via [`MarkdownEscape::new`]. Use [`escape`] to directly escape
+/// content into a new string.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_mention::escape::MarkdownEscape;
+///
+/// let content = "`code` and ~~strikethrough~~";
+/// let message = format!("You said: {}", MarkdownEscape::new(content));
+///
+/// assert_eq!(r"You said: \`code\` and \~\~strikethrough\~\~", message);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MarkdownEscape<'a>(&'a str);
+
+impl<'a> MarkdownEscape<'a> {
+    /// Create a new formatter escaping the provided content.
+    #[must_use = "creating a formatter does nothing on its own"]
+    pub const fn new(content: &'a str) -> Self {
+        Self(content)
+    }
+}
+
+impl Display for MarkdownEscape<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut rest = self.0;
+
+        while let Some(character) = rest.chars().next() {
+            rest = &rest[character.len_utf8()..];
+
+            if MARKDOWN.contains(&character) {
+                f.write_char('\\')?;
+            }
+
+            f.write_char(character)?;
+
+            if character == '@' && MASS_MENTIONS.iter().any(|name| rest.starts_with(name)) {
+                f.write_char(ZERO_WIDTH_SPACE)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Escape markdown and mass mentions in content.
+///
+/// Refer to [`MarkdownEscape`] for escaping content while formatting it.
+#[must_use = "escaping content has no effect if left unused"]
+pub fn escape(content: &str) -> String {
+    MarkdownEscape::new(content).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, MarkdownEscape};
+    use static_assertions::assert_impl_all;
+    use std::fmt::{Debug, Display};
+
+    assert_impl_all!(MarkdownEscape<'_>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(r"\*bold\*", escape("*bold*"));
+        assert_eq!(r"\_\_underline\_\_", escape("__underline__"));
+        assert_eq!(r"\~\~strike\~\~", escape("~~strike~~"));
+        assert_eq!(r"\`code\`", escape("`code`"));
+        assert_eq!(r"\|\|spoiler\|\|", escape("||spoiler||"));
+        assert_eq!(r"\> quote", escape("> quote"));
+        assert_eq!(r"\\\*", escape(r"\*"));
+    }
+
+    #[test]
+    fn test_mass_mentions() {
+        assert_eq!("@\u{200b}everyone", escape("@everyone"));
+        assert_eq!("hi @\u{200b}here!", escape("hi @here!"));
+        assert_eq!(r"<@123\> @someone", escape("<@123> @someone"));
+        assert!(!escape("@everyone @here").contains("@everyone"));
+        assert!(!escape("@everyone @here").contains("@here"));
+    }
+
+    #[test]
+    fn test_unchanged() {
+        assert_eq!("hello, world ✨", escape("hello, world ✨"));
+        assert_eq!("", escape(""));
+    }
+}
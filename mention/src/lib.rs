@@ -44,10 +44,14 @@
 )]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod escape;
 pub mod fmt;
 pub mod parse;
 pub mod timestamp;
 
+#[doc(no_inline)]
+pub use escape::escape;
+
 #[doc(no_inline)]
 pub use fmt::Mention;
 
@@ -29,6 +29,7 @@
 //! [`Display`]: core::fmt::Display
 //! [`Mention`]: super::fmt::Mention
 
+use super::fmt::{Mention, MentionFormat};
 use std::{
     cmp::Ordering,
     convert::TryFrom,
@@ -157,6 +158,42 @@ impl Timestamp {
     pub const fn unix(&self) -> u64 {
         self.unix
     }
+
+    /// Create a mention of the timestamp with the given style, overriding
+    /// the style of the timestamp if one is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::{Timestamp, TimestampStyle};
+    ///
+    /// let timestamp = Timestamp::new(1624044388, None);
+    /// assert_eq!(
+    ///     "<t:1624044388:D>",
+    ///     timestamp.mention_style(TimestampStyle::LongDate).to_string(),
+    /// );
+    /// ```
+    #[must_use = "creating a mention does nothing on its own"]
+    pub fn mention_style(&self, style: TimestampStyle) -> MentionFormat<Self> {
+        Self::new(self.unix, Some(style)).mention()
+    }
+
+    /// Create a mention of the timestamp with the [`RelativeTime`] style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::Timestamp;
+    ///
+    /// let timestamp = Timestamp::new(1624044388, None);
+    /// assert_eq!("<t:1624044388:R>", timestamp.mention_relative().to_string());
+    /// ```
+    ///
+    /// [`RelativeTime`]: TimestampStyle::RelativeTime
+    #[must_use = "creating a mention does nothing on its own"]
+    pub fn mention_relative(&self) -> MentionFormat<Self> {
+        self.mention_style(TimestampStyle::RelativeTime)
+    }
 }
 
 impl Ord for Timestamp {
@@ -342,6 +379,32 @@ mod tests {
         Ok(())
     }
 
+    /// Test that a timestamp is formatted as a mention with every style,
+    /// regardless of the style it was created with.
+    #[test]
+    fn test_timestamp_mention_style() {
+        const STYLES: [(TimestampStyle, &str); 7] = [
+            (TimestampStyle::LongDateTime, "<t:1624047064:F>"),
+            (TimestampStyle::LongDate, "<t:1624047064:D>"),
+            (TimestampStyle::LongTime, "<t:1624047064:T>"),
+            (TimestampStyle::RelativeTime, "<t:1624047064:R>"),
+            (TimestampStyle::ShortDateTime, "<t:1624047064:f>"),
+            (TimestampStyle::ShortDate, "<t:1624047064:d>"),
+            (TimestampStyle::ShortTime, "<t:1624047064:t>"),
+        ];
+
+        let unstyled = Timestamp::new(1_624_047_064, None);
+        let styled = Timestamp::new(1_624_047_064, Some(TimestampStyle::ShortDate));
+
+        for (style, expected) in STYLES {
+            assert_eq!(expected, unstyled.mention_style(style).to_string());
+            assert_eq!(expected, styled.mention_style(style).to_string());
+        }
+
+        assert_eq!("<t:1624047064:R>", unstyled.mention_relative().to_string());
+        assert_eq!("<t:1624047064:R>", styled.mention_relative().to_string());
+    }
+
     /// Test that timestamps are correctly compared based on their inner unix
     /// timestamp value.
     #[test]
@@ -0,0 +1,15 @@
+use super::Component;
+
+/// Container for other [`Component`]s.
+///
+/// Action rows may contain up to five buttons, or a single select menu or
+/// text input.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#action-rows
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ActionRow {
+    /// List of components in the action row.
+    pub components: Vec<Component>,
+}
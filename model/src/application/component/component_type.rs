@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// Type of a [`Component`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Component`]: super::Component
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#component-object-component-types
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ComponentType {
+    /// Container for other components.
+    ActionRow,
+    /// Clickable button.
+    Button,
+    /// Select menu for picking from defined text options.
+    TextSelectMenu,
+    /// Text input field, only usable in modals.
+    TextInput,
+    /// Select menu for users.
+    UserSelectMenu,
+    /// Select menu for roles.
+    RoleSelectMenu,
+    /// Select menu for users and roles.
+    MentionableSelectMenu,
+    /// Select menu for channels.
+    ChannelSelectMenu,
+    /// Component type that isn't known by this version of the library.
+    Unknown(u8),
+}
+
+impl ComponentType {
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::ActionRow => "ActionRow",
+            Self::Button => "Button",
+            Self::TextSelectMenu => "TextSelectMenu",
+            Self::TextInput => "TextInput",
+            Self::UserSelectMenu => "UserSelectMenu",
+            Self::RoleSelectMenu => "RoleSelectMenu",
+            Self::MentionableSelectMenu => "MentionableSelectMenu",
+            Self::ChannelSelectMenu => "ChannelSelectMenu",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl From<u8> for ComponentType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::ActionRow,
+            2 => Self::Button,
+            3 => Self::TextSelectMenu,
+            4 => Self::TextInput,
+            5 => Self::UserSelectMenu,
+            6 => Self::RoleSelectMenu,
+            7 => Self::MentionableSelectMenu,
+            8 => Self::ChannelSelectMenu,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ComponentType> for u8 {
+    fn from(value: ComponentType) -> Self {
+        match value {
+            ComponentType::ActionRow => 1,
+            ComponentType::Button => 2,
+            ComponentType::TextSelectMenu => 3,
+            ComponentType::TextInput => 4,
+            ComponentType::UserSelectMenu => 5,
+            ComponentType::RoleSelectMenu => 6,
+            ComponentType::MentionableSelectMenu => 7,
+            ComponentType::ChannelSelectMenu => 8,
+            ComponentType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ComponentType::TextSelectMenu, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ComponentType::UserSelectMenu, &[Token::U8(5)]);
+        serde_test::assert_tokens(&ComponentType::RoleSelectMenu, &[Token::U8(6)]);
+        serde_test::assert_tokens(&ComponentType::MentionableSelectMenu, &[Token::U8(7)]);
+        serde_test::assert_tokens(&ComponentType::ChannelSelectMenu, &[Token::U8(8)]);
+        serde_test::assert_tokens(&ComponentType::Unknown(99), &[Token::U8(99)]);
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!("ActionRow", ComponentType::ActionRow.kind());
+        assert_eq!("ChannelSelectMenu", ComponentType::ChannelSelectMenu.kind());
+        assert_eq!("Unknown", ComponentType::Unknown(99).kind());
+    }
+}
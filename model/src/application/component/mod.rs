@@ -0,0 +1,451 @@
+//! Interactive message and modal components.
+
+pub mod action_row;
+pub mod button;
+pub mod component_type;
+pub mod select_menu;
+pub mod text_input;
+
+pub use self::{
+    action_row::ActionRow,
+    button::{Button, ButtonStyle},
+    component_type::ComponentType,
+    select_menu::{SelectMenu, SelectMenuOption, SelectMenuType},
+    text_input::{TextInput, TextInputStyle},
+};
+
+use crate::channel::{ChannelType, ReactionType};
+use serde::{
+    de::{Deserializer, Error as DeError, IntoDeserializer},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+
+/// Interactive component of a message or modal.
+///
+/// Components with a type that isn't known by this version of the library are
+/// deserialized as [`Unknown`] instead of failing, so that new component types
+/// added by Discord don't prevent the rest of a message from being
+/// deserialized.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Unknown`]: Self::Unknown
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#what-is-a-component
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Component {
+    ActionRow(ActionRow),
+    Button(Button),
+    SelectMenu(SelectMenu),
+    TextInput(TextInput),
+    /// Component of a type unknown to this version of the library.
+    ///
+    /// The contained value is the raw component type.
+    Unknown(u8),
+}
+
+impl Component {
+    /// Type of the component.
+    pub const fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(select_menu) => match select_menu.kind {
+                SelectMenuType::Text => ComponentType::TextSelectMenu,
+                SelectMenuType::User => ComponentType::UserSelectMenu,
+                SelectMenuType::Role => ComponentType::RoleSelectMenu,
+                SelectMenuType::Mentionable => ComponentType::MentionableSelectMenu,
+                SelectMenuType::Channel => ComponentType::ChannelSelectMenu,
+            },
+            Self::TextInput(_) => ComponentType::TextInput,
+            Self::Unknown(unknown) => ComponentType::Unknown(*unknown),
+        }
+    }
+}
+
+/// Every field of every component type.
+///
+/// Which fields are present depends on the type of the component.
+#[derive(Deserialize)]
+#[serde(rename = "Component")]
+struct ComponentEnvelope {
+    channel_types: Option<Vec<ChannelType>>,
+    components: Option<Vec<Component>>,
+    custom_id: Option<String>,
+    disabled: Option<bool>,
+    emoji: Option<ReactionType>,
+    label: Option<String>,
+    max_length: Option<u16>,
+    max_values: Option<u8>,
+    min_length: Option<u16>,
+    min_values: Option<u8>,
+    options: Option<Vec<SelectMenuOption>>,
+    placeholder: Option<String>,
+    required: Option<bool>,
+    // Button and text input styles share the field, but not their values.
+    style: Option<u8>,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    url: Option<String>,
+    value: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let envelope = ComponentEnvelope::deserialize(deserializer)?;
+
+        let select_menu_type = match envelope.kind {
+            ComponentType::ActionRow => {
+                let components = envelope
+                    .components
+                    .ok_or_else(|| DeError::missing_field("components"))?;
+
+                return Ok(Self::ActionRow(ActionRow { components }));
+            }
+            ComponentType::Button => {
+                let style = envelope
+                    .style
+                    .ok_or_else(|| DeError::missing_field("style"))?;
+                let style = ButtonStyle::deserialize(
+                    IntoDeserializer::<D::Error>::into_deserializer(style),
+                )?;
+
+                return Ok(Self::Button(Button {
+                    custom_id: envelope.custom_id,
+                    disabled: envelope.disabled.unwrap_or_default(),
+                    emoji: envelope.emoji,
+                    label: envelope.label,
+                    style,
+                    url: envelope.url,
+                }));
+            }
+            ComponentType::TextInput => {
+                let custom_id = envelope
+                    .custom_id
+                    .ok_or_else(|| DeError::missing_field("custom_id"))?;
+                let label = envelope
+                    .label
+                    .ok_or_else(|| DeError::missing_field("label"))?;
+                let style = envelope
+                    .style
+                    .ok_or_else(|| DeError::missing_field("style"))?;
+                let style = TextInputStyle::deserialize(
+                    IntoDeserializer::<D::Error>::into_deserializer(style),
+                )?;
+
+                return Ok(Self::TextInput(TextInput {
+                    custom_id,
+                    label,
+                    max_length: envelope.max_length,
+                    min_length: envelope.min_length,
+                    placeholder: envelope.placeholder,
+                    required: envelope.required,
+                    style,
+                    value: envelope.value,
+                }));
+            }
+            ComponentType::Unknown(unknown) => return Ok(Self::Unknown(unknown)),
+            ComponentType::TextSelectMenu => SelectMenuType::Text,
+            ComponentType::UserSelectMenu => SelectMenuType::User,
+            ComponentType::RoleSelectMenu => SelectMenuType::Role,
+            ComponentType::MentionableSelectMenu => SelectMenuType::Mentionable,
+            ComponentType::ChannelSelectMenu => SelectMenuType::Channel,
+        };
+
+        let custom_id = envelope
+            .custom_id
+            .ok_or_else(|| DeError::missing_field("custom_id"))?;
+
+        Ok(Self::SelectMenu(SelectMenu {
+            channel_types: envelope.channel_types,
+            custom_id,
+            disabled: envelope.disabled.unwrap_or_default(),
+            kind: select_menu_type,
+            max_values: envelope.max_values,
+            min_values: envelope.min_values,
+            options: envelope.options,
+            placeholder: envelope.placeholder,
+        }))
+    }
+}
+
+/// Borrowed version of [`ComponentEnvelope`] used for serialization.
+#[derive(Serialize)]
+#[serde(rename = "Component")]
+struct ComponentEnvelopeRef<'ser> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_types: Option<&'ser [ChannelType]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<&'ser [Component]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_id: Option<&'ser str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<&'ser ReactionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<&'ser str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<&'ser [SelectMenuOption]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<&'ser str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<u8>,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'ser str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<&'ser str>,
+}
+
+impl ComponentEnvelopeRef<'_> {
+    /// Create an envelope of a component type with no fields set.
+    const fn new(kind: ComponentType) -> Self {
+        Self {
+            channel_types: None,
+            components: None,
+            custom_id: None,
+            disabled: None,
+            emoji: None,
+            label: None,
+            max_length: None,
+            max_values: None,
+            min_length: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+            required: None,
+            style: None,
+            kind,
+            url: None,
+            value: None,
+        }
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let kind = self.kind();
+
+        let envelope = match self {
+            Self::ActionRow(action_row) => ComponentEnvelopeRef {
+                components: Some(&action_row.components),
+                ..ComponentEnvelopeRef::new(kind)
+            },
+            Self::Button(button) => ComponentEnvelopeRef {
+                custom_id: button.custom_id.as_deref(),
+                disabled: Some(button.disabled),
+                emoji: button.emoji.as_ref(),
+                label: button.label.as_deref(),
+                style: Some(button.style as u8),
+                url: button.url.as_deref(),
+                ..ComponentEnvelopeRef::new(kind)
+            },
+            Self::SelectMenu(select_menu) => ComponentEnvelopeRef {
+                channel_types: select_menu.channel_types.as_deref(),
+                custom_id: Some(&select_menu.custom_id),
+                disabled: Some(select_menu.disabled),
+                max_values: select_menu.max_values,
+                min_values: select_menu.min_values,
+                options: select_menu.options.as_deref(),
+                placeholder: select_menu.placeholder.as_deref(),
+                ..ComponentEnvelopeRef::new(kind)
+            },
+            Self::TextInput(text_input) => ComponentEnvelopeRef {
+                custom_id: Some(&text_input.custom_id),
+                label: Some(&text_input.label),
+                max_length: text_input.max_length,
+                min_length: text_input.min_length,
+                placeholder: text_input.placeholder.as_deref(),
+                required: text_input.required,
+                style: Some(text_input.style as u8),
+                value: text_input.value.as_deref(),
+                ..ComponentEnvelopeRef::new(kind)
+            },
+            Self::Unknown(_) => ComponentEnvelopeRef::new(kind),
+        };
+
+        envelope.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuType,
+        TextInput, TextInputStyle,
+    };
+    use crate::channel::ChannelType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_action_row_button() {
+        let value = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::Button(Button {
+                custom_id: Some("click".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Click".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+            })]),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("components"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("custom_id"),
+                Token::Some,
+                Token::Str("click"),
+                Token::Str("disabled"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("Click"),
+                Token::Str("style"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_channel_select_menu() {
+        let value = Component::SelectMenu(SelectMenu {
+            channel_types: Some(Vec::from([ChannelType::GuildText])),
+            custom_id: "channels".to_owned(),
+            disabled: false,
+            kind: SelectMenuType::Channel,
+            max_values: Some(2),
+            min_values: None,
+            options: None,
+            placeholder: Some("pick a channel".to_owned()),
+        });
+
+        assert_eq!(ComponentType::ChannelSelectMenu, value.kind());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 6,
+                },
+                Token::Str("channel_types"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::U8(0),
+                Token::SeqEnd,
+                Token::Str("custom_id"),
+                Token::Some,
+                Token::Str("channels"),
+                Token::Str("disabled"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("max_values"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("placeholder"),
+                Token::Some,
+                Token::Str("pick a channel"),
+                Token::Str("type"),
+                Token::U8(8),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_text_input() {
+        let value = Component::TextInput(TextInput {
+            custom_id: "feedback".to_owned(),
+            label: "Feedback".to_owned(),
+            max_length: Some(1000),
+            min_length: Some(10),
+            placeholder: None,
+            required: Some(true),
+            style: TextInputStyle::Paragraph,
+            value: None,
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 7,
+                },
+                Token::Str("custom_id"),
+                Token::Some,
+                Token::Str("feedback"),
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("Feedback"),
+                Token::Str("max_length"),
+                Token::Some,
+                Token::U16(1000),
+                Token::Str("min_length"),
+                Token::Some,
+                Token::U16(10),
+                Token::Str("required"),
+                Token::Some,
+                Token::Bool(true),
+                Token::Str("style"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("type"),
+                Token::U8(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        serde_test::assert_de_tokens(
+            &Component::Unknown(99),
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(99),
+                Token::Str("custom_id"),
+                Token::Some,
+                Token::Str("new"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
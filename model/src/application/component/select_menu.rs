@@ -0,0 +1,60 @@
+use crate::channel::{ChannelType, ReactionType};
+use serde::{Deserialize, Serialize};
+
+/// Dropdown-style component for selecting one or more values.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#select-menus
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectMenu {
+    /// Types of channels that may be selected, for channel select menus.
+    pub channel_types: Option<Vec<ChannelType>>,
+    /// Developer defined identifier.
+    pub custom_id: String,
+    /// Whether the select menu is disabled.
+    pub disabled: bool,
+    /// Type of values that may be selected.
+    pub kind: SelectMenuType,
+    /// Maximum number of values that may be selected.
+    pub max_values: Option<u8>,
+    /// Minimum number of values that must be selected.
+    pub min_values: Option<u8>,
+    /// Options that may be selected, for text select menus.
+    pub options: Option<Vec<SelectMenuOption>>,
+    /// Placeholder text shown when no value is selected.
+    pub placeholder: Option<String>,
+}
+
+/// Type of values that may be selected in a [`SelectMenu`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SelectMenuType {
+    /// Developer defined text options.
+    Text,
+    /// Users.
+    User,
+    /// Roles.
+    Role,
+    /// Users and roles.
+    Mentionable,
+    /// Channels.
+    Channel,
+}
+
+/// Option that may be selected in a text [`SelectMenu`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectMenuOption {
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+    /// Additional description of the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Emoji displayed next to the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// User-facing name of the option.
+    pub label: String,
+    /// Developer defined value of the option.
+    pub value: String,
+}
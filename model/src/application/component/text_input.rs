@@ -0,0 +1,50 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Text input field, only usable in modals.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#text-inputs
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TextInput {
+    /// Developer defined identifier.
+    pub custom_id: String,
+    /// Text displayed above the text input.
+    pub label: String,
+    /// Maximum length of the input.
+    pub max_length: Option<u16>,
+    /// Minimum length of the input.
+    pub min_length: Option<u16>,
+    /// Placeholder text shown when the text input is empty.
+    pub placeholder: Option<String>,
+    /// Whether the text input must be filled.
+    pub required: Option<bool>,
+    /// Style of the text input.
+    pub style: TextInputStyle,
+    /// Pre-filled value of the text input.
+    pub value: Option<String>,
+}
+
+/// Style of a [`TextInput`].
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// Single-line input.
+    Short = 1,
+    /// Multi-line input.
+    Paragraph = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_text_input_style_variants() {
+        serde_test::assert_tokens(&TextInputStyle::Short, &[Token::U8(1)]);
+        serde_test::assert_tokens(&TextInputStyle::Paragraph, &[Token::U8(2)]);
+    }
+}
@@ -0,0 +1,45 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of an [`Entitlement`].
+///
+/// [`Entitlement`]: super::Entitlement
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum EntitlementType {
+    /// Purchased by a user.
+    Purchase = 1,
+    /// Granted by a Discord Nitro subscription.
+    PremiumSubscription = 2,
+    /// Gifted by the developer.
+    DeveloperGift = 3,
+    /// Purchased by a developer in application test mode.
+    TestModePurchase = 4,
+    /// Granted when the SKU was free.
+    FreePurchase = 5,
+    /// Gifted by another user.
+    UserGift = 6,
+    /// Claimed by a user for free as a Nitro subscriber.
+    PremiumPurchase = 7,
+    /// Purchased as an application subscription.
+    ApplicationSubscription = 8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntitlementType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&EntitlementType::Purchase, &[Token::U8(1)]);
+        serde_test::assert_tokens(&EntitlementType::PremiumSubscription, &[Token::U8(2)]);
+        serde_test::assert_tokens(&EntitlementType::DeveloperGift, &[Token::U8(3)]);
+        serde_test::assert_tokens(&EntitlementType::TestModePurchase, &[Token::U8(4)]);
+        serde_test::assert_tokens(&EntitlementType::FreePurchase, &[Token::U8(5)]);
+        serde_test::assert_tokens(&EntitlementType::UserGift, &[Token::U8(6)]);
+        serde_test::assert_tokens(&EntitlementType::PremiumPurchase, &[Token::U8(7)]);
+        serde_test::assert_tokens(&EntitlementType::ApplicationSubscription, &[Token::U8(8)]);
+    }
+}
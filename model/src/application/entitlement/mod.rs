@@ -0,0 +1,135 @@
+mod kind;
+
+pub use self::kind::EntitlementType;
+
+use crate::{
+    id::{ApplicationId, EntitlementId, GuildId, UserId},
+    oauth::id::SkuId,
+};
+use serde::{Deserialize, Serialize};
+
+/// Access of a user or guild to a premium offering of an application.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Entitlement {
+    /// ID of the parent application.
+    pub application_id: ApplicationId,
+    /// For consumable items, whether the entitlement has been consumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumed: Option<bool>,
+    /// Whether the entitlement was deleted.
+    pub deleted: bool,
+    /// When the entitlement stops being valid.
+    ///
+    /// Not present when using test entitlements.
+    pub ends_at: Option<String>,
+    /// ID of the guild that is granted access to the SKU.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// ID of the entitlement.
+    pub id: EntitlementId,
+    /// Type of the entitlement.
+    #[serde(rename = "type")]
+    pub kind: EntitlementType,
+    /// ID of the SKU.
+    pub sku_id: SkuId,
+    /// When the entitlement starts being valid.
+    ///
+    /// Not present when using test entitlements.
+    pub starts_at: Option<String>,
+    /// ID of the user that is granted access to the SKU.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entitlement, EntitlementType};
+    use crate::{
+        id::{ApplicationId, EntitlementId, UserId},
+        oauth::id::SkuId,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        Entitlement: application_id,
+        consumed,
+        deleted,
+        ends_at,
+        guild_id,
+        id,
+        kind,
+        sku_id,
+        starts_at,
+        user_id
+    );
+
+    assert_impl_all!(
+        Entitlement: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn test_entitlement() {
+        let value = Entitlement {
+            application_id: ApplicationId(1),
+            consumed: Some(false),
+            deleted: false,
+            ends_at: Some("2021-02-01T00:00:00.000000+00:00".to_owned()),
+            guild_id: None,
+            id: EntitlementId(2),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: SkuId(3),
+            starts_at: Some("2021-01-01T00:00:00.000000+00:00".to_owned()),
+            user_id: Some(UserId(4)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Entitlement",
+                    len: 9,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("consumed"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("deleted"),
+                Token::Bool(false),
+                Token::Str("ends_at"),
+                Token::Some,
+                Token::Str("2021-02-01T00:00:00.000000+00:00"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "EntitlementId",
+                },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct { name: "SkuId" },
+                Token::Str("3"),
+                Token::Str("starts_at"),
+                Token::Some,
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("user_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("4"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -1,3 +1,6 @@
 pub mod callback;
 pub mod command;
+pub mod component;
+pub mod entitlement;
 pub mod interaction;
+pub mod role_connection;
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Connection of a user to an application, used for linked roles.
+///
+/// Requires an OAuth2 bearer token with the `role_connections.write` scope.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationRoleConnection {
+    /// Mapping of metadata keys to their stringified values.
+    ///
+    /// Keys are those of the application's role connection metadata records.
+    pub metadata: HashMap<String, String>,
+    /// Vanity name of the platform the bot has connected.
+    ///
+    /// Maximum length of 50 characters.
+    pub platform_name: Option<String>,
+    /// Username of the user on the platform the bot has connected.
+    ///
+    /// Maximum length of 100 characters.
+    pub platform_username: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnection;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnection: metadata,
+        platform_name,
+        platform_username
+    );
+    assert_impl_all!(
+        ApplicationRoleConnection: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_application_role_connection() {
+        let mut metadata = HashMap::new();
+        metadata.insert("matches_won".to_owned(), "12".to_owned());
+
+        let value = ApplicationRoleConnection {
+            metadata,
+            platform_name: Some("Twilight Arena".to_owned()),
+            platform_username: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnection",
+                    len: 3,
+                },
+                Token::Str("metadata"),
+                Token::Map { len: Some(1) },
+                Token::Str("matches_won"),
+                Token::Str("12"),
+                Token::MapEnd,
+                Token::Str("platform_name"),
+                Token::Some,
+                Token::Str("Twilight Arena"),
+                Token::Str("platform_username"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
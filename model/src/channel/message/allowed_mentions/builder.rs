@@ -98,6 +98,12 @@ impl AllowedMentionsBuilder {
     }
 }
 
+impl From<AllowedMentionsBuilder> for AllowedMentions {
+    fn from(builder: AllowedMentionsBuilder) -> Self {
+        builder.build()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -126,6 +132,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_builder() {
+        let value = AllowedMentions::from(
+            AllowedMentionsBuilder::new()
+                .user_ids(vec![UserId(100)])
+                .replied_user(),
+        );
+
+        assert_eq!(
+            value,
+            AllowedMentions {
+                parse: vec![],
+                users: vec![UserId(100)],
+                roles: vec![],
+                replied_user: true,
+            },
+        );
+    }
+
     #[test]
     fn test_validation() {
         let value = AllowedMentionsBuilder::new()
@@ -35,6 +35,28 @@ impl AllowedMentions {
     pub const fn builder() -> AllowedMentionsBuilder {
         AllowedMentionsBuilder::new()
     }
+
+    /// Create an allowed mentions structure that doesn't allow any mentions,
+    /// including of the user being replied to.
+    pub const fn none() -> Self {
+        Self {
+            parse: Vec::new(),
+            users: Vec::new(),
+            roles: Vec::new(),
+            replied_user: false,
+        }
+    }
+
+    /// Create an allowed mentions structure that allows mentions of users,
+    /// roles, and the user being replied to, but not `@everyone` and `@here`.
+    pub fn suppress_everyone() -> Self {
+        Self {
+            parse: vec![ParseTypes::Roles, ParseTypes::Users],
+            users: Vec::new(),
+            roles: Vec::new(),
+            replied_user: true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +128,48 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_none() {
+        serde_test::assert_ser_tokens(
+            &AllowedMentions::none(),
+            &[
+                Token::Struct {
+                    name: "AllowedMentions",
+                    len: 1,
+                },
+                Token::Str("parse"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_suppress_everyone() {
+        serde_test::assert_ser_tokens(
+            &AllowedMentions::suppress_everyone(),
+            &[
+                Token::Struct {
+                    name: "AllowedMentions",
+                    len: 2,
+                },
+                Token::Str("parse"),
+                Token::Seq { len: Some(2) },
+                Token::UnitVariant {
+                    name: "ParseTypes",
+                    variant: "roles",
+                },
+                Token::UnitVariant {
+                    name: "ParseTypes",
+                    variant: "users",
+                },
+                Token::SeqEnd,
+                Token::Str("replied_user"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
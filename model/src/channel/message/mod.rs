@@ -26,6 +26,7 @@ use crate::{
     user::User,
 };
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Message {
@@ -80,6 +81,13 @@ pub struct Message {
     pub webhook_id: Option<WebhookId>,
 }
 
+impl Message {
+    /// Time at which the message was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        crate::id::created_at(self.id.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -2,6 +2,7 @@ pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
 pub mod stage_instance;
+pub mod thread;
 pub mod webhook;
 
 mod attachment;
@@ -40,7 +41,10 @@ use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::SystemTime,
+};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ConversionError {
@@ -111,6 +115,11 @@ pub enum GuildChannel {
 }
 
 impl GuildChannel {
+    /// Time at which the inner guild channel was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        crate::id::created_at(self.id().0)
+    }
+
     /// Return the guild ID of the inner guild channel.
     pub const fn guild_id(&self) -> Option<GuildId> {
         match self {
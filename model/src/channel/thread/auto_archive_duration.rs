@@ -0,0 +1,44 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Number of minutes of inactivity after which a thread is archived.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
+)]
+#[repr(u16)]
+pub enum AutoArchiveDuration {
+    /// One hour.
+    Hour = 60,
+    /// One day.
+    Day = 1440,
+    /// Three days.
+    ThreeDays = 4320,
+    /// One week.
+    Week = 10080,
+}
+
+impl AutoArchiveDuration {
+    /// Number of minutes of inactivity the duration represents.
+    pub const fn number(self) -> u16 {
+        self as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoArchiveDuration;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoArchiveDuration::Hour, &[Token::U16(60)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Day, &[Token::U16(1440)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::ThreeDays, &[Token::U16(4320)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Week, &[Token::U16(10080)]);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(60, AutoArchiveDuration::Hour.number());
+        assert_eq!(10080, AutoArchiveDuration::Week.number());
+    }
+}
@@ -0,0 +1,59 @@
+use super::AutoArchiveDuration;
+use serde::{Deserialize, Serialize};
+
+/// Thread-specific information not present on other channels.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// ISO 8601 timestamp of when the thread's archive status last changed.
+    pub archive_timestamp: String,
+    /// Duration of inactivity after which the thread is archived.
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// Whether members without the `MANAGE_THREADS` permission can invite
+    /// others to the thread.
+    ///
+    /// Only present for private threads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// Whether the thread is locked, meaning only members with the
+    /// `MANAGE_THREADS` permission can unarchive it.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archived: true,
+            archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+            auto_archive_duration: AutoArchiveDuration::Day,
+            invitable: None,
+            locked: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archived"),
+                Token::Bool(true),
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-09-19T14:17:32.000000+00:00"),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
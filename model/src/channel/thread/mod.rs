@@ -0,0 +1,130 @@
+//! Threads are temporary sub-channels of guild text and news channels.
+
+mod auto_archive_duration;
+mod member;
+mod metadata;
+
+pub use self::{
+    auto_archive_duration::AutoArchiveDuration, member::ThreadMember, metadata::ThreadMetadata,
+};
+
+use crate::{
+    channel::ChannelType,
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// News, public, or private thread in a guild.
+///
+/// The type of thread is determined by its [`kind`].
+///
+/// [`kind`]: Self::kind
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    pub id: ChannelId,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message_id: Option<MessageId>,
+    /// Thread member of the current user, if they have joined the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<ThreadMember>,
+    /// Approximate number of members in the thread, stopping at 50.
+    pub member_count: u8,
+    /// Approximate number of messages in the thread, stopping at 50.
+    pub message_count: u8,
+    pub name: String,
+    /// ID of the user that created the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<UserId>,
+    /// ID of the text or news channel the thread was created in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u64>,
+    pub thread_metadata: ThreadMetadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadChannel, ThreadMetadata};
+    use crate::{
+        channel::ChannelType,
+        id::{ChannelId, GuildId, UserId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_channel() {
+        let value = ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 5,
+            message_count: 10,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(3)),
+            parent_id: Some(ChannelId(4)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                auto_archive_duration: AutoArchiveDuration::Hour,
+                invitable: None,
+                locked: false,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadChannel",
+                    len: 9,
+                },
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(11),
+                Token::Str("member_count"),
+                Token::U8(5),
+                Token::Str("message_count"),
+                Token::U8(10),
+                Token::Str("name"),
+                Token::Str("thread"),
+                Token::Str("owner_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::Str("parent_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("4"),
+                Token::Str("thread_metadata"),
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-09-19T14:17:32.000000+00:00"),
+                Token::Str("auto_archive_duration"),
+                Token::U16(60),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
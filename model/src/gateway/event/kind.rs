@@ -23,6 +23,9 @@ pub enum EventType {
     GuildDelete,
     GuildEmojisUpdate,
     GuildIntegrationsUpdate,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
     GuildUpdate,
     IntegrationCreate,
     IntegrationDelete,
@@ -70,6 +73,10 @@ pub enum EventType {
     StageInstanceCreate,
     StageInstanceDelete,
     StageInstanceUpdate,
+    ThreadCreate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadUpdate,
     TypingStart,
     UnavailableGuild,
     UserUpdate,
@@ -92,6 +99,9 @@ impl EventType {
             Self::GuildDelete => Some("GUILD_DELETE"),
             Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
             Self::GuildIntegrationsUpdate => Some("GUILD_INTEGRATIONS_UPDATE"),
+            Self::GuildScheduledEventCreate => Some("GUILD_SCHEDULED_EVENT_CREATE"),
+            Self::GuildScheduledEventDelete => Some("GUILD_SCHEDULED_EVENT_DELETE"),
+            Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
             Self::IntegrationCreate => Some("INTEGRATION_CREATE"),
             Self::IntegrationDelete => Some("INTEGRATION_DELETE"),
@@ -121,6 +131,10 @@ impl EventType {
             Self::StageInstanceCreate => Some("STAGE_INSTANCE_CREATE"),
             Self::StageInstanceDelete => Some("STAGE_INSTANCE_DELETE"),
             Self::StageInstanceUpdate => Some("STAGE_INSTANCE_UPDATE"),
+            Self::ThreadCreate => Some("THREAD_CREATE"),
+            Self::ThreadDelete => Some("THREAD_DELETE"),
+            Self::ThreadListSync => Some("THREAD_LIST_SYNC"),
+            Self::ThreadUpdate => Some("THREAD_UPDATE"),
             Self::TypingStart => Some("TYPING_START"),
             Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
             Self::UserUpdate => Some("USER_UPDATE"),
@@ -159,6 +173,9 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_DELETE" => Ok(Self::GuildDelete),
             "GUILD_EMOJIS_UPDATE" => Ok(Self::GuildEmojisUpdate),
             "GUILD_INTEGRATIONS_UPDATE" => Ok(Self::GuildIntegrationsUpdate),
+            "GUILD_SCHEDULED_EVENT_CREATE" => Ok(Self::GuildScheduledEventCreate),
+            "GUILD_SCHEDULED_EVENT_DELETE" => Ok(Self::GuildScheduledEventDelete),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
             "INTEGRATION_CREATE" => Ok(Self::IntegrationCreate),
             "INTEGRATION_DELETE" => Ok(Self::IntegrationDelete),
@@ -188,6 +205,10 @@ impl<'a> TryFrom<&'a str> for EventType {
             "STAGE_INSTANCE_CREATE" => Ok(Self::StageInstanceCreate),
             "STAGE_INSTANCE_DELETE" => Ok(Self::StageInstanceDelete),
             "STAGE_INSTANCE_UPDATE" => Ok(Self::StageInstanceUpdate),
+            "THREAD_CREATE" => Ok(Self::ThreadCreate),
+            "THREAD_DELETE" => Ok(Self::ThreadDelete),
+            "THREAD_LIST_SYNC" => Ok(Self::ThreadListSync),
+            "THREAD_UPDATE" => Ok(Self::ThreadUpdate),
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
             "USER_UPDATE" => Ok(Self::UserUpdate),
@@ -238,6 +259,18 @@ mod tests {
             EventType::GuildIntegrationsUpdate,
             "GUILD_INTEGRATIONS_UPDATE",
         );
+        assert_variant(
+            EventType::GuildScheduledEventCreate,
+            "GUILD_SCHEDULED_EVENT_CREATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventDelete,
+            "GUILD_SCHEDULED_EVENT_DELETE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUpdate,
+            "GUILD_SCHEDULED_EVENT_UPDATE",
+        );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
         assert_variant(EventType::IntegrationCreate, "INTEGRATION_CREATE");
         assert_variant(EventType::IntegrationDelete, "INTEGRATION_DELETE");
@@ -277,6 +310,10 @@ mod tests {
         assert_variant(EventType::StageInstanceCreate, "STAGE_INSTANCE_CREATE");
         assert_variant(EventType::StageInstanceDelete, "STAGE_INSTANCE_DELETE");
         assert_variant(EventType::StageInstanceUpdate, "STAGE_INSTANCE_UPDATE");
+        assert_variant(EventType::ThreadCreate, "THREAD_CREATE");
+        assert_variant(EventType::ThreadDelete, "THREAD_DELETE");
+        assert_variant(EventType::ThreadListSync, "THREAD_LIST_SYNC");
+        assert_variant(EventType::ThreadUpdate, "THREAD_UPDATE");
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
@@ -57,6 +57,12 @@ pub enum Event {
     GuildEmojisUpdate(GuildEmojisUpdate),
     /// A guild's integrations were updated.
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    /// A guild scheduled event was created.
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    /// A guild scheduled event was deleted.
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    /// A guild scheduled event was updated.
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
     /// A guild was updated.
     GuildUpdate(Box<GuildUpdate>),
     /// A guild integration was created.
@@ -135,6 +141,16 @@ pub enum Event {
     StageInstanceDelete(StageInstanceDelete),
     /// A stage instance was updated in a stage channel.
     StageInstanceUpdate(StageInstanceUpdate),
+    /// A thread was created or the current user was added to a private
+    /// thread.
+    ThreadCreate(Box<ThreadCreate>),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDelete),
+    /// The current user gained access to channels and was sent their active
+    /// threads.
+    ThreadListSync(ThreadListSync),
+    /// A thread was updated.
+    ThreadUpdate(Box<ThreadUpdate>),
     /// A user started typing in a channel.
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
@@ -168,6 +184,9 @@ impl Event {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
@@ -204,6 +223,10 @@ impl Event {
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -228,6 +251,9 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::GuildDelete(v) => Self::GuildDelete(v),
             DispatchEvent::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
             DispatchEvent::GuildIntegrationsUpdate(v) => Self::GuildIntegrationsUpdate(v),
+            DispatchEvent::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            DispatchEvent::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            DispatchEvent::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
             DispatchEvent::IntegrationCreate(v) => Self::IntegrationCreate(v),
             DispatchEvent::IntegrationDelete(v) => Self::IntegrationDelete(v),
             DispatchEvent::IntegrationUpdate(v) => Self::IntegrationUpdate(v),
@@ -257,6 +283,10 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            DispatchEvent::ThreadCreate(v) => Self::ThreadCreate(v),
+            DispatchEvent::ThreadDelete(v) => Self::ThreadDelete(v),
+            DispatchEvent::ThreadListSync(v) => Self::ThreadListSync(v),
+            DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
@@ -14,6 +14,9 @@ mod guild_create;
 mod guild_delete;
 mod guild_emojis_update;
 mod guild_integrations_update;
+mod guild_scheduled_event_create;
+mod guild_scheduled_event_delete;
+mod guild_scheduled_event_update;
 mod guild_update;
 mod heartbeat;
 mod integration_create;
@@ -41,6 +44,10 @@ mod role_update;
 mod stage_instance_create;
 mod stage_instance_delete;
 mod stage_instance_update;
+mod thread_create;
+mod thread_delete;
+mod thread_list_sync;
+mod thread_update;
 mod typing_start;
 mod unavailable_guild;
 mod update_voice_state;
@@ -54,7 +61,10 @@ pub use self::{
     channel_delete::ChannelDelete, channel_pins_update::ChannelPinsUpdate,
     channel_update::ChannelUpdate, guild_create::GuildCreate, guild_delete::GuildDelete,
     guild_emojis_update::GuildEmojisUpdate, guild_integrations_update::GuildIntegrationsUpdate,
-    guild_update::GuildUpdate, heartbeat::Heartbeat, integration_create::IntegrationCreate,
+    guild_scheduled_event_create::GuildScheduledEventCreate,
+    guild_scheduled_event_delete::GuildScheduledEventDelete,
+    guild_scheduled_event_update::GuildScheduledEventUpdate, guild_update::GuildUpdate,
+    heartbeat::Heartbeat, integration_create::IntegrationCreate,
     integration_delete::IntegrationDelete, integration_update::IntegrationUpdate,
     interaction_create::InteractionCreate, invite_create::InviteCreate,
     invite_delete::InviteDelete, member_add::MemberAdd, member_chunk::MemberChunk,
@@ -66,7 +76,8 @@ pub use self::{
     request_guild_members::RequestGuildMembers, role_create::RoleCreate, role_delete::RoleDelete,
     role_update::RoleUpdate, stage_instance_create::StageInstanceCreate,
     stage_instance_delete::StageInstanceDelete, stage_instance_update::StageInstanceUpdate,
-    typing_start::TypingStart, unavailable_guild::UnavailableGuild,
+    thread_create::ThreadCreate, thread_delete::ThreadDelete, thread_list_sync::ThreadListSync,
+    thread_update::ThreadUpdate, typing_start::TypingStart, unavailable_guild::UnavailableGuild,
     update_presence::UpdatePresence, update_voice_state::UpdateVoiceState, user_update::UserUpdate,
     voice_server_update::VoiceServerUpdate, voice_state_update::VoiceStateUpdate,
     webhooks_update::WebhooksUpdate,
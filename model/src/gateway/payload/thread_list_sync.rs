@@ -0,0 +1,17 @@
+use crate::{
+    channel::thread::{ThreadChannel, ThreadMember},
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// When empty, threads are being synced for the entire guild.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<ThreadChannel>,
+}
@@ -1,5 +1,6 @@
 pub mod audit_log;
 pub mod member;
+pub mod scheduled_event;
 
 mod ban;
 mod default_message_notification_level;
@@ -49,7 +50,10 @@ use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::fmt::{Formatter, Result as FmtResult};
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    time::SystemTime,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct Guild {
@@ -118,6 +122,13 @@ pub struct Guild {
     pub widget_enabled: Option<bool>,
 }
 
+impl Guild {
+    /// Time at which the guild was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        crate::id::created_at(self.id.0)
+    }
+}
+
 impl<'de> Deserialize<'de> for Guild {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
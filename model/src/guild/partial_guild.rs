@@ -46,12 +46,42 @@ pub struct PartialGuild {
     pub system_channel_id: Option<ChannelId>,
     pub verification_level: VerificationLevel,
     pub vanity_url_code: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub widget_channel_id: Option<ChannelId>,
+    /// ID of the channel the widget generates invites to.
+    ///
+    /// `None` if the field wasn't sent, and `Some(None)` if the widget has no
+    /// channel.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "nullable")]
+    pub widget_channel_id: Option<Option<ChannelId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub widget_enabled: Option<bool>,
 }
 
+/// (De)serialize a field that can be absent or null, keeping the two apart.
+///
+/// An absent field is defaulted to `None`, so only present fields are
+/// deserialized here.
+mod nullable {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[allow(clippy::option_option)]
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Option<T>>, D::Error> {
+        Option::deserialize(deserializer).map(Some)
+    }
+
+    #[allow(clippy::option_option, clippy::ref_option)]
+    pub fn serialize<S: Serializer, T: Serialize>(
+        value: &Option<Option<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(inner) => inner.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -96,7 +126,7 @@ mod tests {
             system_channel_id: Some(ChannelId(7)),
             verification_level: VerificationLevel::Medium,
             vanity_url_code: Some("twilight".to_owned()),
-            widget_channel_id: Some(ChannelId(8)),
+            widget_channel_id: Some(Some(ChannelId(8))),
             widget_enabled: Some(true),
         };
 
@@ -208,4 +238,42 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_widget_channel_id_absent_or_null() {
+        let mut value = serde_json::json!({
+            "id": "1",
+            "afk_channel_id": null,
+            "afk_timeout": 900,
+            "application_id": null,
+            "banner": null,
+            "default_message_notifications": 1,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 1,
+            "features": [],
+            "icon": null,
+            "mfa_level": 1,
+            "name": "the name",
+            "nsfw_level": 0,
+            "owner_id": "5",
+            "preferred_locale": "en-us",
+            "premium_tier": 1,
+            "roles": [],
+            "rules_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 2,
+            "system_channel_id": null,
+            "verification_level": 2,
+            "vanity_url_code": null,
+        });
+
+        let guild = serde_json::from_value::<PartialGuild>(value.clone()).unwrap();
+        assert_eq!(None, guild.widget_channel_id);
+
+        value["widget_channel_id"] = serde_json::Value::Null;
+        let guild = serde_json::from_value::<PartialGuild>(value).unwrap();
+        assert_eq!(Some(None), guild.widget_channel_id);
+    }
 }
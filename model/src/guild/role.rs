@@ -1,7 +1,10 @@
 use super::RoleTags;
 use crate::{guild::Permissions, id::RoleId};
 use serde::{Deserialize, Serialize};
-use std::cmp::{Ord, Ordering, PartialOrd};
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    time::SystemTime,
+};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Role {
@@ -18,6 +21,13 @@ pub struct Role {
     pub tags: Option<RoleTags>,
 }
 
+impl Role {
+    /// Time at which the role was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        crate::id::created_at(self.id.0)
+    }
+}
+
 impl Ord for Role {
     /// Compare two roles to each other using their position and ID.
     ///
@@ -0,0 +1,29 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of location a scheduled event takes place at.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum EntityType {
+    /// Event takes place in a stage instance.
+    StageInstance = 1,
+    /// Event takes place in a voice channel.
+    Voice = 2,
+    /// Event takes place outside of Discord, at a location specified by the
+    /// event's entity metadata.
+    External = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&EntityType::StageInstance, &[Token::U8(1)]);
+        serde_test::assert_tokens(&EntityType::Voice, &[Token::U8(2)]);
+        serde_test::assert_tokens(&EntityType::External, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,163 @@
+//! Events scheduled to take place in guilds.
+
+mod entity_type;
+mod privacy_level;
+mod status;
+
+pub use self::{entity_type::EntityType, privacy_level::PrivacyLevel, status::Status};
+
+use crate::{
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Event scheduled to take place in a guild.
+///
+/// Refer to [the Discord docs] for more information.
+///
+/// [the Discord docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the stage or voice channel the event takes place in.
+    ///
+    /// This is `None` for [`EntityType::External`] events.
+    pub channel_id: Option<ChannelId>,
+    /// User that created the event.
+    ///
+    /// This isn't present for events created before October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// ID of the user that created the event.
+    ///
+    /// This is `None` for events created before October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    /// Description of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID of the entity the event is associated with, such as a stage
+    /// instance.
+    pub entity_id: Option<GenericId>,
+    /// Additional information about the event's location.
+    pub entity_metadata: Option<EntityMetadata>,
+    /// Type of location the event takes place at.
+    pub entity_type: EntityType,
+    /// ID of the guild the event is in.
+    pub guild_id: GuildId,
+    /// ID of the event.
+    pub id: ScheduledEventId,
+    /// Hash of the event's cover image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Name of the event.
+    pub name: String,
+    /// Who can see the event.
+    pub privacy_level: PrivacyLevel,
+    /// ISO 8601 timestamp of when the event is scheduled to end.
+    ///
+    /// This is always present for [`EntityType::External`] events.
+    pub scheduled_end_time: Option<String>,
+    /// ISO 8601 timestamp of when the event is scheduled to start.
+    pub scheduled_start_time: String,
+    /// Current status of the event.
+    pub status: Status,
+    /// Number of users subscribed to the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+}
+
+/// Additional information about the location of a [`GuildScheduledEvent`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EntityMetadata {
+    /// Location of an [`EntityType::External`] event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, Status};
+    use crate::id::{GuildId, ScheduledEventId, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_scheduled_event() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: Some(UserId(1)),
+            description: Some("a party".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("the park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId(2),
+            id: ScheduledEventId(3),
+            image: None,
+            name: "party".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            scheduled_end_time: Some("2021-12-01T20:00:00+00:00".to_owned()),
+            scheduled_start_time: "2021-12-01T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(4),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 14,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("creator_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a party"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("the park"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("party"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2021-12-01T20:00:00+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2021-12-01T18:00:00+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::Str("user_count"),
+                Token::Some,
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -49,7 +49,26 @@ pub(crate) mod string {
 }
 
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Discord's epoch, the Unix time in milliseconds of the first second of 2015.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Unix time in milliseconds at which a snowflake was generated, derived from
+/// bits 22..63 of it.
+pub const fn timestamp(snowflake: u64) -> u64 {
+    (snowflake >> 22) + DISCORD_EPOCH
+}
+
+/// Time at which a snowflake was generated.
+///
+/// This is the [`timestamp`] as a [`SystemTime`].
+pub fn created_at(snowflake: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(timestamp(snowflake))
+}
 
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
@@ -154,6 +173,23 @@ impl From<u64> for EmojiId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct EntitlementId(#[serde(with = "string")] pub u64);
+
+impl Display for EntitlementId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for EntitlementId {
+    fn from(id: u64) -> Self {
+        EntitlementId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -176,6 +212,22 @@ impl From<u64> for GenericId {
 )]
 pub struct GuildId(#[serde(with = "string")] pub u64);
 
+impl GuildId {
+    /// ID of the shard that receives events for the guild when the bot is
+    /// split into the given number of shards.
+    ///
+    /// Refer to [the Discord docs] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shard count is 0.
+    ///
+    /// [the Discord docs]: https://discord.com/developers/docs/topics/gateway#sharding-sharding-formula
+    pub const fn shard_id(self, shard_count: u64) -> u64 {
+        (self.0 >> 22) % shard_count
+    }
+}
+
 impl Display for GuildId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         Display::fmt(&self.0, f)
@@ -257,6 +309,23 @@ impl From<u64> for RoleId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct ScheduledEventId(#[serde(with = "string")] pub u64);
+
+impl Display for ScheduledEventId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for ScheduledEventId {
+    fn from(id: u64) -> Self {
+        ScheduledEventId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -311,10 +380,63 @@ impl From<u64> for WebhookId {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, GenericId,
-        GuildId, IntegrationId, InteractionId, MessageId, RoleId, StageId, UserId, WebhookId,
+        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, EntitlementId,
+        GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId, ScheduledEventId,
+        StageId, UserId, WebhookId,
     };
+    use serde::Deserialize;
     use serde_test::Token;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(1_445_219_918_546, super::timestamp(105_484_726_235_607_040));
+        assert_eq!(super::DISCORD_EPOCH, super::timestamp(0));
+    }
+
+    #[test]
+    fn test_created_at() {
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_millis(1_445_219_918_546),
+            super::created_at(105_484_726_235_607_040),
+        );
+    }
+
+    #[test]
+    fn test_guild_shard_id() {
+        assert_eq!(0, GuildId(81_384_788_765_712_384).shard_id(1));
+        assert_eq!(1, GuildId(81_384_788_765_712_384).shard_id(3));
+        assert_eq!(2, GuildId(81_384_788_765_712_384).shard_id(16));
+        assert_eq!(0, GuildId(197_038_439_483_310_086).shard_id(5));
+        assert_eq!(770, GuildId(197_038_439_483_310_086).shard_id(1000));
+        assert_eq!(38, GuildId(41_771_983_423_143_937).shard_id(128));
+        assert_eq!(0, GuildId(1).shard_id(2));
+    }
+
+    /// Test that optional IDs deserialize from `null` as `None`, which is
+    /// what Discord sends for the guild IDs of events in direct messages.
+    #[test]
+    fn test_option_id_deser() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Payload {
+            #[serde(default)]
+            guild_id: Option<GuildId>,
+        }
+
+        let payload = serde_json::from_str::<Payload>(r#"{"guild_id":null}"#).unwrap();
+        assert_eq!(None, payload.guild_id);
+
+        let payload = serde_json::from_str::<Payload>("{}").unwrap();
+        assert_eq!(None, payload.guild_id);
+
+        let payload = serde_json::from_str::<Payload>(r#"{"guild_id":"1"}"#).unwrap();
+        assert_eq!(Some(GuildId(1)), payload.guild_id);
+
+        let payload = serde_json::from_str::<Payload>(r#"{"guild_id":1}"#).unwrap();
+        assert_eq!(Some(GuildId(1)), payload.guild_id);
+
+        assert!(serde_json::from_str::<Payload>(r#"{"guild_id":"a"}"#).is_err());
+    }
 
     #[allow(clippy::too_many_lines)]
     #[test]
@@ -415,6 +537,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &EntitlementId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "EntitlementId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &EntitlementId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "EntitlementId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &GenericId(114_941_315_417_899_012),
             &[
@@ -507,6 +647,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &StageId(114_941_315_417_899_012),
             &[
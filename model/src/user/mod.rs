@@ -14,6 +14,7 @@ pub use self::{
 
 use crate::id::UserId;
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
 pub(crate) mod discriminator {
     use serde::{
@@ -84,10 +85,18 @@ pub struct User {
     pub verified: Option<bool>,
 }
 
+impl User {
+    /// Time at which the user was created, derived from its ID.
+    pub fn created_at(&self) -> SystemTime {
+        crate::id::created_at(self.id.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{PremiumType, User, UserFlags, UserId};
     use serde_test::Token;
+    use std::time::{Duration, UNIX_EPOCH};
 
     fn user_tokens(discriminator_token: Token) -> Vec<Token> {
         vec![
@@ -233,4 +242,28 @@ mod tests {
         // discriminator.
         serde_test::assert_de_tokens(&value, &user_tokens_complete(Token::U64(1)));
     }
+
+    #[test]
+    fn test_created_at() {
+        let value = User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(105_484_726_235_607_040),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_millis(1_445_219_918_546),
+            value.created_at(),
+        );
+    }
 }
@@ -814,6 +814,9 @@ const fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
         Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.0.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.0.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.0.guild_id),
         Event::GuildUpdate(e) => Some(e.0.id),
         Event::IntegrationCreate(e) => e.0.guild_id,
         Event::IntegrationDelete(e) => Some(e.guild_id),
@@ -850,6 +853,10 @@ const fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::StageInstanceCreate(e) => Some(e.0.guild_id),
         Event::StageInstanceDelete(e) => Some(e.0.guild_id),
         Event::StageInstanceUpdate(e) => Some(e.0.guild_id),
+        Event::ThreadCreate(e) => e.0.guild_id,
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => e.0.guild_id,
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
         Event::UserUpdate(_) => None,
@@ -105,7 +105,7 @@ mod preset;
 
 use self::preset::{
     PERMISSIONS_MESSAGING, PERMISSIONS_ROOT_ONLY, PERMISSIONS_STAGE_OMIT, PERMISSIONS_TEXT_OMIT,
-    PERMISSIONS_VOICE_OMIT,
+    PERMISSIONS_TIMED_OUT, PERMISSIONS_VOICE_OMIT,
 };
 use twilight_model::{
     channel::{
@@ -137,6 +137,8 @@ pub struct PermissionCalculator<'a> {
     /// `const`: When this has a value of 0 then it logically maps to
     /// `Option::None`, while any other value logically maps to `Option::Some`.
     owner_id: UserId,
+    /// Whether the member is timed out.
+    timed_out: bool,
     /// ID of the user whose permissions are being calculated.
     user_id: UserId,
 }
@@ -161,6 +163,7 @@ impl<'a> PermissionCalculator<'a> {
             guild_id,
             owner_id: UserId(0),
             member_roles,
+            timed_out: false,
             user_id,
         }
     }
@@ -182,6 +185,21 @@ impl<'a> PermissionCalculator<'a> {
         self
     }
 
+    /// Configure whether the member is timed out.
+    ///
+    /// Timed out members only retain the [View Channel] and
+    /// [Read Message History] permissions, unless they are the owner of the
+    /// guild or an administrator.
+    ///
+    /// [Read Message History]: twilight_model::guild::Permissions::READ_MESSAGE_HISTORY
+    /// [View Channel]: twilight_model::guild::Permissions::VIEW_CHANNEL
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn timed_out(mut self, timed_out: bool) -> Self {
+        self.timed_out = timed_out;
+
+        self
+    }
+
     /// Calculate the guild-level permissions of a member.
     #[must_use = "calculating permissions is only useful if they're used"]
     pub const fn root(&self) -> Permissions {
@@ -221,6 +239,10 @@ impl<'a> PermissionCalculator<'a> {
             idx += 1;
         }
 
+        if self.timed_out {
+            permissions = bitops::intersection(permissions, PERMISSIONS_TIMED_OUT);
+        }
+
         permissions
     }
 
@@ -367,6 +389,55 @@ impl<'a> PermissionCalculator<'a> {
         self,
         channel_type: ChannelType,
         channel_overwrites: &[PermissionOverwrite],
+    ) -> Permissions {
+        self.in_channel_with_parent(channel_type, channel_overwrites, &[])
+    }
+
+    /// Calculate the permissions of the member in a channel without consuming
+    /// the calculator.
+    ///
+    /// This allows one calculator to be reused to calculate the member's
+    /// permissions in multiple channels. Refer to [`in_channel`] for the
+    /// permissions that are removed from the returned permission set.
+    ///
+    /// [`in_channel`]: Self::in_channel
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub const fn in_channel_ref(
+        &self,
+        channel_type: ChannelType,
+        channel_overwrites: &[PermissionOverwrite],
+    ) -> Permissions {
+        self.calculate_in_channel(channel_type, channel_overwrites, &[])
+    }
+
+    /// Calculate the permissions of the member in a channel within a category,
+    /// taking into account the permission overwrites of the category.
+    ///
+    /// The category's overwrites are applied first, after which the channel's
+    /// own overwrites are applied on top of them. This means that a permission
+    /// denied by the category may be allowed again by the channel.
+    ///
+    /// Refer to [`in_channel`] for the permissions that are removed from the
+    /// returned permission set.
+    ///
+    /// [`in_channel`]: Self::in_channel
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub const fn in_channel_with_parent(
+        self,
+        channel_type: ChannelType,
+        channel_overwrites: &[PermissionOverwrite],
+        parent_overwrites: &[PermissionOverwrite],
+    ) -> Permissions {
+        self.calculate_in_channel(channel_type, channel_overwrites, parent_overwrites)
+    }
+
+    /// Calculate the permissions of the member in a channel, applying the
+    /// overwrites of its parent before its own.
+    const fn calculate_in_channel(
+        &self,
+        channel_type: ChannelType,
+        channel_overwrites: &[PermissionOverwrite],
+        parent_overwrites: &[PermissionOverwrite],
     ) -> Permissions {
         let mut permissions = self.root();
 
@@ -378,6 +449,14 @@ impl<'a> PermissionCalculator<'a> {
 
         permissions = bitops::remove(permissions, PERMISSIONS_ROOT_ONLY);
 
+        permissions = process_permission_overwrites(
+            permissions,
+            parent_overwrites,
+            &self.member_roles,
+            self.guild_id,
+            self.user_id,
+        );
+
         permissions = process_permission_overwrites(
             permissions,
             channel_overwrites,
@@ -405,6 +484,11 @@ impl<'a> PermissionCalculator<'a> {
             permissions = bitops::remove(permissions, PERMISSIONS_VOICE_OMIT);
         }
 
+        // Overwrites may not grant timed out members additional permissions.
+        if self.timed_out {
+            permissions = bitops::intersection(permissions, PERMISSIONS_TIMED_OUT);
+        }
+
         permissions
     }
 }
@@ -632,6 +716,102 @@ mod tests {
         assert_eq!(calculated, Permissions::MANAGE_MESSAGES);
     }
 
+    /// Test that a channel's overwrites are applied after its category's
+    /// overwrites, allowing the channel to re-allow a denied permission.
+    #[test]
+    fn test_in_channel_with_parent() {
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+        let everyone_role = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let roles = &[(RoleId(3), Permissions::empty())];
+        let calc = PermissionCalculator::new(guild_id, user_id, everyone_role, roles);
+
+        let category_overwrites = &[PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::SEND_MESSAGES,
+            kind: PermissionOverwriteType::Role(RoleId(3)),
+        }];
+        let channel_overwrites = &[PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(RoleId(3)),
+        }];
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            calc.clone()
+                .in_channel_with_parent(ChannelType::GuildText, &[], category_overwrites),
+        );
+        assert_eq!(
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            calc.in_channel_with_parent(
+                ChannelType::GuildText,
+                channel_overwrites,
+                category_overwrites,
+            ),
+        );
+    }
+
+    /// Test that a calculator can be reused to calculate permissions in
+    /// multiple channels.
+    #[test]
+    fn test_in_channel_ref() {
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+        let everyone_role = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let roles = &[(RoleId(3), Permissions::empty())];
+        let calc = PermissionCalculator::new(guild_id, user_id, everyone_role, roles);
+
+        let denied = &[PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::SEND_MESSAGES,
+            kind: PermissionOverwriteType::Role(RoleId(3)),
+        }];
+
+        assert_eq!(
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            calc.in_channel_ref(ChannelType::GuildText, &[]),
+        );
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            calc.in_channel_ref(ChannelType::GuildText, denied),
+        );
+        assert_eq!(
+            calc.clone().in_channel(ChannelType::GuildText, denied),
+            calc.in_channel_ref(ChannelType::GuildText, denied),
+        );
+    }
+
+    /// Test that a timed out member only retains the permissions to view
+    /// channels and read their message history, unless they're an
+    /// administrator.
+    #[test]
+    fn test_timed_out() {
+        let everyone_role = Permissions::READ_MESSAGE_HISTORY
+            | Permissions::SEND_MESSAGES
+            | Permissions::VIEW_CHANNEL;
+        let roles = &[(RoleId(3), Permissions::KICK_MEMBERS)];
+        let overwrites = &[PermissionOverwrite {
+            allow: Permissions::ADD_REACTIONS,
+            deny: Permissions::empty(),
+            kind: PermissionOverwriteType::Role(RoleId(3)),
+        }];
+        let calc =
+            PermissionCalculator::new(GuildId(1), UserId(2), everyone_role, roles).timed_out(true);
+
+        let expected = Permissions::READ_MESSAGE_HISTORY | Permissions::VIEW_CHANNEL;
+        assert_eq!(expected, calc.clone().root());
+        assert_eq!(
+            expected,
+            calc.in_channel(ChannelType::GuildText, overwrites)
+        );
+
+        let admin = &[(RoleId(3), Permissions::ADMINISTRATOR)];
+        let calc =
+            PermissionCalculator::new(GuildId(1), UserId(2), everyone_role, admin).timed_out(true);
+        assert!(calc.root().is_all());
+    }
+
     /// Test that a member that has a role with the "administrator" permission
     /// has all denying overwrites ignored.
     #[test]
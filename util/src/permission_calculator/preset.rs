@@ -26,6 +26,11 @@ pub const PERMISSIONS_ROOT_ONLY: Permissions = Permissions::from_bits_truncate(
         | Permissions::VIEW_GUILD_INSIGHTS.bits(),
 );
 
+/// Permissions retained by a member that is timed out.
+pub const PERMISSIONS_TIMED_OUT: Permissions = Permissions::from_bits_truncate(
+    Permissions::READ_MESSAGE_HISTORY.bits() | Permissions::VIEW_CHANNEL.bits(),
+);
+
 /// Permissions associated with guild channels, omitting those in guild stage
 /// channels.
 pub const PERMISSIONS_STAGE_OMIT: Permissions = bitops::remove(
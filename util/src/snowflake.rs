@@ -1,8 +1,10 @@
 //! Provides the Snowflake trait for defining extractable information from a Discord Snowflake.
 
+use std::{cmp::Ordering, time::SystemTime};
 use twilight_model::id::{
-    ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, GenericId,
-    GuildId, IntegrationId, InteractionId, MessageId, RoleId, StageId, UserId, WebhookId,
+    self, ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId,
+    EntitlementId, GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId,
+    ScheduledEventId, StageId, UserId, WebhookId,
 };
 
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
@@ -48,10 +50,30 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+        id::timestamp(self.id()) as i64
+    }
 
-        ((self.id() >> 22) + DISCORD_EPOCH) as i64
+    /// The time at which the Snowflake was generated.
+    ///
+    /// This is the [`timestamp`] as a [`SystemTime`].
+    ///
+    /// # Examples
+    ///
+    /// See how long ago a message was sent:
+    ///
+    /// ```rust
+    /// use twilight_util::snowflake::Snowflake;
+    /// use twilight_model::id::MessageId;
+    ///
+    /// let id = MessageId(105484726235607040);
+    /// let age = id.created_at().elapsed().expect("message is from the past");
+    ///
+    /// println!("message was sent {} seconds ago", age.as_secs());
+    /// ```
+    ///
+    /// [`timestamp`]: Self::timestamp
+    fn created_at(&self) -> SystemTime {
+        id::created_at(self.id())
     }
 
     /// The id of the internal worker that generated the Snowflake.
@@ -78,6 +100,59 @@ pub trait Snowflake {
     fn increment(&self) -> u16 {
         (self.id() & 0xFFF) as u16
     }
+
+    /// Compare the Snowflake to another by when they were generated.
+    ///
+    /// Since the [`timestamp`] makes up the most significant bits of the id,
+    /// this is the same as comparing the ids numerically. Snowflakes generated
+    /// in the same millisecond are ordered by their worker id, process id, and
+    /// [`increment`], so they aren't necessarily ordered chronologically.
+    ///
+    /// The other Snowflake may be of a different type, such as when comparing
+    /// a message's id to a channel's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use twilight_util::snowflake::Snowflake;
+    /// use twilight_model::id::{ChannelId, MessageId};
+    ///
+    /// let channel_id = ChannelId(105484726235607040);
+    /// let message_id = MessageId(762022344856174632);
+    ///
+    /// assert_eq!(Ordering::Greater, message_id.cmp_by_time(&channel_id));
+    /// ```
+    ///
+    /// [`increment`]: Self::increment
+    /// [`timestamp`]: Self::timestamp
+    fn cmp_by_time(&self, other: &dyn Snowflake) -> Ordering {
+        self.id().cmp(&other.id())
+    }
+
+    /// Whether the Snowflake was generated after another.
+    ///
+    /// Refer to [`cmp_by_time`] for how Snowflakes are compared.
+    ///
+    /// # Examples
+    ///
+    /// Check whether a message was sent after another:
+    ///
+    /// ```rust
+    /// use twilight_util::snowflake::Snowflake;
+    /// use twilight_model::id::MessageId;
+    ///
+    /// let older = MessageId(105484726235607040);
+    /// let newer = MessageId(762022344856174632);
+    ///
+    /// assert!(newer.is_newer_than(&older));
+    /// assert!(!older.is_newer_than(&newer));
+    /// ```
+    ///
+    /// [`cmp_by_time`]: Self::cmp_by_time
+    fn is_newer_than(&self, other: &dyn Snowflake) -> bool {
+        self.cmp_by_time(other) == Ordering::Greater
+    }
 }
 
 impl Snowflake for ApplicationId {
@@ -116,6 +191,12 @@ impl Snowflake for EmojiId {
     }
 }
 
+impl Snowflake for EntitlementId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for GenericId {
     fn id(&self) -> u64 {
         self.0
@@ -152,6 +233,12 @@ impl Snowflake for RoleId {
     }
 }
 
+impl Snowflake for ScheduledEventId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for StageId {
     fn id(&self) -> u64 {
         self.0
@@ -174,9 +261,14 @@ impl Snowflake for WebhookId {
 mod tests {
     use super::Snowflake;
     use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        cmp::Ordering,
+        time::{Duration, UNIX_EPOCH},
+    };
     use twilight_model::id::{
-        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, GenericId,
-        GuildId, IntegrationId, InteractionId, MessageId, RoleId, StageId, UserId, WebhookId,
+        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, EntitlementId,
+        GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId, ScheduledEventId,
+        StageId, UserId, WebhookId,
     };
 
     assert_impl_all!(ApplicationId: Snowflake);
@@ -185,12 +277,14 @@ mod tests {
     assert_impl_all!(ChannelId: Snowflake);
     assert_impl_all!(CommandId: Snowflake);
     assert_impl_all!(EmojiId: Snowflake);
+    assert_impl_all!(EntitlementId: Snowflake);
     assert_impl_all!(GenericId: Snowflake);
     assert_impl_all!(GuildId: Snowflake);
     assert_impl_all!(IntegrationId: Snowflake);
     assert_impl_all!(InteractionId: Snowflake);
     assert_impl_all!(MessageId: Snowflake);
     assert_impl_all!(RoleId: Snowflake);
+    assert_impl_all!(ScheduledEventId: Snowflake);
     assert_impl_all!(StageId: Snowflake);
     assert_impl_all!(UserId: Snowflake);
     assert_impl_all!(WebhookId: Snowflake);
@@ -204,6 +298,14 @@ mod tests {
         assert_eq!(expected, id.timestamp())
     }
 
+    #[test]
+    fn test_created_at() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1_445_219_918_546);
+        let id = GenericId(105_484_726_235_607_040);
+
+        assert_eq!(expected, id.created_at())
+    }
+
     #[test]
     fn test_worker_id() {
         let expected: u8 = 8;
@@ -227,4 +329,25 @@ mod tests {
 
         assert_eq!(expected, id.increment())
     }
+
+    #[test]
+    fn test_cmp_by_time() {
+        let older = MessageId(105_484_726_235_607_040);
+        let newer = MessageId(762_022_344_856_174_632);
+
+        assert_eq!(Ordering::Greater, newer.cmp_by_time(&older));
+        assert_eq!(Ordering::Less, older.cmp_by_time(&newer));
+        assert_eq!(Ordering::Equal, older.cmp_by_time(&GenericId(older.0)));
+    }
+
+    #[test]
+    fn test_is_newer_than() {
+        let older = MessageId(105_484_726_235_607_040);
+        let newer = MessageId(762_022_344_856_174_632);
+
+        assert!(newer.is_newer_than(&older));
+        assert!(!older.is_newer_than(&newer));
+        assert!(!older.is_newer_than(&older));
+        assert!(newer.is_newer_than(&ChannelId(older.0)));
+    }
 }